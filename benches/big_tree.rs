@@ -319,5 +319,45 @@ fn taffy_benchmarks(c: &mut Criterion) {
     });
 }
 
+/// Compares the layout of big trees on a single thread with their layout on all threads of the `rayon` thread pool
+#[cfg(feature = "parallel")]
+fn parallel_benchmarks(c: &mut Criterion) {
+    let single_thread = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+    let all_threads = rayon::ThreadPoolBuilder::new().build().unwrap();
+
+    let mut group = c.benchmark_group("parallel layout");
+    group.sample_size(10);
+
+    for (name, node_count) in [("10_000 nodes", 10_000), ("100_000 nodes", 100_000)] {
+        for (pool_name, pool) in [("single thread", &single_thread), ("all threads", &all_threads)] {
+            group.bench_function(format!("{name} (yoga, {pool_name})"), |b| {
+                b.iter_batched(
+                    || {
+                        let mut taffy = Taffy::new();
+                        let root = build_yoga_deep_hierarchy(&mut taffy, node_count, 10);
+                        (taffy, root)
+                    },
+                    |(mut taffy, root)| pool.install(|| taffy.compute_layout(root, Size::MAX_CONTENT).unwrap()),
+                    criterion::BatchSize::LargeInput,
+                )
+            });
+            group.bench_function(format!("{name} (7-level hierarchy, {pool_name})"), |b| {
+                b.iter_batched(
+                    || {
+                        let mut taffy = Taffy::new();
+                        let root = build_deep_hierarchy(&mut taffy, node_count, 7);
+                        (taffy, root)
+                    },
+                    |(mut taffy, root)| pool.install(|| taffy.compute_layout(root, Size::MAX_CONTENT).unwrap()),
+                    criterion::BatchSize::LargeInput,
+                )
+            });
+        }
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
 criterion_group!(benches, taffy_benchmarks);
+#[cfg(feature = "parallel")]
+criterion_group!(benches, taffy_benchmarks, parallel_benchmarks);
 criterion_main!(benches);
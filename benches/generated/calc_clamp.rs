@@ -0,0 +1,37 @@
+pub fn compute() {
+    #[allow(unused_imports)]
+    use taffy::prelude::*;
+    let mut taffy = taffy::Taffy::new();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::from(taffy::style::CalcLength {
+                    points: 0f32,
+                    percent: 0.5f32,
+                    min: 50f32,
+                    max: 100f32,
+                }),
+                height: taffy::style::Dimension::from(taffy::style::CalcLength {
+                    points: 0f32,
+                    percent: 0.1f32,
+                    min: 50f32,
+                    max: 100f32,
+                }),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(300f32),
+                    height: taffy::style::Dimension::Points(100f32),
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::MAX_CONTENT).unwrap();
+}
@@ -0,0 +1,64 @@
+pub fn compute() {
+    #[allow(unused_imports)]
+    use taffy::prelude::*;
+    let mut taffy = taffy::Taffy::new();
+    let node0 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::Points(50f32),
+                height: taffy::style::Dimension::Points(50f32),
+            },
+            inset: taffy::geometry::Rect {
+                left: taffy::style::LengthPercentageAuto::from(taffy::style::CalcLength {
+                    points: -25f32,
+                    percent: 0.5f32,
+                    min: f32::NEG_INFINITY,
+                    max: f32::INFINITY,
+                }),
+                right: auto(),
+                top: auto(),
+                bottom: auto(),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            position: taffy::style::Position::Absolute,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::Points(50f32),
+                height: taffy::style::Dimension::Points(50f32),
+            },
+            inset: taffy::geometry::Rect {
+                left: auto(),
+                right: taffy::style::LengthPercentageAuto::from(taffy::style::CalcLength {
+                    points: 5f32,
+                    percent: 0.1f32,
+                    min: f32::NEG_INFINITY,
+                    max: f32::INFINITY,
+                }),
+                top: auto(),
+                bottom: taffy::style::LengthPercentageAuto::from(taffy::style::CalcLength {
+                    points: -25f32,
+                    percent: 0.5f32,
+                    min: f32::NEG_INFINITY,
+                    max: f32::INFINITY,
+                }),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(200f32),
+                    height: taffy::style::Dimension::Points(100f32),
+                },
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::MAX_CONTENT).unwrap();
+}
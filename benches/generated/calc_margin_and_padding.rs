@@ -0,0 +1,73 @@
+pub fn compute() {
+    #[allow(unused_imports)]
+    use taffy::prelude::*;
+    let mut taffy = taffy::Taffy::new();
+    let node00 = taffy
+        .new_leaf(taffy::style::Style {
+            flex_grow: 1f32,
+            margin: taffy::geometry::Rect {
+                left: taffy::style::LengthPercentageAuto::from(taffy::style::CalcLength {
+                    points: -10f32,
+                    percent: 0.1f32,
+                    min: f32::NEG_INFINITY,
+                    max: f32::INFINITY,
+                }),
+                right: zero(),
+                top: zero(),
+                bottom: zero(),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(200f32),
+                    height: taffy::style::Dimension::Points(100f32),
+                },
+                padding: taffy::geometry::Rect {
+                    left: taffy::style::LengthPercentage::from(taffy::style::CalcLength {
+                        points: 5f32,
+                        percent: 0.1f32,
+                        min: f32::NEG_INFINITY,
+                        max: f32::INFINITY,
+                    }),
+                    right: taffy::style::LengthPercentage::from(taffy::style::CalcLength {
+                        points: 5f32,
+                        percent: 0.1f32,
+                        min: f32::NEG_INFINITY,
+                        max: f32::INFINITY,
+                    }),
+                    top: taffy::style::LengthPercentage::from(taffy::style::CalcLength {
+                        points: 5f32,
+                        percent: 0.1f32,
+                        min: f32::NEG_INFINITY,
+                        max: f32::INFINITY,
+                    }),
+                    bottom: taffy::style::LengthPercentage::from(taffy::style::CalcLength {
+                        points: 5f32,
+                        percent: 0.1f32,
+                        min: f32::NEG_INFINITY,
+                        max: f32::INFINITY,
+                    }),
+                },
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(200f32),
+                    height: taffy::style::Dimension::Points(100f32),
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::MAX_CONTENT).unwrap();
+}
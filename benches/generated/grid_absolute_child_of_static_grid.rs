@@ -0,0 +1,54 @@
+pub fn compute() {
+    #[allow(unused_imports)]
+    use taffy::prelude::*;
+    let mut taffy = taffy::Taffy::new();
+    let node00 = taffy
+        .new_leaf(taffy::style::Style {
+            position: taffy::style::Position::Absolute,
+            size: taffy::geometry::Size {
+                width: taffy::style::Dimension::Points(10f32),
+                height: taffy::style::Dimension::Points(10f32),
+            },
+            grid_column: taffy::geometry::Line { start: line(2i16), end: taffy::style::GridPlacement::Auto },
+            inset: taffy::geometry::Rect {
+                left: taffy::style::LengthPercentageAuto::Points(0f32),
+                right: auto(),
+                top: taffy::style::LengthPercentageAuto::Points(0f32),
+                bottom: auto(),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                position: taffy::style::Position::Static,
+                display: taffy::style::Display::Grid,
+                size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::Points(50f32) },
+                grid_template_columns: vec![points(50f32), points(50f32)],
+                grid_template_rows: vec![points(50f32)],
+                margin: taffy::geometry::Rect {
+                    left: taffy::style::LengthPercentageAuto::Points(20f32),
+                    right: zero(),
+                    top: zero(),
+                    bottom: zero(),
+                },
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(200f32),
+                    height: taffy::style::Dimension::Points(200f32),
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::MAX_CONTENT).unwrap();
+}
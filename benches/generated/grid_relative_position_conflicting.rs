@@ -0,0 +1,29 @@
+pub fn compute() {
+    #[allow(unused_imports)]
+    use taffy::prelude::*;
+    let mut taffy = taffy::Taffy::new();
+    let node0 = taffy.new_leaf(taffy::style::Style { ..Default::default() }).unwrap();
+    let node1 = taffy
+        .new_leaf(taffy::style::Style {
+            inset: taffy::geometry::Rect {
+                left: taffy::style::LengthPercentageAuto::Percent(0.1f32),
+                right: taffy::style::LengthPercentageAuto::Points(30f32),
+                top: taffy::style::LengthPercentageAuto::Percent(0.2f32),
+                bottom: taffy::style::LengthPercentageAuto::Points(5f32),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                display: taffy::style::Display::Grid,
+                grid_template_columns: vec![points(100f32), points(100f32)],
+                grid_template_rows: vec![points(50f32)],
+                ..Default::default()
+            },
+            &[node0, node1],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::MAX_CONTENT).unwrap();
+}
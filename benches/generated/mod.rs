@@ -60,6 +60,9 @@ fn measure_standard_text(
         WritingMode::Vertical => Size { width: block_size, height: inline_size },
     }
 }
+mod absolute_containing_block_inset_right_bottom;
+mod absolute_containing_block_is_nearest_positioned_ancestor;
+mod absolute_containing_block_is_static_root;
 mod absolute_layout_align_items_and_justify_content_center;
 mod absolute_layout_align_items_and_justify_content_center_and_bottom_position;
 mod absolute_layout_align_items_and_justify_content_center_and_left_position;
@@ -81,6 +84,7 @@ mod absolute_layout_width_height_end_bottom;
 mod absolute_layout_width_height_start_top;
 mod absolute_layout_width_height_start_top_end_bottom;
 mod absolute_layout_within_border;
+mod absolute_static_position_with_static_parent;
 mod align_baseline;
 mod align_baseline_child_multiline;
 mod align_baseline_nested_child;
@@ -111,17 +115,76 @@ mod align_self_flex_end;
 mod align_self_flex_end_override_flex_start;
 mod align_self_flex_start;
 mod align_strech_should_size_based_on_parent;
+#[cfg(feature = "block_layout")]
+mod block_absolute_child;
+#[cfg(feature = "block_layout")]
+mod block_auto_width_fits_content;
+#[cfg(feature = "block_layout")]
+mod block_basic;
+#[cfg(feature = "block_layout")]
+mod block_direction_rtl;
+#[cfg(feature = "block_layout")]
+mod block_direction_rtl_absolute;
+#[cfg(feature = "block_layout")]
+mod block_display_none_child;
+#[cfg(feature = "block_layout")]
+mod block_margin_auto;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_negative_margins;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_siblings;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_through_empty_child;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_with_children_blocked_by_overflow;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_with_children_blocked_by_padding;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_with_first_child;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_with_last_child;
+#[cfg(feature = "block_layout")]
+mod block_min_max_size;
+#[cfg(feature = "block_layout")]
+mod block_nested;
+#[cfg(feature = "block_layout")]
+mod block_padding_border;
+#[cfg(feature = "block_layout")]
+mod block_percentage_sizes;
+#[cfg(feature = "block_layout")]
+mod block_relative_inset;
+#[cfg(feature = "block_layout")]
+mod block_relative_position_conflicting;
+#[cfg(feature = "block_layout")]
+mod block_text_wraps_to_container_width;
 mod border_center_child;
 mod border_flex_child;
 mod border_no_child;
 mod border_stretch_child;
+mod calc_clamp;
+mod calc_gap;
+mod calc_inset;
+mod calc_margin_and_padding;
+mod calc_max;
+mod calc_min;
+mod calc_width_percent_minus_points;
 mod child_min_max_width_flexing;
 mod container_with_unsized_child;
+mod direction_rtl_absolute;
+mod direction_rtl_auto_margin;
+mod direction_rtl_column;
+mod direction_rtl_inherited;
+mod direction_rtl_justify_content_end;
+mod direction_rtl_relative_inset;
+mod direction_rtl_row;
+mod direction_rtl_row_reverse;
 mod display_none;
 mod display_none_fixed_size;
 mod display_none_with_child;
 mod display_none_with_margin;
 mod display_none_with_position;
+mod fixed_is_positioned_relative_to_root;
+mod fixed_size_from_insets;
 mod flex_basis_and_main_dimen_set_when_flexing;
 mod flex_basis_flex_grow_column;
 mod flex_basis_flex_grow_row;
@@ -162,6 +225,9 @@ mod flex_grow_within_constrained_min_column;
 mod flex_grow_within_constrained_min_max_column;
 mod flex_grow_within_constrained_min_row;
 mod flex_grow_within_max_width;
+mod flex_order;
+mod flex_order_equal_values_keep_source_order;
+mod flex_order_row_reverse;
 mod flex_root_ignored;
 mod flex_shrink_by_outer_margin_with_max_size;
 mod flex_shrink_flex_grow_child_flex_shrink_other_child;
@@ -205,6 +271,8 @@ mod gap_row_gap_row_wrap_child_margins;
 #[cfg(feature = "grid")]
 mod grid_absolute_align_self_sized_all;
 #[cfg(feature = "grid")]
+mod grid_absolute_child_of_static_grid;
+#[cfg(feature = "grid")]
 mod grid_absolute_column_end;
 #[cfg(feature = "grid")]
 mod grid_absolute_column_start;
@@ -265,6 +333,14 @@ mod grid_align_content_start;
 #[cfg(feature = "grid")]
 mod grid_align_content_start_with_padding_border;
 #[cfg(feature = "grid")]
+mod grid_align_items_baseline;
+#[cfg(feature = "grid")]
+mod grid_align_items_baseline_child_margin;
+#[cfg(feature = "grid")]
+mod grid_align_items_baseline_multiple_rows;
+#[cfg(feature = "grid")]
+mod grid_align_items_baseline_nested_child;
+#[cfg(feature = "grid")]
 mod grid_align_items_sized_center;
 #[cfg(feature = "grid")]
 mod grid_align_items_sized_end;
@@ -273,6 +349,8 @@ mod grid_align_items_sized_start;
 #[cfg(feature = "grid")]
 mod grid_align_items_sized_stretch;
 #[cfg(feature = "grid")]
+mod grid_align_self_baseline;
+#[cfg(feature = "grid")]
 mod grid_align_self_sized_all;
 #[cfg(feature = "grid")]
 mod grid_auto_columns_fixed_width;
@@ -297,6 +375,10 @@ mod grid_basic_with_overflow;
 #[cfg(feature = "grid")]
 mod grid_basic_with_padding;
 #[cfg(feature = "grid")]
+mod grid_direction_rtl;
+#[cfg(feature = "grid")]
+mod grid_direction_rtl_justify_self_start;
+#[cfg(feature = "grid")]
 mod grid_fit_content_points_argument;
 #[cfg(feature = "grid")]
 mod grid_fit_content_points_max_content;
@@ -403,8 +485,18 @@ mod grid_min_max_column_fixed_width_below_range;
 #[cfg(feature = "grid")]
 mod grid_min_max_column_fixed_width_within_range;
 #[cfg(feature = "grid")]
+mod grid_order_auto_placement;
+#[cfg(feature = "grid")]
+mod grid_order_definite_placement_first;
+#[cfg(feature = "grid")]
 mod grid_out_of_order_items;
 #[cfg(feature = "grid")]
+mod grid_overflow_hidden_item_min_size_zero;
+#[cfg(feature = "grid")]
+mod grid_overflow_scroll_container_reserves_scrollbar;
+#[cfg(feature = "grid")]
+mod grid_relative_position_conflicting;
+#[cfg(feature = "grid")]
 mod grid_relayout_vertical_text;
 #[cfg(feature = "grid")]
 mod grid_size_child_fixed_tracks;
@@ -491,7 +583,11 @@ mod min_width_overrides_width_on_root;
 mod nested_overflowing_child;
 mod nested_overflowing_child_in_constraint_parent;
 mod overflow_cross_axis;
+mod overflow_hidden_flex_item_min_size_zero;
 mod overflow_main_axis;
+mod overflow_scroll_flex_container_reserves_scrollbar;
+mod overflow_scroll_flex_item_auto_size_includes_scrollbar;
+mod overflow_visible_flex_item_min_size_content;
 mod padding_align_end_child;
 mod padding_center_child;
 mod padding_flex_child;
@@ -520,6 +616,12 @@ mod percentage_size_based_on_parent_inner_size;
 mod percentage_size_of_flex_basis;
 mod percentage_width_height;
 mod percentage_width_height_undefined_parent_size;
+mod position_static_ignores_inset;
+mod relative_position_conflicting_left_right;
+mod relative_position_conflicting_left_right_rtl;
+mod relative_position_conflicting_top_bottom;
+mod relative_position_percentage;
+mod relative_position_right_bottom;
 mod relative_position_should_not_nudge_siblings;
 mod rounding_flex_basis_flex_grow_row_prime_number_width;
 mod rounding_flex_basis_flex_grow_row_width_of_100;
@@ -535,6 +637,9 @@ mod size_defined_by_child;
 mod size_defined_by_child_with_border;
 mod size_defined_by_child_with_padding;
 mod size_defined_by_grand_child;
+mod units_em;
+mod units_em_padding_and_gap;
+mod units_rem;
 mod width_smaller_then_content_with_flex_grow_large_size;
 mod width_smaller_then_content_with_flex_grow_small_size;
 mod width_smaller_then_content_with_flex_grow_unconstraint_size;
@@ -561,6 +666,9 @@ mod wrapped_row_within_align_items_flex_start;
 fn benchmark(c: &mut Criterion) {
     c.bench_function("generated benchmarks", |b| {
         b.iter(|| {
+            absolute_containing_block_inset_right_bottom::compute();
+            absolute_containing_block_is_nearest_positioned_ancestor::compute();
+            absolute_containing_block_is_static_root::compute();
             absolute_layout_align_items_and_justify_content_center::compute();
             absolute_layout_align_items_and_justify_content_center_and_bottom_position::compute();
             absolute_layout_align_items_and_justify_content_center_and_left_position::compute();
@@ -582,6 +690,7 @@ fn benchmark(c: &mut Criterion) {
             absolute_layout_width_height_start_top::compute();
             absolute_layout_width_height_start_top_end_bottom::compute();
             absolute_layout_within_border::compute();
+            absolute_static_position_with_static_parent::compute();
             align_baseline::compute();
             align_baseline_child_multiline::compute();
             align_baseline_nested_child::compute();
@@ -612,17 +721,76 @@ fn benchmark(c: &mut Criterion) {
             align_self_flex_end_override_flex_start::compute();
             align_self_flex_start::compute();
             align_strech_should_size_based_on_parent::compute();
+            #[cfg(feature = "block_layout")]
+            block_absolute_child::compute();
+            #[cfg(feature = "block_layout")]
+            block_auto_width_fits_content::compute();
+            #[cfg(feature = "block_layout")]
+            block_basic::compute();
+            #[cfg(feature = "block_layout")]
+            block_direction_rtl::compute();
+            #[cfg(feature = "block_layout")]
+            block_direction_rtl_absolute::compute();
+            #[cfg(feature = "block_layout")]
+            block_display_none_child::compute();
+            #[cfg(feature = "block_layout")]
+            block_margin_auto::compute();
+            #[cfg(feature = "block_layout")]
+            block_margin_collapse_negative_margins::compute();
+            #[cfg(feature = "block_layout")]
+            block_margin_collapse_siblings::compute();
+            #[cfg(feature = "block_layout")]
+            block_margin_collapse_through_empty_child::compute();
+            #[cfg(feature = "block_layout")]
+            block_margin_collapse_with_children_blocked_by_overflow::compute();
+            #[cfg(feature = "block_layout")]
+            block_margin_collapse_with_children_blocked_by_padding::compute();
+            #[cfg(feature = "block_layout")]
+            block_margin_collapse_with_first_child::compute();
+            #[cfg(feature = "block_layout")]
+            block_margin_collapse_with_last_child::compute();
+            #[cfg(feature = "block_layout")]
+            block_min_max_size::compute();
+            #[cfg(feature = "block_layout")]
+            block_nested::compute();
+            #[cfg(feature = "block_layout")]
+            block_padding_border::compute();
+            #[cfg(feature = "block_layout")]
+            block_percentage_sizes::compute();
+            #[cfg(feature = "block_layout")]
+            block_relative_inset::compute();
+            #[cfg(feature = "block_layout")]
+            block_relative_position_conflicting::compute();
+            #[cfg(feature = "block_layout")]
+            block_text_wraps_to_container_width::compute();
             border_center_child::compute();
             border_flex_child::compute();
             border_no_child::compute();
             border_stretch_child::compute();
+            calc_clamp::compute();
+            calc_gap::compute();
+            calc_inset::compute();
+            calc_margin_and_padding::compute();
+            calc_max::compute();
+            calc_min::compute();
+            calc_width_percent_minus_points::compute();
             child_min_max_width_flexing::compute();
             container_with_unsized_child::compute();
+            direction_rtl_absolute::compute();
+            direction_rtl_auto_margin::compute();
+            direction_rtl_column::compute();
+            direction_rtl_inherited::compute();
+            direction_rtl_justify_content_end::compute();
+            direction_rtl_relative_inset::compute();
+            direction_rtl_row::compute();
+            direction_rtl_row_reverse::compute();
             display_none::compute();
             display_none_fixed_size::compute();
             display_none_with_child::compute();
             display_none_with_margin::compute();
             display_none_with_position::compute();
+            fixed_is_positioned_relative_to_root::compute();
+            fixed_size_from_insets::compute();
             flex_basis_and_main_dimen_set_when_flexing::compute();
             flex_basis_flex_grow_column::compute();
             flex_basis_flex_grow_row::compute();
@@ -663,6 +831,9 @@ fn benchmark(c: &mut Criterion) {
             flex_grow_within_constrained_min_max_column::compute();
             flex_grow_within_constrained_min_row::compute();
             flex_grow_within_max_width::compute();
+            flex_order::compute();
+            flex_order_equal_values_keep_source_order::compute();
+            flex_order_row_reverse::compute();
             flex_root_ignored::compute();
             flex_shrink_by_outer_margin_with_max_size::compute();
             flex_shrink_flex_grow_child_flex_shrink_other_child::compute();
@@ -706,6 +877,8 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_absolute_align_self_sized_all::compute();
             #[cfg(feature = "grid")]
+            grid_absolute_child_of_static_grid::compute();
+            #[cfg(feature = "grid")]
             grid_absolute_column_end::compute();
             #[cfg(feature = "grid")]
             grid_absolute_column_start::compute();
@@ -766,6 +939,14 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_align_content_start_with_padding_border::compute();
             #[cfg(feature = "grid")]
+            grid_align_items_baseline::compute();
+            #[cfg(feature = "grid")]
+            grid_align_items_baseline_child_margin::compute();
+            #[cfg(feature = "grid")]
+            grid_align_items_baseline_multiple_rows::compute();
+            #[cfg(feature = "grid")]
+            grid_align_items_baseline_nested_child::compute();
+            #[cfg(feature = "grid")]
             grid_align_items_sized_center::compute();
             #[cfg(feature = "grid")]
             grid_align_items_sized_end::compute();
@@ -774,6 +955,8 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_align_items_sized_stretch::compute();
             #[cfg(feature = "grid")]
+            grid_align_self_baseline::compute();
+            #[cfg(feature = "grid")]
             grid_align_self_sized_all::compute();
             #[cfg(feature = "grid")]
             grid_auto_columns_fixed_width::compute();
@@ -798,6 +981,10 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_basic_with_padding::compute();
             #[cfg(feature = "grid")]
+            grid_direction_rtl::compute();
+            #[cfg(feature = "grid")]
+            grid_direction_rtl_justify_self_start::compute();
+            #[cfg(feature = "grid")]
             grid_fit_content_points_argument::compute();
             #[cfg(feature = "grid")]
             grid_fit_content_points_max_content::compute();
@@ -904,8 +1091,18 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_min_max_column_fixed_width_within_range::compute();
             #[cfg(feature = "grid")]
+            grid_order_auto_placement::compute();
+            #[cfg(feature = "grid")]
+            grid_order_definite_placement_first::compute();
+            #[cfg(feature = "grid")]
             grid_out_of_order_items::compute();
             #[cfg(feature = "grid")]
+            grid_overflow_hidden_item_min_size_zero::compute();
+            #[cfg(feature = "grid")]
+            grid_overflow_scroll_container_reserves_scrollbar::compute();
+            #[cfg(feature = "grid")]
+            grid_relative_position_conflicting::compute();
+            #[cfg(feature = "grid")]
             grid_relayout_vertical_text::compute();
             #[cfg(feature = "grid")]
             grid_size_child_fixed_tracks::compute();
@@ -992,7 +1189,11 @@ fn benchmark(c: &mut Criterion) {
             nested_overflowing_child::compute();
             nested_overflowing_child_in_constraint_parent::compute();
             overflow_cross_axis::compute();
+            overflow_hidden_flex_item_min_size_zero::compute();
             overflow_main_axis::compute();
+            overflow_scroll_flex_container_reserves_scrollbar::compute();
+            overflow_scroll_flex_item_auto_size_includes_scrollbar::compute();
+            overflow_visible_flex_item_min_size_content::compute();
             padding_align_end_child::compute();
             padding_center_child::compute();
             padding_flex_child::compute();
@@ -1021,6 +1222,12 @@ fn benchmark(c: &mut Criterion) {
             percentage_size_of_flex_basis::compute();
             percentage_width_height::compute();
             percentage_width_height_undefined_parent_size::compute();
+            position_static_ignores_inset::compute();
+            relative_position_conflicting_left_right::compute();
+            relative_position_conflicting_left_right_rtl::compute();
+            relative_position_conflicting_top_bottom::compute();
+            relative_position_percentage::compute();
+            relative_position_right_bottom::compute();
             relative_position_should_not_nudge_siblings::compute();
             rounding_flex_basis_flex_grow_row_prime_number_width::compute();
             rounding_flex_basis_flex_grow_row_width_of_100::compute();
@@ -1036,6 +1243,9 @@ fn benchmark(c: &mut Criterion) {
             size_defined_by_child_with_border::compute();
             size_defined_by_child_with_padding::compute();
             size_defined_by_grand_child::compute();
+            units_em::compute();
+            units_em_padding_and_gap::compute();
+            units_rem::compute();
             width_smaller_then_content_with_flex_grow_large_size::compute();
             width_smaller_then_content_with_flex_grow_small_size::compute();
             width_smaller_then_content_with_flex_grow_unconstraint_size::compute();
@@ -83,6 +83,8 @@ async fn main() {
             let bench_mod = Ident::new(name, Span::call_site());
             if name.starts_with("grid") {
                 quote!(#[cfg(feature = "grid")] #bench_mod::compute())
+            } else if name.starts_with("block") {
+                quote!(#[cfg(feature = "block_layout")] #bench_mod::compute())
             } else {
                 quote!(#bench_mod::compute())
             }
@@ -95,6 +97,8 @@ async fn main() {
             let name_ident = Ident::new(name, Span::call_site());
             if name.starts_with("grid") {
                 quote!(#[cfg(feature = "grid")] mod #name_ident;)
+            } else if name.starts_with("block") {
+                quote!(#[cfg(feature = "block_layout")] mod #name_ident;)
             } else {
                 quote!(mod #name_ident;)
             }
@@ -350,6 +354,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let display = match style["display"] {
         Value::String(ref value) => match value.as_ref() {
             "none" => quote!(display: taffy::style::Display::None,),
+            "block" => quote!(display: taffy::style::Display::Block,),
             "grid" => quote!(display: taffy::style::Display::Grid,),
             _ => quote!(display: taffy::style::Display::Flex,),
         },
@@ -373,6 +378,14 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         _ => quote!(),
     };
 
+    let font_size = match style["fontSize"] {
+        Value::Object(ref value) if value.get("unit") == Some(&Value::String("points".into())) => {
+            let value = value.get("value").unwrap().as_f64().unwrap() as f32;
+            quote!(font_size: Some(#value),)
+        }
+        _ => quote!(),
+    };
+
     let flex_direction = match style["flexDirection"] {
         Value::String(ref value) => match value.as_ref() {
             "row-reverse" => quote!(flex_direction: taffy::style::FlexDirection::RowReverse,),
@@ -559,6 +572,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let style = quote!(taffy::style::Style {
         #display
         #direction
+        #font_size
         #position
         #flex_direction
         #flex_wrap
@@ -622,6 +636,21 @@ fn generate_gap(size: &serde_json::Map<String, Value>) -> TokenStream {
     )
 }
 
+fn generate_calc(dimen: &serde_json::Map<String, Value>) -> TokenStream {
+    let get = |key: &str| dimen.get(key).unwrap().as_f64().map(|value| value as f32);
+    let points = get("points").unwrap();
+    let percent = get("percent").unwrap();
+    let min = match get("min") {
+        Some(min) => quote!(#min),
+        None => quote!(f32::NEG_INFINITY),
+    };
+    let max = match get("max") {
+        Some(max) => quote!(#max),
+        None => quote!(f32::INFINITY),
+    };
+    quote!(taffy::style::CalcLength { points: #points, percent: #percent, min: #min, max: #max })
+}
+
 fn generate_length_percentage(dimen: &serde_json::Map<String, Value>) -> TokenStream {
     let unit = dimen.get("unit").unwrap();
     let value = || dimen.get("value").unwrap().as_f64().unwrap() as f32;
@@ -636,6 +665,26 @@ fn generate_length_percentage(dimen: &serde_json::Map<String, Value>) -> TokenSt
                 let value = value();
                 quote!(taffy::style::LengthPercentage::Percent(#value))
             }
+            "calc" => {
+                let calc = generate_calc(dimen);
+                quote!(taffy::style::LengthPercentage::from(#calc))
+            }
+            "vw" => {
+                let value = value();
+                quote!(taffy::style::LengthPercentage::Vw(#value))
+            }
+            "vh" => {
+                let value = value();
+                quote!(taffy::style::LengthPercentage::Vh(#value))
+            }
+            "em" => {
+                let value = value();
+                quote!(taffy::style::LengthPercentage::Em(#value))
+            }
+            "rem" => {
+                let value = value();
+                quote!(taffy::style::LengthPercentage::Rem(#value))
+            }
             _ => unreachable!(),
         },
         _ => unreachable!(),
@@ -657,6 +706,26 @@ fn generate_length_percentage_auto(dimen: &serde_json::Map<String, Value>) -> To
                 let value = value();
                 quote!(taffy::style::LengthPercentageAuto::Percent(#value))
             }
+            "calc" => {
+                let calc = generate_calc(dimen);
+                quote!(taffy::style::LengthPercentageAuto::from(#calc))
+            }
+            "vw" => {
+                let value = value();
+                quote!(taffy::style::LengthPercentageAuto::Vw(#value))
+            }
+            "vh" => {
+                let value = value();
+                quote!(taffy::style::LengthPercentageAuto::Vh(#value))
+            }
+            "em" => {
+                let value = value();
+                quote!(taffy::style::LengthPercentageAuto::Em(#value))
+            }
+            "rem" => {
+                let value = value();
+                quote!(taffy::style::LengthPercentageAuto::Rem(#value))
+            }
             _ => unreachable!(),
         },
         _ => unreachable!(),
@@ -678,6 +747,26 @@ fn generate_dimension(dimen: &serde_json::Map<String, Value>) -> TokenStream {
                 let value = value();
                 quote!(taffy::style::Dimension::Percent(#value))
             }
+            "calc" => {
+                let calc = generate_calc(dimen);
+                quote!(taffy::style::Dimension::from(#calc))
+            }
+            "vw" => {
+                let value = value();
+                quote!(taffy::style::Dimension::Vw(#value))
+            }
+            "vh" => {
+                let value = value();
+                quote!(taffy::style::Dimension::Vh(#value))
+            }
+            "em" => {
+                let value = value();
+                quote!(taffy::style::Dimension::Em(#value))
+            }
+            "rem" => {
+                let value = value();
+                quote!(taffy::style::Dimension::Rem(#value))
+            }
             _ => unreachable!(),
         },
         _ => unreachable!(),
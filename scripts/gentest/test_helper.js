@@ -92,10 +92,48 @@ function parseDimension(input, options = { allowFrUnits: false }) {
   if (options.allowFrUnits && input.endsWith('fr')) return { unit: 'fraction', value: parseFloat(input.replace('fr','')) };
   if (input.endsWith('px')) return { unit: 'points',   value: parseFloat(input.replace('px','')) };
   if (input.endsWith('%')) return { unit: 'percent',  value: parseFloat(input.replace('%','')) / 100 };
+  if (input.endsWith('vw')) return { unit: 'vw',       value: parseFloat(input.replace('vw','')) / 100 };
+  if (input.endsWith('vh')) return { unit: 'vh',       value: parseFloat(input.replace('vh','')) / 100 };
+  if (input.endsWith('rem')) return { unit: 'rem',     value: parseFloat(input.replace('rem','')) };
+  if (input.endsWith('em')) return { unit: 'em',       value: parseFloat(input.replace('em','')) };
   if (input === 'auto') return { unit: 'auto' };
   if (input === 'min-content') return { unit: 'min-content' };
   if (input === 'max-content') return { unit: 'max-content' };
-  return undefined;
+  return parseCalc(input);
+}
+
+function parseCalcSum(input) {
+  const terms = input.trim().replace(/\s+-\s+/g, ' + -').split(/\s+\+\s+/);
+  let points = 0, percent = 0;
+  for (const term of terms) {
+    const value = parseDimension(term);
+    if (value?.unit === 'points') points += value.value;
+    else if (value?.unit === 'percent') percent += value.value;
+    else return undefined;
+  }
+  return { unit: 'calc', points, percent, min: null, max: null };
+}
+
+function parseCalc(input) {
+  const match = /^(calc|min|max|clamp)\((.*)\)$/.exec(input);
+  if (!match) return undefined;
+  const args = match[2].split(',').map(arg => parseCalcSum(arg.trim().replace(/^calc\((.*)\)$/, '$1')));
+  if (args.some(arg => !arg)) return undefined;
+  // The bounds of min(), max() and clamp() must be absolute lengths
+  const bound = (arg) => arg.percent === 0 ? arg.points : undefined;
+  const boundedArg = (bounds) => {
+    const value = args.find(arg => bound(arg) === undefined) ?? args[0];
+    const others = args.filter(arg => arg !== value).map(bound);
+    return others.some(other => other === undefined) ? undefined : bounds(value, others);
+  };
+  switch (match[1]) {
+    case 'calc': return args[0];
+    case 'min': return boundedArg((value, others) => ({ ...value, max: Math.min(...others) }));
+    case 'max': return boundedArg((value, others) => ({ ...value, min: Math.max(...others) }));
+    case 'clamp': return bound(args[0]) !== undefined && bound(args[2]) !== undefined
+      ? { ...args[1], min: bound(args[0]), max: bound(args[2]) }
+      : undefined;
+  }
 }
 
 function parseNumber(input) {
@@ -165,6 +203,7 @@ function describeElement(e) {
 
       position: parseEnum(e.style.position),
       direction: parseEnum(e.style.direction),
+      fontSize: parseDimension(e.style.fontSize),
       flexDirection: parseEnum(e.style.flexDirection),
 
       writingMode: parseEnum(e.style.writingMode),
@@ -20,6 +20,16 @@ impl AbsoluteAxis {
             AbsoluteAxis::Vertical => AbsoluteAxis::Horizontal,
         }
     }
+
+    /// Convert an AbsoluteAxis into an AbstractAxis naively assuming that the Inline axis is Horizontal
+    /// This is currently always true, but will change if Taffy implements the `writing_mode` property
+    #[inline]
+    pub fn as_abstract_naive(&self) -> AbstractAxis {
+        match self {
+            AbsoluteAxis::Horizontal => AbstractAxis::Inline,
+            AbsoluteAxis::Vertical => AbstractAxis::Block,
+        }
+    }
 }
 
 impl<T> Size<T> {
@@ -62,6 +72,16 @@ impl AbstractAxis {
             AbstractAxis::Block => AbstractAxis::Inline,
         }
     }
+
+    /// Convert an AbstractAxis into an AbsoluteAxis naively assuming that the Inline axis is Horizontal
+    /// This is currently always true, but will change if Taffy implements the `writing_mode` property
+    #[inline]
+    pub fn as_abs_naive(&self) -> AbsoluteAxis {
+        match self {
+            AbstractAxis::Inline => AbsoluteAxis::Horizontal,
+            AbstractAxis::Block => AbsoluteAxis::Vertical,
+        }
+    }
 }
 
 /// Container that holds an item in each absolute axis without specifying
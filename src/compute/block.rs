@@ -0,0 +1,683 @@
+//! Computes the CSS block layout algorithm in the case that the block container being laid out contains only block-level boxes
+//!
+//! Children are laid out one after another in [normal flow](https://www.w3.org/TR/CSS22/visuren.html#normal-flow), with
+//! [adjoining vertical margins collapsed](https://www.w3.org/TR/CSS22/box.html#collapsing-margins) between siblings.
+//!
+//! The top and bottom margins of a block container also collapse with the margins of its first and last in-flow child
+//! when no padding or border separates them (and, for the bottom margin, when its height is `auto`), unless the container
+//! establishes a new block formatting context. The root node, non-block containers (such as flexbox and grid containers),
+//! absolutely positioned nodes and nodes whose overflow is not visible establish a new block formatting context.
+use crate::compute::common::content_size::compute_content_size;
+use crate::compute::common::relative_position::compute_relative_offset;
+use crate::compute::{compute_node_layout, COLLAPSIBLE_MARGINS_CACHE_SLOT};
+use crate::geometry::{Line, Point, Rect, Size};
+use crate::layout::{Cache, Layout, RunMode, SizeAndBaseline, SizingMode};
+use crate::math::MaybeMath;
+use crate::resolve::{MaybeResolve, ResolveOrZero};
+use crate::style::{
+    AvailableSpace, Dimension, Direction, Display, LengthPercentageAuto, Overflow, Position, Style, Visibility,
+};
+use crate::style_helpers::TaffyMaxContent;
+use crate::sys::Vec;
+use crate::tree::LayoutTree;
+
+#[cfg(feature = "debug")]
+use crate::debug::NODE_LOGGER;
+
+/// A set of adjoining vertical margins which collapse into a single margin
+///
+/// See: https://www.w3.org/TR/CSS22/box.html#collapsing-margins
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CollapsibleMarginSet {
+    /// The largest positive margin in the set
+    positive: f32,
+    /// The smallest (most negative) margin in the set
+    negative: f32,
+}
+
+impl CollapsibleMarginSet {
+    /// A set which contains no margins
+    const ZERO: Self = Self { positive: 0.0, negative: 0.0 };
+
+    /// Create a set containing a single margin
+    fn from_margin(margin: f32) -> Self {
+        Self::ZERO.collapse_with_margin(margin)
+    }
+
+    /// Collapse all of the margins of another set into the set
+    fn collapse_with_set(self, other: Self) -> Self {
+        Self { positive: self.positive.max(other.positive), negative: self.negative.min(other.negative) }
+    }
+
+    /// Collapse a single margin into the set
+    fn collapse_with_margin(mut self, margin: f32) -> Self {
+        if margin >= 0.0 {
+            self.positive = self.positive.max(margin);
+        } else {
+            self.negative = self.negative.min(margin);
+        }
+        self
+    }
+
+    /// The size of the collapsed margin: the largest positive margin plus the most negative margin
+    fn resolve(&self) -> f32 {
+        self.positive + self.negative
+    }
+}
+
+/// The vertical margins of an in-flow block-level box, each collapsed with the margins of its descendants that they adjoin
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CollapsibleMargins {
+    /// The top margin, collapsed with the top margins of its first in-flow children
+    top: CollapsibleMarginSet,
+    /// The bottom margin, collapsed with the bottom margins of its last in-flow children
+    bottom: CollapsibleMarginSet,
+    /// Whether the box is empty, such that its top and bottom margins are adjoining and collapse through it
+    collapses_through: bool,
+}
+
+/// The intermediate results of a block layout calculation for a single in-flow child
+struct BlockItem<NodeId> {
+    /// The identifier for the associated [`Node`](crate::node::Node)
+    node: NodeId,
+    /// The order of the item in the children array
+    order: u32,
+
+    /// The preferred size of the item, resolved against the container's content box
+    size: Size<Option<f32>>,
+    /// The minimum allowable size of this item
+    min_size: Size<Option<f32>>,
+    /// The maximum allowable size of this item
+    max_size: Size<Option<f32>>,
+
+    /// The margin style of the item. Auto margins are resolved when the item is positioned.
+    margin: Rect<LengthPercentageAuto>,
+    /// The offset of the item from its position in flow (if it is relatively positioned)
+    relative_offset: Point<f32>,
+    /// The resolved visibility of the item
+    visibility: Visibility,
+}
+
+/// Computes the layout of [`LayoutTree`] according to the block layout algorithm
+pub fn compute<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+) -> SizeAndBaseline {
+    let style = tree.style(node);
+    let parent_size = available_space.into_options();
+
+    let min_size = style.min_size.maybe_resolve(parent_size);
+    let max_size = style.max_size.maybe_resolve(parent_size);
+    let clamped_style_size = style.size.maybe_resolve(parent_size).maybe_clamp(min_size, max_size);
+    let node_size = known_dimensions.or(clamped_style_size);
+
+    // Note: both horizontal and vertical percentage padding/borders are resolved against the container's inline size (i.e. width).
+    let padding = style.padding.resolve_or_zero(parent_size.width);
+    let border = style.border.resolve_or_zero(parent_size.width);
+    // Space reserved for scrollbars is treated as additional padding at the end edge of each axis
+    let scrollbar_gutter = style.scrollbar_gutter();
+    let padding_border = Rect {
+        left: padding.left + border.left,
+        right: padding.right + border.right + scrollbar_gutter.width,
+        top: padding.top + border.top,
+        bottom: padding.bottom + border.bottom + scrollbar_gutter.height,
+    };
+    let padding_border_size = padding_border.sum_axes();
+
+    // The margins of a block container collapse with the margins of its children if it is itself laid out in the normal
+    // flow of a block container (the root node always establishes a new block formatting context)
+    let has_block_parent = tree.parent(node).is_some_and(|parent| tree.style(parent).display == Display::Block);
+    let margins_collapse_with_children = match has_block_parent {
+        true => determine_if_margins_collapse_with_children(style, padding_border),
+        false => Line { start: false, end: false },
+    };
+
+    // Return early if both width and height are known and we only need the node's size
+    if run_mode == RunMode::ComputeSize {
+        if let Size { width: Some(width), height: Some(height) } = node_size {
+            return Size { width, height }.into();
+        }
+    }
+
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.labelled_debug_log("node_size", node_size);
+
+    // 1. Determine the width of the container.
+    // Block-level children of a block container are stretched to fill it (see below), so a container whose width is still unknown
+    // at this point (e.g. the root node, or a flex item whose flex basis is being determined) is sized to fit its contents.
+    let container_width = node_size.width.unwrap_or_else(|| {
+        let available_width = available_space.width.maybe_sub(padding_border_size.width);
+        let content_width = determine_content_based_container_width(tree, node, available_width);
+        (content_width + padding_border_size.width)
+            .maybe_clamp(min_size.width, max_size.width)
+            .max(padding_border_size.width)
+    });
+    let inner_size = Size {
+        width: Some(container_width - padding_border_size.width),
+        height: node_size.height.maybe_sub(padding_border_size.height),
+    };
+
+    // 2. Size and position in-flow children
+    let direction = tree.direction(node);
+    let visibility = tree.visibility(node);
+    let mut items = generate_item_list(tree, node, inner_size, direction, visibility);
+    let (content_height, first_baseline) = perform_final_layout_on_in_flow_children(
+        tree,
+        &mut items,
+        inner_size,
+        padding_border,
+        margins_collapse_with_children,
+        direction,
+        run_mode,
+    );
+
+    // 3. Determine the height of the container
+    let container_height = node_size
+        .height
+        .unwrap_or(content_height + padding_border.bottom)
+        .maybe_clamp(min_size.height, max_size.height)
+        .max(padding_border_size.height);
+    let container_size = Size { width: container_width, height: container_height };
+
+    if run_mode == RunMode::ComputeSize {
+        return SizeAndBaseline { size: container_size, first_baseline, content_size: Size::ZERO };
+    }
+
+    // 4. Perform layout on hidden and absolutely positioned children
+    perform_hidden_layout_on_hidden_children(tree, node);
+    perform_absolute_layout_on_absolute_children(
+        tree,
+        node,
+        direction,
+        visibility,
+        container_size,
+        border,
+        padding_border,
+    );
+
+    // 5. Determine the content size of the container
+    let content_size = compute_content_size(tree, node);
+
+    SizeAndBaseline { size: container_size, first_baseline, content_size }
+}
+
+/// Create a [`BlockItem`] for each in-flow child of the container
+#[inline]
+fn generate_item_list<Tree: LayoutTree>(
+    tree: &Tree,
+    node: Tree::NodeId,
+    inner_size: Size<Option<f32>>,
+    direction: Direction,
+    visibility: Visibility,
+) -> Vec<BlockItem<Tree::NodeId>> {
+    tree.children(node)
+        .enumerate()
+        .map(|(index, child)| (index, child, tree.style(child)))
+        .filter(|(_, _, style)| !style.position.is_out_of_flow())
+        .filter(|(_, _, style)| style.display != Display::None)
+        .map(|(index, child, child_style)| BlockItem {
+            node: child,
+            order: index as u32,
+            size: child_style.size.maybe_resolve(inner_size),
+            min_size: child_style.min_size.maybe_resolve(inner_size),
+            max_size: child_style.max_size.maybe_resolve(inner_size),
+            margin: child_style.margin,
+            relative_offset: compute_relative_offset(child_style, direction, inner_size),
+            visibility: child_style.visibility.unwrap_or(visibility),
+        })
+        .collect()
+}
+
+/// Compute the content-based width of a block container: the largest outer width of any of its in-flow children
+#[inline]
+fn determine_content_based_container_width<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    available_width: AvailableSpace,
+) -> f32 {
+    let mut max_child_width: f32 = 0.0;
+    for index in 0..tree.child_count(node) {
+        let child = tree.child(node, index);
+        let child_style = tree.style(child);
+        if child_style.position.is_out_of_flow() || child_style.display == Display::None {
+            continue;
+        }
+
+        // Percentage margins resolve to zero here as the width of the containing block is not yet known
+        let margin_sum = child_style.margin.resolve_or_zero(None).horizontal_axis_sum();
+        let child_width = compute_node_layout(
+            tree,
+            child,
+            Size::NONE,
+            Size { width: available_width.maybe_sub(margin_sum), height: AvailableSpace::MinContent },
+            RunMode::ComputeSize,
+            SizingMode::InherentSize,
+        )
+        .size
+        .width;
+
+        max_child_width = max_child_width.max(child_width + margin_sum);
+    }
+
+    max_child_width
+}
+
+/// Whether a node establishes a new block formatting context, in which case its margins never collapse with the margins
+/// of its children
+#[inline]
+fn establishes_block_formatting_context(style: &Style) -> bool {
+    style.display != Display::Block
+        || style.position.is_out_of_flow()
+        || style.overflow.x != Overflow::Visible
+        || style.overflow.y != Overflow::Visible
+}
+
+/// Whether the top and bottom margins of a block container in the normal flow of a block container collapse with the
+/// margins of its first and last in-flow children respectively
+#[inline]
+fn determine_if_margins_collapse_with_children(style: &Style, padding_border: Rect<f32>) -> Line<bool> {
+    if establishes_block_formatting_context(style) {
+        return Line { start: false, end: false };
+    }
+    Line { start: padding_border.top == 0.0, end: padding_border.bottom == 0.0 && style.size.height == Dimension::Auto }
+}
+
+/// Determine the collapsible margins of an in-flow child of a block container, where `parent_inner_width` is the
+/// content box width of the block container.
+///
+/// The margins only depend on the styles of the child and its descendants, so they are cached for the child (until it is
+/// marked dirty) rather than being determined again each time the container is sized or laid out.
+fn determine_collapsible_margins<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    parent_inner_width: f32,
+) -> CollapsibleMargins {
+    let available_space =
+        Size { width: AvailableSpace::Definite(parent_inner_width), height: AvailableSpace::MaxContent };
+    let cached_margins = tree
+        .cache_mut(node, COLLAPSIBLE_MARGINS_CACHE_SLOT)
+        .filter(|entry| entry.available_space == available_space)
+        .and_then(|entry| entry.cached_collapsible_margins);
+    if let Some(margins) = cached_margins {
+        return margins;
+    }
+
+    let margins = compute_collapsible_margins(tree, node, parent_inner_width);
+    *tree.cache_mut(node, COLLAPSIBLE_MARGINS_CACHE_SLOT) = Some(Cache {
+        known_dimensions: Size::NONE,
+        available_space,
+        rounded_position: None,
+        cached_size: Size::ZERO,
+        cached_baseline: None,
+        cached_content_size: Size::ZERO,
+        cached_collapsible_margins: Some(margins),
+    });
+    margins
+}
+
+/// Compute the collapsible margins of an in-flow child of a block container from its styles and the (cached) collapsible
+/// margins of its own in-flow children
+fn compute_collapsible_margins<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    parent_inner_width: f32,
+) -> CollapsibleMargins {
+    let style = tree.style(node);
+    let margin = style.margin.resolve_or_zero(Some(parent_inner_width));
+    let mut top = CollapsibleMarginSet::from_margin(margin.top);
+    let mut bottom = CollapsibleMarginSet::from_margin(margin.bottom);
+
+    let padding = style.padding.resolve_or_zero(Some(parent_inner_width));
+    let border = style.border.resolve_or_zero(Some(parent_inner_width));
+    let padding_border = Rect {
+        left: padding.left + border.left,
+        right: padding.right + border.right,
+        top: padding.top + border.top,
+        bottom: padding.bottom + border.bottom,
+    };
+    // Whether the height of the box is zero if it has no in-flow content
+    let is_empty_box_zero_height = style.size.height.maybe_resolve(None).unwrap_or(0.0) == 0.0
+        && style.min_size.height.maybe_resolve(None).unwrap_or(0.0) <= 0.0
+        && padding_border.vertical_axis_sum() == 0.0;
+
+    // Leaves that are measured may have content, so only the margins of unmeasured empty leaves collapse through them
+    if tree.is_childless(node) {
+        let collapses_through = is_empty_box_zero_height && !tree.needs_measure(node);
+        return CollapsibleMargins { top, bottom, collapses_through };
+    }
+
+    let margins_collapse_with_children = determine_if_margins_collapse_with_children(style, padding_border);
+    if !margins_collapse_with_children.start && !margins_collapse_with_children.end {
+        return CollapsibleMargins { top, bottom, collapses_through: false };
+    }
+
+    // Block-level children with an auto width stretch to fill the container (less their margins)
+    let width = style
+        .size
+        .width
+        .maybe_resolve(Some(parent_inner_width))
+        .unwrap_or(parent_inner_width - margin.horizontal_axis_sum());
+    let inner_width = width - padding_border.horizontal_axis_sum();
+    let child_count = tree.child_count(node);
+    let is_in_flow = |tree: &Tree, child: Tree::NodeId| {
+        let child_style = tree.style(child);
+        !child_style.position.is_out_of_flow() && child_style.display != Display::None
+    };
+
+    // The top margin collapses with the top margins of the first in-flow children, up to and including the first child
+    // that its margins don't collapse through
+    let mut all_children_collapse_through = true;
+    if margins_collapse_with_children.start {
+        for index in 0..child_count {
+            let child = tree.child(node, index);
+            if !is_in_flow(tree, child) {
+                continue;
+            }
+            let child_margins = determine_collapsible_margins(tree, child, inner_width);
+            top = top.collapse_with_set(child_margins.top);
+            if !child_margins.collapses_through {
+                all_children_collapse_through = false;
+                break;
+            }
+            top = top.collapse_with_set(child_margins.bottom);
+        }
+    }
+
+    // The bottom margin collapses with the bottom margins of the last in-flow children in the same way
+    if margins_collapse_with_children.end {
+        for index in (0..child_count).rev() {
+            let child = tree.child(node, index);
+            if !is_in_flow(tree, child) {
+                continue;
+            }
+            let child_margins = determine_collapsible_margins(tree, child, inner_width);
+            bottom = bottom.collapse_with_set(child_margins.bottom);
+            if !child_margins.collapses_through {
+                break;
+            }
+            bottom = bottom.collapse_with_set(child_margins.top);
+        }
+    }
+
+    let collapses_through = margins_collapse_with_children.start
+        && margins_collapse_with_children.end
+        && all_children_collapse_through
+        && is_empty_box_zero_height;
+    CollapsibleMargins { top, bottom, collapses_through }
+}
+
+/// Size and position each in-flow child, stacking them vertically and collapsing adjoining margins between siblings.
+/// Margins that collapse with the container's own top or bottom margin (see [`determine_collapsible_margins`]) are not
+/// applied. Children are placed against the start edge of the container's content box in its `direction`.
+///
+/// Returns the y-coordinate of the bottom margin edge of the last child (relative to the container's border box), and the
+/// first baseline of the container: the first baseline of its first in-flow child that has one (children without a
+/// baseline, such as empty leaf nodes, are skipped).
+#[inline]
+fn perform_final_layout_on_in_flow_children<Tree: LayoutTree>(
+    tree: &mut Tree,
+    items: &mut [BlockItem<Tree::NodeId>],
+    inner_size: Size<Option<f32>>,
+    padding_border: Rect<f32>,
+    margins_collapse_with_children: Line<bool>,
+    direction: Direction,
+    run_mode: RunMode,
+) -> (f32, Option<f32>) {
+    let container_inner_width = inner_size.width.unwrap_or(0.0);
+    let child_available_space = Size {
+        width: AvailableSpace::Definite(container_inner_width),
+        height: inner_size.height.map(AvailableSpace::Definite).unwrap_or(AvailableSpace::MaxContent),
+    };
+
+    // The y-coordinate of the bottom border edge of the last laid out child
+    let mut committed_y_offset = padding_border.top;
+    // Margins which have been encountered but not yet been applied
+    let mut pending_margins = CollapsibleMarginSet::ZERO;
+    // Whether a child that margins don't collapse through has been laid out. Until then, margins collapse with the
+    // container's top margin if it collapses with the margins of its children.
+    let mut has_committed_child = false;
+    let mut first_baseline = None;
+
+    for item in items.iter_mut() {
+        // Note: vertical percentage margins are also resolved against the container's inline size (i.e. width).
+        let margin = item.margin.resolve_or_zero(inner_size.width);
+
+        // Block-level children with an auto width stretch to fill the container (less their margins)
+        let item_width = item
+            .size
+            .width
+            .unwrap_or(container_inner_width - margin.horizontal_axis_sum())
+            .maybe_clamp(item.min_size.width, item.max_size.width);
+
+        // Determine the height of the item, laying it out at its content height if its height is auto
+        let item_height =
+            item.size.height.maybe_clamp(item.min_size.height, item.max_size.height).unwrap_or_else(|| {
+                compute_node_layout(
+                    tree,
+                    item.node,
+                    Size { width: Some(item_width), height: None },
+                    child_available_space,
+                    RunMode::ComputeSize,
+                    SizingMode::ContentSize,
+                )
+                .size
+                .height
+                .maybe_clamp(item.min_size.height, item.max_size.height)
+            });
+
+        // If only the size of the container is needed, the items are only sized (for their baselines) until the first
+        // baseline of the container has been found
+        let item_known_dimensions = Size { width: Some(item_width), height: Some(item_height) };
+        let SizeAndBaseline { size: item_size, first_baseline: item_first_baseline, content_size } = match run_mode {
+            RunMode::PeformLayout => compute_node_layout(
+                tree,
+                item.node,
+                item_known_dimensions,
+                child_available_space,
+                RunMode::PeformLayout,
+                SizingMode::ContentSize,
+            ),
+            RunMode::ComputeSize if first_baseline.is_none() => compute_node_layout(
+                tree,
+                item.node,
+                item_known_dimensions,
+                child_available_space,
+                RunMode::ComputeSize,
+                SizingMode::ContentSize,
+            ),
+            RunMode::ComputeSize => Size { width: item_width, height: item_height }.into(),
+        };
+
+        // Resolve auto margins in the horizontal axis
+        let free_space = container_inner_width - item_size.width - margin.horizontal_axis_sum();
+        let margin_left = match (item.margin.left, item.margin.right) {
+            (LengthPercentageAuto::Auto, LengthPercentageAuto::Auto) => free_space.max(0.0) / 2.0,
+            (LengthPercentageAuto::Auto, _) => free_space.max(0.0),
+            _ => margin.left,
+        };
+        let margin_right = match item.margin.right {
+            LengthPercentageAuto::Auto => (container_inner_width - item_size.width - margin_left).max(0.0),
+            _ => margin.right,
+        };
+
+        // Collapse the item's top margin with any preceding margins. If the item is empty then its top and bottom
+        // margins are adjoining and the margins collapse through it.
+        let item_margins = determine_collapsible_margins(tree, item.node, container_inner_width);
+        pending_margins = pending_margins.collapse_with_set(item_margins.top);
+        let is_collapsed_through = item_size.height == 0.0 && item_margins.collapses_through;
+        let y = match margins_collapse_with_children.start && !has_committed_child {
+            true => committed_y_offset,
+            false => committed_y_offset + pending_margins.resolve(),
+        };
+        first_baseline = first_baseline.or(item_first_baseline.map(|baseline| y + item.relative_offset.y + baseline));
+        if is_collapsed_through {
+            pending_margins = pending_margins.collapse_with_set(item_margins.bottom);
+        } else {
+            committed_y_offset = y + item_size.height;
+            pending_margins = item_margins.bottom;
+            has_committed_child = true;
+        }
+
+        if run_mode == RunMode::PeformLayout {
+            let item_style = tree.style(item.node);
+            let padding = item_style.padding.resolve_or_zero(inner_size.width);
+            let border = item_style.border.resolve_or_zero(inner_size.width);
+            // If the item overflows the container then the margin on its end edge is ignored
+            let x = match direction {
+                Direction::Ltr => padding_border.left + margin_left,
+                Direction::Rtl => padding_border.left + container_inner_width - margin_right - item_size.width,
+            };
+            *tree.layout_mut(item.node) = Layout {
+                order: item.order,
+                size: item_size,
+                location: Point { x: x + item.relative_offset.x, y: y + item.relative_offset.y },
+                padding,
+                border,
+                margin: Rect { left: margin_left, right: margin_right, ..margin },
+                content_size,
+                first_baseline: item_first_baseline,
+                visibility: item.visibility,
+            };
+        }
+    }
+
+    // Trailing margins which collapse with the container's top or bottom margin are not applied
+    let trailing_margins_collapse_with_container =
+        margins_collapse_with_children.end || (margins_collapse_with_children.start && !has_committed_child);
+    let content_height = match trailing_margins_collapse_with_container {
+        true => committed_y_offset,
+        false => committed_y_offset + pending_margins.resolve(),
+    };
+
+    (content_height, first_baseline)
+}
+
+/// Perform hidden layout on all children with `display: none`
+#[inline]
+fn perform_hidden_layout_on_hidden_children<Tree: LayoutTree>(tree: &mut Tree, node: Tree::NodeId) {
+    for order in 0..tree.child_count(node) {
+        let child = tree.child(node, order);
+        if tree.style(child).display == Display::None {
+            *tree.layout_mut(child) = Layout::with_order(order as u32);
+            compute_node_layout(
+                tree,
+                child,
+                Size::NONE,
+                Size::MAX_CONTENT,
+                RunMode::PeformLayout,
+                SizingMode::InherentSize,
+            );
+        }
+    }
+}
+
+/// Perform absolute layout on all absolutely positioned children.
+/// Absolutely positioned children are positioned relative to the container's padding box.
+#[inline]
+fn perform_absolute_layout_on_absolute_children<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    direction: Direction,
+    visibility: Visibility,
+    container_size: Size<f32>,
+    border: Rect<f32>,
+    padding_border: Rect<f32>,
+) {
+    // Absolutely positioned children of static nodes are laid out by their containing block instead
+    if !tree.style(node).position.is_positioned() {
+        return;
+    }
+
+    let area_size = Size {
+        width: container_size.width - border.horizontal_axis_sum(),
+        height: container_size.height - border.vertical_axis_sum(),
+    };
+
+    for order in 0..tree.child_count(node) {
+        let child = tree.child(node, order);
+        let child_style = tree.style(child);
+        if child_style.position != Position::Absolute || child_style.display == Display::None {
+            continue;
+        }
+
+        let inset = child_style.inset.zip_size(area_size, |p, s| p.maybe_resolve(s));
+        let margin = child_style.margin.resolve_or_zero(Some(area_size.width));
+        let min_size = child_style.min_size.maybe_resolve(area_size);
+        let max_size = child_style.max_size.maybe_resolve(area_size);
+        let mut known_dimensions = child_style.size.maybe_resolve(area_size).maybe_clamp(min_size, max_size);
+
+        // Fill in width from left/right and height from top/bottom if appropriate
+        if let (None, Some(left), Some(right)) = (known_dimensions.width, inset.left, inset.right) {
+            known_dimensions.width = Some(area_size.width - left - right - margin.horizontal_axis_sum());
+        }
+        if let (None, Some(top), Some(bottom)) = (known_dimensions.height, inset.top, inset.bottom) {
+            known_dimensions.height = Some(area_size.height - top - bottom - margin.vertical_axis_sum());
+        }
+
+        let SizeAndBaseline { size, first_baseline, content_size } = compute_node_layout(
+            tree,
+            child,
+            known_dimensions,
+            Size {
+                width: AvailableSpace::Definite(area_size.width),
+                height: AvailableSpace::Definite(area_size.height),
+            },
+            RunMode::PeformLayout,
+            SizingMode::ContentSize,
+        );
+        let size = size.maybe_clamp(min_size, max_size);
+
+        // Items with no insets in an axis are placed at the start of the container's content box, and if both insets in
+        // an axis are set then the inset on the start edge wins
+        let from_left = inset.left.map(|left| border.left + left + margin.left);
+        let from_right =
+            inset.right.map(|right| container_size.width - border.right - right - margin.right - size.width);
+        let x = match direction {
+            Direction::Ltr => from_left.or(from_right).unwrap_or(padding_border.left + margin.left),
+            Direction::Rtl => from_right
+                .or(from_left)
+                .unwrap_or(container_size.width - padding_border.right - margin.right - size.width),
+        };
+        let y = match (inset.top, inset.bottom) {
+            (Some(top), _) => border.top + top + margin.top,
+            (None, Some(bottom)) => container_size.height - border.bottom - bottom - margin.bottom - size.height,
+            (None, None) => padding_border.top + margin.top,
+        };
+
+        let child_style = tree.style(child);
+        let visibility = child_style.visibility.unwrap_or(visibility);
+        *tree.layout_mut(child) = Layout {
+            order: order as u32,
+            size,
+            location: Point { x, y },
+            padding: child_style.padding.resolve_or_zero(Some(area_size.width)),
+            border: child_style.border.resolve_or_zero(Some(area_size.width)),
+            margin,
+            content_size,
+            first_baseline,
+            visibility,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CollapsibleMarginSet;
+
+    #[test]
+    fn collapsible_margin_set_uses_largest_positive_and_smallest_negative_margin() {
+        let set = CollapsibleMarginSet::from_margin(10.0).collapse_with_margin(20.0).collapse_with_margin(-5.0);
+        assert_eq!(set.resolve(), 15.0);
+
+        let set = set.collapse_with_margin(-15.0).collapse_with_margin(5.0);
+        assert_eq!(set.resolve(), 5.0);
+    }
+
+    #[test]
+    fn empty_collapsible_margin_set_resolves_to_zero() {
+        assert_eq!(CollapsibleMarginSet::ZERO.resolve(), 0.0);
+    }
+}
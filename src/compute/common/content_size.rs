@@ -0,0 +1,63 @@
+//! Computes the size of the scrollable content of a node. This is shared between all of the layout algorithms.
+use crate::geometry::{Point, Size};
+use crate::style::{Display, Overflow, Position};
+use crate::tree::LayoutTree;
+
+/// Computes the content size of a node whose children have already been laid out (i.e. their [`Layout`](crate::layout::Layout)
+/// has been written by the calling layout algorithm).
+///
+/// This is the union of the margin boxes of the node's children, measured from the top-left corner of the node's border box.
+/// Content that overflows a child which has `Overflow::Visible` in an axis also overflows the node in that axis.
+///
+/// Out-of-flow children that are laid out by a different containing block (fixed position children, and absolutely
+/// positioned children of static nodes) contribute to the content size of that containing block instead.
+pub(crate) fn compute_content_size<Tree: LayoutTree>(tree: &Tree, node: Tree::NodeId) -> Size<f32> {
+    let is_containing_block = tree.style(node).position.is_positioned();
+
+    let mut content_size = Size::ZERO;
+    for child in tree.children(node) {
+        let style = tree.style(child);
+        if style.display == Display::None {
+            continue;
+        }
+        match style.position {
+            Position::Fixed => continue,
+            Position::Absolute if !is_containing_block => continue,
+            _ => {}
+        }
+
+        let contribution = compute_content_contribution(tree, child, tree.layout(child).location);
+        content_size.width = content_size.width.max(contribution.width);
+        content_size.height = content_size.height.max(contribution.height);
+    }
+    content_size
+}
+
+/// Computes the far edges of the area covered by an already laid out `node` (its margin box, plus any content that
+/// overflows it in axes where it has `Overflow::Visible`), given the `location` of the node within the node whose
+/// content size is being computed.
+pub(crate) fn compute_content_contribution<Tree: LayoutTree>(
+    tree: &Tree,
+    node: Tree::NodeId,
+    location: Point<f32>,
+) -> Size<f32> {
+    let style = tree.style(node);
+    let layout = tree.layout(node);
+
+    let margin_box_end = Size {
+        width: location.x + layout.size.width + layout.margin.right,
+        height: location.y + layout.size.height + layout.margin.bottom,
+    };
+    let overflow_end = Size {
+        width: match style.overflow.x {
+            Overflow::Visible => location.x + layout.content_size.width,
+            _ => 0.0,
+        },
+        height: match style.overflow.y {
+            Overflow::Visible => location.y + layout.content_size.height,
+            _ => 0.0,
+        },
+    };
+
+    Size { width: margin_box_end.width.max(overflow_end.width), height: margin_box_end.height.max(overflow_end.height) }
+}
@@ -0,0 +1,28 @@
+//! Implements the `direction` property for the Flexbox and CSS Grid algorithms.
+//!
+//! Right-to-left containers are laid out as if they were left-to-right with the horizontal edges of the container and
+//! its children swapped (see [`Rect::for_direction`](crate::geometry::Rect::for_direction)). The resulting child
+//! positions are then mirrored back into physical coordinates.
+//!
+//! <https://www.w3.org/TR/css-writing-modes-3/#direction>
+use crate::style::{Direction, Display};
+use crate::tree::LayoutTree;
+
+/// Mirrors the horizontal position of each of the children of a right-to-left `node` within the node's border box
+/// (of width `container_width`), and swaps their resolved left and right margins back into physical edges.
+pub(crate) fn mirror_children_horizontally<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    container_width: f32,
+) {
+    for index in 0..tree.child_count(node) {
+        let child = tree.child(node, index);
+        if tree.style(child).display == Display::None {
+            continue;
+        }
+
+        let layout = tree.layout_mut(child);
+        layout.location.x = container_width - layout.location.x - layout.size.width;
+        layout.margin = layout.margin.for_direction(Direction::Rtl);
+    }
+}
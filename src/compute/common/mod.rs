@@ -1,2 +1,6 @@
 //! Generic code that is shared between multiple layout algorithms
 pub(crate) mod alignment;
+pub(crate) mod content_size;
+pub(crate) mod direction;
+pub(crate) mod order;
+pub(crate) mod relative_position;
@@ -0,0 +1,27 @@
+//! Implements the `order` property, which reorders the children of flex and grid containers.
+//! This is shared between the Flexbox and CSS Grid algorithms.
+//!
+//! <https://www.w3.org/TR/css-display-3/#order-property>
+use crate::sys::Vec;
+use crate::tree::LayoutTree;
+
+/// Returns the indexes of the children of `node` in [order-modified document order](https://www.w3.org/TR/css-display-3/#order-modified-document-order):
+/// sorted by their `order` style, with children that have the same `order` kept in the order they appear in the tree.
+pub(crate) fn order_modified_document_order<Tree: LayoutTree>(tree: &Tree, node: Tree::NodeId) -> Vec<usize> {
+    let mut child_indexes: Vec<usize> = (0..tree.child_count(node)).collect();
+    // Note: the index is included in the sort key so that children with the same order stay in document order
+    child_indexes.sort_unstable_by_key(|&index| (tree.style(tree.child(node, index)).order, index));
+    child_indexes
+}
+
+/// Returns the painting order of each of the children of `node` (indexed by the position of the child in the tree).
+/// This is the position of the child in order-modified document order, and is used as the child's [`Layout::order`](crate::layout::Layout::order).
+pub(crate) fn compute_painting_order<Tree: LayoutTree>(tree: &Tree, node: Tree::NodeId) -> Vec<u32> {
+    let mut painting_order: Vec<(usize, u32)> = order_modified_document_order(tree, node)
+        .into_iter()
+        .enumerate()
+        .map(|(order, child_index)| (child_index, order as u32))
+        .collect();
+    painting_order.sort_unstable_by_key(|(child_index, _)| *child_index);
+    painting_order.into_iter().map(|(_, order)| order).collect()
+}
@@ -0,0 +1,33 @@
+//! Implements relative positioning, which visually offsets a node from its position in flow without affecting the layout
+//! of any other node. This is shared between all of the layout algorithms.
+//!
+//! <https://www.w3.org/TR/css-position-3/#relpos-insets>
+use crate::geometry::{Point, Size};
+use crate::resolve::MaybeResolve;
+use crate::style::{Direction, Position, Style};
+
+/// Computes the offset of a node from its position in flow. This is zero unless the node is relatively positioned.
+///
+/// Percentage insets are resolved against the size of the node's containing block (and are treated as `auto` if that is
+/// indefinite). If both insets in an axis are set then the inset on the start edge wins: `top` in the vertical axis, and
+/// `left` (or `right` for right-to-left content) in the horizontal axis.
+pub(crate) fn compute_relative_offset(
+    style: &Style,
+    direction: Direction,
+    containing_block_size: Size<Option<f32>>,
+) -> Point<f32> {
+    if style.position != Position::Relative {
+        return Point::ZERO;
+    }
+
+    let inset = style.inset.zip_size(containing_block_size, |inset, size| inset.maybe_resolve(size));
+    let from_left = inset.left;
+    let from_right = inset.right.map(|right| -right);
+    let x = match direction {
+        Direction::Ltr => from_left.or(from_right),
+        Direction::Rtl => from_right.or(from_left),
+    };
+    let y = inset.top.or(inset.bottom.map(|bottom| -bottom));
+
+    Point { x: x.unwrap_or(0.0), y: y.unwrap_or(0.0) }
+}
@@ -4,16 +4,19 @@
 use core::f32;
 
 use crate::compute::common::alignment::compute_alignment_offset;
-use crate::compute::compute_node_layout;
+use crate::compute::common::content_size::compute_content_size;
+use crate::compute::common::direction::mirror_children_horizontally;
+use crate::compute::common::order::{compute_painting_order, order_modified_document_order};
+use crate::compute::common::relative_position::compute_relative_offset;
+use crate::compute::{compute_node_layout, perform_hidden_layout};
 use crate::geometry::{Point, Rect, Size};
-use crate::layout::{Layout, RunMode, SizingMode};
+use crate::layout::{Layout, LayoutInput, RunMode, SizeAndBaseline, SizingMode};
 use crate::math::MaybeMath;
-use crate::node::Node;
 use crate::prelude::{TaffyMaxContent, TaffyMinContent};
 use crate::resolve::{MaybeResolve, ResolveOrZero};
 use crate::style::{
-    AlignContent, AlignItems, AlignSelf, AvailableSpace, Dimension, Display, FlexWrap, JustifyContent,
-    LengthPercentageAuto, Position,
+    AlignContent, AlignItems, AlignSelf, AvailableSpace, Dimension, Direction, Display, FlexWrap, JustifyContent,
+    LengthPercentageAuto, Overflow, Position, Visibility,
 };
 use crate::style::{FlexDirection, Style};
 use crate::sys::Vec;
@@ -23,9 +26,11 @@ use crate::tree::LayoutTree;
 use crate::debug::NODE_LOGGER;
 
 /// The intermediate results of a flexbox calculation for a single item
-struct FlexItem {
+struct FlexItem<NodeId> {
     /// The identifier for the associated [`Node`](crate::node::Node)
-    node: Node,
+    node: NodeId,
+    /// The painting order of the item (its position amongst its siblings in order-modified document order)
+    order: u32,
 
     /// The base size of this item
     size: Size<Option<f32>>,
@@ -35,13 +40,15 @@ struct FlexItem {
     max_size: Size<Option<f32>>,
     /// The cross-alignment of this item
     align_self: AlignSelf,
+    /// The overflow style of the item
+    overflow: Point<Overflow>,
 
     /// The minimum size of the item. This differs from min_size above because it also
     /// takes into account content based automatic minimum sizes
     resolved_minimum_size: Size<f32>,
 
-    /// The final offset of this item
-    inset: Rect<Option<f32>>,
+    /// The offset of this item from its position in flow (if it is relatively positioned)
+    relative_offset: Point<f32>,
     /// The margin of this item
     margin: Rect<f32>,
     /// The padding of this item
@@ -57,6 +64,12 @@ struct FlexItem {
     violation: f32,
     /// Is the size of this item locked
     frozen: bool,
+    /// The resolved visibility of this item
+    visibility: Visibility,
+    /// Does this item have `visibility: collapse`
+    is_collapsed: bool,
+    /// The cross size of the line this item was in during the first layout round, if it is collapsed
+    strut_size: Option<f32>,
 
     /// The proposed inner size of this item
     hypothetical_inner_size: Size<f32>,
@@ -83,9 +96,9 @@ struct FlexItem {
 }
 
 /// A line of [`FlexItem`] used for intermediate computation
-struct FlexLine<'a> {
+struct FlexLine<'a, NodeId> {
     /// The slice of items to iterate over during computation of this line
-    items: &'a mut [FlexItem],
+    items: &'a mut [FlexItem<NodeId>],
     /// The length in the main-axis that this line contributes to the overall main
     /// main size of the container.
     container_main_size_contribution: f32,
@@ -93,9 +106,12 @@ struct FlexLine<'a> {
     cross_size: f32,
     /// The relative offset of the cross-axis
     offset_cross: f32,
+    /// The largest strut size among the collapsed items of this line (which are not included in `items`)
+    strut_size: Option<f32>,
 }
 
 /// Values that can be cached during the flexbox algorithm
+#[derive(Clone, Copy)]
 struct AlgoConstants {
     /// The direction of the current segment being laid out
     dir: FlexDirection,
@@ -105,6 +121,10 @@ struct AlgoConstants {
     is_column: bool,
     /// Is the wrap direction inverted
     is_wrap_reverse: bool,
+    /// The direction in which inline content flows. Horizontal edges are swapped for right-to-left containers.
+    direction: Direction,
+    /// The resolved visibility of the container, which is inherited by children that don't set one
+    visibility: Visibility,
 
     /// The margin of this section
     margin: Rect<f32>,
@@ -126,13 +146,13 @@ struct AlgoConstants {
 }
 
 /// Computes the layout of [`LayoutTree`] according to the flexbox algorithm
-pub fn compute(
-    tree: &mut impl LayoutTree,
-    node: Node,
+pub fn compute<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
     known_dimensions: Size<Option<f32>>,
     available_space: Size<AvailableSpace>,
     run_mode: RunMode,
-) -> Size<f32> {
+) -> SizeAndBaseline {
     let style = tree.style(node);
     let has_min_max_sizes = style.min_size.width.is_defined()
         || style.min_size.height.is_defined()
@@ -154,7 +174,8 @@ pub fn compute(
             known_dimensions.zip_map(clamped_style_size, |known, style| known.or(style)),
             available_space,
             RunMode::ComputeSize,
-        );
+        )
+        .size;
 
         let clamped_first_pass_size = first_pass.maybe_clamp(min_size, max_size);
 
@@ -173,15 +194,17 @@ pub fn compute(
 }
 
 /// Compute a preliminary size for an item
-fn compute_preliminary(
-    tree: &mut impl LayoutTree,
-    node: Node,
+fn compute_preliminary<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
     known_dimensions: Size<Option<f32>>,
     parent_size: Size<AvailableSpace>,
     run_mode: RunMode,
-) -> Size<f32> {
+) -> SizeAndBaseline {
     // Define some general constants we will need for the remainder of the algorithm.
-    let mut constants = compute_constants(tree.style(node), known_dimensions, parent_size);
+    let direction = tree.direction(node);
+    let visibility = tree.visibility(node);
+    let mut constants = compute_constants(tree.style(node), direction, visibility, known_dimensions, parent_size);
 
     // 9. Flex Layout Algorithm
 
@@ -215,81 +238,6 @@ fn compute_preliminary(
         NODE_LOGGER.labelled_debug_log("item.resolved_minimum_size", item.resolved_minimum_size);
     }
 
-    // TODO: Add step 4 according to spec: https://www.w3.org/TR/css-flexbox-1/#algo-main-container
-    // 9.3. Main Size Determination
-
-    // 5. Collect flex items into flex lines.
-    #[cfg(feature = "debug")]
-    NODE_LOGGER.log("collect_flex_lines");
-    let mut flex_lines = collect_flex_lines(tree, node, &constants, available_space, &mut flex_items);
-
-    // If container size is undefined, re-resolve gap based on resolved base sizes
-    let original_gap = constants.gap;
-    if constants.node_inner_size.main(constants.dir).is_none() {
-        let longest_line_length = flex_lines.iter().fold(f32::MIN, |acc, line| {
-            let length: f32 = line.items.iter().map(|item| item.hypothetical_outer_size.main(constants.dir)).sum();
-            acc.max(length)
-        });
-
-        let style = tree.style(node);
-        let new_gap = style.gap.main(constants.dir).maybe_resolve(longest_line_length).unwrap_or(0.0);
-        constants.gap.set_main(constants.dir, new_gap);
-    }
-
-    // 6. Resolve the flexible lengths of all the flex items to find their used main size.
-    #[cfg(feature = "debug")]
-    NODE_LOGGER.log("resolve_flexible_lengths");
-    for line in &mut flex_lines {
-        resolve_flexible_lengths(tree, line, &constants, original_gap);
-    }
-
-    // TODO: Cleanup and make according to spec
-    // Not part of the spec from what i can see but seems correct
-    constants.container_size.set_main(
-        constants.dir,
-        known_dimensions.main(constants.dir).unwrap_or({
-            let longest_line =
-                flex_lines.iter().fold(f32::MIN, |acc, line| acc.max(line.container_main_size_contribution));
-            let size = longest_line + constants.padding_border.main_axis_sum(constants.dir);
-            match available_space.main(constants.dir) {
-                AvailableSpace::Definite(val) if flex_lines.len() > 1 && size < val => val,
-                _ => size,
-            }
-        }),
-    );
-
-    constants.inner_container_size.set_main(
-        constants.dir,
-        constants.container_size.main(constants.dir) - constants.padding_border.main_axis_sum(constants.dir),
-    );
-
-    // 9.4. Cross Size Determination
-
-    // 7. Determine the hypothetical cross size of each item.
-    #[cfg(feature = "debug")]
-    NODE_LOGGER.log("determine_hypothetical_cross_size");
-    for line in &mut flex_lines {
-        determine_hypothetical_cross_size(tree, line, &constants, available_space);
-    }
-
-    // TODO - probably should move this somewhere else as it doesn't make a ton of sense here but we need it below
-    // TODO - This is expensive and should only be done if we really require a baseline. aka, make it lazy
-    if has_baseline_child {
-        #[cfg(feature = "debug")]
-        NODE_LOGGER.log("calculate_children_base_lines");
-        calculate_children_base_lines(tree, node, known_dimensions, available_space, &mut flex_lines, &constants);
-    }
-
-    // 8. Calculate the cross size of each flex line.
-    #[cfg(feature = "debug")]
-    NODE_LOGGER.log("calculate_cross_size");
-    calculate_cross_size(tree, &mut flex_lines, known_dimensions, &constants);
-
-    // 9. Handle 'align-content: stretch'.
-    #[cfg(feature = "debug")]
-    NODE_LOGGER.log("handle_align_content_stretch");
-    handle_align_content_stretch(tree, &mut flex_lines, node, known_dimensions, &constants);
-
     // 10. Collapse visibility:collapse items. If any flex items have visibility: collapse,
     //     note the cross size of the line they’re in as the item’s strut size, and restart
     //     layout from the beginning.
@@ -302,8 +250,49 @@ fn compute_preliminary(
     //     that strut size.
     //
     //     Skip this step in the second layout round.
+    //
+    // Note: steps 5 to 9 are performed by `compute_flex_lines`, so the first layout round is run here (before those
+    // steps of the second round) and only if there are collapsed items.
+    if flex_items.iter().any(|item| item.is_collapsed) {
+        #[cfg(feature = "debug")]
+        NODE_LOGGER.log("collapse_visibility_collapse_items");
+        let mut first_round_constants = constants;
+        let flex_lines = compute_flex_lines(
+            tree,
+            node,
+            known_dimensions,
+            available_space,
+            has_baseline_child,
+            &mut first_round_constants,
+            &mut flex_items,
+        );
+        for line in flex_lines {
+            for item in line.items.iter_mut().filter(|item| item.is_collapsed) {
+                item.strut_size = Some(line.cross_size);
+            }
+        }
 
-    // TODO implement once (if ever) we support visibility:collapse
+        let strut_sizes: Vec<Option<f32>> = flex_items.iter().map(|item| item.strut_size).collect();
+        flex_items = generate_anonymous_flex_items(tree, node, &constants);
+        determine_flex_base_size(tree, known_dimensions, &constants, available_space, &mut flex_items);
+        for (item, strut_size) in flex_items.iter_mut().zip(strut_sizes) {
+            item.strut_size = strut_size;
+        }
+    }
+
+    // Collapsed items are removed from their lines by `collect_flex_lines`, so note them here to lay them out as hidden
+    let collapsed_items: Vec<(Tree::NodeId, u32)> =
+        flex_items.iter().filter(|item| item.is_collapsed).map(|item| (item.node, item.order)).collect();
+
+    let mut flex_lines = compute_flex_lines(
+        tree,
+        node,
+        known_dimensions,
+        available_space,
+        has_baseline_child,
+        &mut constants,
+        &mut flex_items,
+    );
 
     // 11. Determine the used cross size of each flex item.
     #[cfg(feature = "debug")]
@@ -329,28 +318,40 @@ fn compute_preliminary(
     NODE_LOGGER.log("determine_container_cross_size");
     let total_line_cross_size = determine_container_cross_size(&mut flex_lines, known_dimensions, &mut constants);
 
-    // We have the container size.
-    // If our caller does not care about performing layout we are done now.
-    if run_mode == RunMode::ComputeSize {
-        let container_size = constants.container_size;
-        return container_size;
-    }
-
     // 16. Align all flex lines per align-content.
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("align_flex_lines_per_align_content");
     align_flex_lines_per_align_content(tree, &mut flex_lines, node, &constants, total_line_cross_size);
 
+    // We have the container size.
+    // If our caller does not care about performing layout we are done now (once the container's first baseline has been
+    // determined from the sizes of the items).
+    if run_mode == RunMode::ComputeSize {
+        let first_baseline = compute_first_baseline(tree, &flex_lines, &constants);
+        return SizeAndBaseline { size: constants.container_size, first_baseline, content_size: Size::ZERO };
+    }
+
     // Do a final layout pass and gather the resulting layouts
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("final_layout_pass");
-    final_layout_pass(tree, node, &mut flex_lines, &constants);
+    final_layout_pass(tree, &mut flex_lines, &constants);
 
     // Before returning we perform absolute layout on all absolutely positioned children
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("perform_absolute_layout_on_absolute_children");
     perform_absolute_layout_on_absolute_children(tree, node, &constants);
 
+    // Right-to-left containers were laid out with their horizontal edges swapped, so mirror the results
+    if constants.direction.is_rtl() {
+        mirror_children_horizontally(tree, node, constants.container_size.width);
+    }
+
+    // Collapsed items are not displayed, so they (and their descendants) are given a hidden layout
+    for (child, order) in collapsed_items {
+        *tree.layout_mut(child) = Layout { visibility: Visibility::Collapse, ..Layout::with_order(order) };
+        perform_hidden_layout(tree, child);
+    }
+
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("hidden_layout");
     let len = tree.child_count(node);
@@ -369,28 +370,138 @@ fn compute_preliminary(
         }
     }
 
-    constants.container_size
+    let first_baseline = determine_first_baseline(tree, &flex_lines, &constants);
+    let content_size = compute_content_size(tree, node);
+
+    SizeAndBaseline { size: constants.container_size, first_baseline, content_size }
+}
+
+/// Collects the flex items into flex lines and determines the cross size of each line (steps 5 to 9 of the algorithm)
+///
+/// This is performed twice if there are any collapsed items: once to determine their strut sizes and once to lay out the
+/// remaining items.
+fn compute_flex_lines<'a, Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    has_baseline_child: bool,
+    constants: &mut AlgoConstants,
+    flex_items: &'a mut Vec<FlexItem<Tree::NodeId>>,
+) -> Vec<FlexLine<'a, Tree::NodeId>> {
+    // TODO: Add step 4 according to spec: https://www.w3.org/TR/css-flexbox-1/#algo-main-container
+    // 9.3. Main Size Determination
+
+    // 5. Collect flex items into flex lines.
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("collect_flex_lines");
+    let mut flex_lines = collect_flex_lines(tree, node, constants, available_space, flex_items);
+
+    // If container size is undefined, re-resolve gap based on resolved base sizes
+    let original_gap = constants.gap;
+    if constants.node_inner_size.main(constants.dir).is_none() {
+        let longest_line_length = flex_lines.iter().fold(f32::MIN, |acc, line| {
+            let length: f32 = line.items.iter().map(|item| item.hypothetical_outer_size.main(constants.dir)).sum();
+            acc.max(length)
+        });
+
+        let style = tree.style(node);
+        let new_gap = style.gap.main(constants.dir).maybe_resolve(longest_line_length).unwrap_or(0.0);
+        constants.gap.set_main(constants.dir, new_gap);
+    }
+
+    // 6. Resolve the flexible lengths of all the flex items to find their used main size.
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("resolve_flexible_lengths");
+    for line in &mut flex_lines {
+        resolve_flexible_lengths(tree, line, constants, original_gap);
+    }
+
+    // TODO: Cleanup and make according to spec
+    // Not part of the spec from what i can see but seems correct
+    constants.container_size.set_main(
+        constants.dir,
+        known_dimensions.main(constants.dir).unwrap_or({
+            let longest_line =
+                flex_lines.iter().fold(f32::MIN, |acc, line| acc.max(line.container_main_size_contribution));
+            let size = longest_line + constants.padding_border.main_axis_sum(constants.dir);
+            match available_space.main(constants.dir) {
+                AvailableSpace::Definite(val) if flex_lines.len() > 1 && size < val => val,
+                _ => size,
+            }
+        }),
+    );
+
+    constants.inner_container_size.set_main(
+        constants.dir,
+        constants.container_size.main(constants.dir) - constants.padding_border.main_axis_sum(constants.dir),
+    );
+
+    // 9.4. Cross Size Determination
+
+    // 7. Determine the hypothetical cross size of each item.
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("determine_hypothetical_cross_size");
+    for line in &mut flex_lines {
+        determine_hypothetical_cross_size(tree, line, constants, available_space);
+    }
+
+    // TODO - probably should move this somewhere else as it doesn't make a ton of sense here but we need it below
+    if has_baseline_child {
+        #[cfg(feature = "debug")]
+        NODE_LOGGER.log("calculate_children_base_lines");
+        calculate_children_base_lines(tree, known_dimensions, available_space, &mut flex_lines, constants);
+    }
+
+    // 8. Calculate the cross size of each flex line.
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("calculate_cross_size");
+    calculate_cross_size(tree, &mut flex_lines, known_dimensions, constants);
+
+    // In the second layout round, the cross size of each line is at least the largest strut size of its collapsed items
+    for line in flex_lines.iter_mut() {
+        if let Some(strut_size) = line.strut_size {
+            line.cross_size = line.cross_size.max(strut_size);
+        }
+    }
+
+    // 9. Handle 'align-content: stretch'.
+    #[cfg(feature = "debug")]
+    NODE_LOGGER.log("handle_align_content_stretch");
+    handle_align_content_stretch(tree, &mut flex_lines, node, known_dimensions, constants);
+
+    flex_lines
 }
 
 /// Compute constants that can be reused during the flexbox algorithm.
 #[inline]
-fn compute_constants(style: &Style, node_size: Size<Option<f32>>, parent_size: Size<AvailableSpace>) -> AlgoConstants {
+fn compute_constants(
+    style: &Style,
+    direction: Direction,
+    visibility: Visibility,
+    node_size: Size<Option<f32>>,
+    parent_size: Size<AvailableSpace>,
+) -> AlgoConstants {
     let dir = style.flex_direction;
     let is_row = dir.is_row();
     let is_column = dir.is_column();
     let is_wrap_reverse = style.flex_wrap == FlexWrap::WrapReverse;
 
-    let margin = style.margin.resolve_or_zero(parent_size.width.into_option());
+    let margin = style.margin.resolve_or_zero(parent_size.width.into_option()).for_direction(direction);
     let padding = style.padding.resolve_or_zero(parent_size.width.into_option());
     let border = style.border.resolve_or_zero(parent_size.width.into_option());
     let align_items = style.align_items.unwrap_or(crate::style::AlignItems::Stretch);
 
+    // Space reserved for scrollbars is treated as additional padding at the end edge of each axis
+    let scrollbar_gutter = style.scrollbar_gutter();
     let padding_border = Rect {
         left: padding.left + border.left,
-        right: padding.right + border.right,
+        right: padding.right + border.right + scrollbar_gutter.width,
         top: padding.top + border.top,
-        bottom: padding.bottom + border.bottom,
-    };
+        bottom: padding.bottom + border.bottom + scrollbar_gutter.height,
+    }
+    .for_direction(direction);
+    let border = border.for_direction(direction);
 
     let node_inner_size = Size {
         width: node_size.width.maybe_sub(padding_border.horizontal_axis_sum()),
@@ -406,6 +517,8 @@ fn compute_constants(style: &Style, node_size: Size<Option<f32>>, parent_size: S
         is_row,
         is_column,
         is_wrap_reverse,
+        direction,
+        visibility,
         margin,
         border,
         gap,
@@ -423,26 +536,46 @@ fn compute_constants(style: &Style, node_size: Size<Option<f32>>, parent_size: S
 ///
 /// - [**Generate anonymous flex items**](https://www.w3.org/TR/css-flexbox-1/#algo-anon-box) as described in [§4 Flex Items](https://www.w3.org/TR/css-flexbox-1/#flex-items).
 #[inline]
-fn generate_anonymous_flex_items(tree: &impl LayoutTree, node: Node, constants: &AlgoConstants) -> Vec<FlexItem> {
-    tree.children(node)
-        .map(|child| (child, tree.style(*child)))
-        .filter(|(_, style)| style.position != Position::Absolute)
-        .filter(|(_, style)| style.display != Display::None)
-        .map(|(child, child_style)| FlexItem {
-            node: *child,
+fn generate_anonymous_flex_items<Tree: LayoutTree>(
+    tree: &Tree,
+    node: Tree::NodeId,
+    constants: &AlgoConstants,
+) -> Vec<FlexItem<Tree::NodeId>> {
+    // Flex items are laid out in order-modified document order (i.e. sorted by their `order` style)
+    order_modified_document_order(tree, node)
+        .into_iter()
+        .enumerate()
+        .map(|(order, index)| (order as u32, tree.child(node, index)))
+        .map(|(order, child)| (order, child, tree.style(child)))
+        .filter(|(_, _, style)| !style.position.is_out_of_flow())
+        .filter(|(_, _, style)| style.display != Display::None)
+        .map(|(order, child, child_style)| FlexItem {
+            node: child,
+            order,
             size: child_style.size.maybe_resolve(constants.node_inner_size),
             min_size: child_style.min_size.maybe_resolve(constants.node_inner_size),
             max_size: child_style.max_size.maybe_resolve(constants.node_inner_size),
 
-            inset: child_style.inset.zip_size(constants.node_inner_size, |p, s| p.maybe_resolve(s)),
-            margin: child_style.margin.resolve_or_zero(constants.node_inner_size.width),
+            relative_offset: {
+                // Note: horizontal offsets are flipped for right-to-left containers as they are laid out mirrored
+                let offset = compute_relative_offset(child_style, constants.direction, constants.node_inner_size);
+                Point { x: if constants.direction.is_rtl() { -offset.x } else { offset.x }, y: offset.y }
+            },
+            margin: child_style
+                .margin
+                .resolve_or_zero(constants.node_inner_size.width)
+                .for_direction(constants.direction),
             padding: child_style.padding.resolve_or_zero(constants.node_inner_size.width),
             border: child_style.border.resolve_or_zero(constants.node_inner_size.width),
             align_self: child_style.align_self.unwrap_or(constants.align_items),
+            overflow: child_style.overflow,
             flex_basis: 0.0,
             inner_flex_basis: 0.0,
             violation: 0.0,
             frozen: false,
+            visibility: child_style.visibility.unwrap_or(constants.visibility),
+            is_collapsed: child_style.visibility.unwrap_or(constants.visibility) == Visibility::Collapse,
+            strut_size: None,
 
             resolved_minimum_size: Size::zero(),
             hypothetical_inner_size: Size::zero(),
@@ -521,12 +654,12 @@ fn determine_available_space(
 ///     Furthermore, the sizing calculations that floor the content box size at zero when applying box-sizing are also ignored.
 ///     (For example, an item with a specified size of zero, positive padding, and box-sizing: border-box will have an outer flex base size of zero—and hence a negative inner flex base size.)
 #[inline]
-fn determine_flex_base_size(
-    tree: &mut impl LayoutTree,
+fn determine_flex_base_size<Tree: LayoutTree>(
+    tree: &mut Tree,
     node_size: Size<Option<f32>>,
     constants: &AlgoConstants,
     available_space: Size<AvailableSpace>,
-    flex_items: &mut Vec<FlexItem>,
+    flex_items: &mut Vec<FlexItem<Tree::NodeId>>,
 ) {
     // TODO - this does not follow spec. See the TODOs below
     for child in flex_items.iter_mut() {
@@ -593,6 +726,7 @@ fn determine_flex_base_size(
             RunMode::ComputeSize,
             SizingMode::ContentSize,
         )
+        .size
         .main(constants.dir);
     }
 
@@ -618,17 +752,28 @@ fn determine_flex_base_size(
             Size::MIN_CONTENT,
             RunMode::ComputeSize,
             SizingMode::ContentSize,
-        );
+        )
+        .size;
 
         // 4.5. Automatic Minimum Size of Flex Items
         // https://www.w3.org/TR/css-flexbox-1/#min-size-auto
+        // The automatic minimum size of a scroll container is zero
         let specified = child.size.maybe_min(child.max_size);
-        child.resolved_minimum_size = child.min_size.unwrap_or(min_content_size.maybe_min(specified));
+        let content_based_minimum_size = min_content_size.maybe_min(specified);
+        let automatic_minimum_size = Size {
+            width: child.overflow.x.maybe_into_automatic_min_size().unwrap_or(content_based_minimum_size.width),
+            height: child.overflow.y.maybe_into_automatic_min_size().unwrap_or(content_based_minimum_size.height),
+        };
+        child.resolved_minimum_size = child.min_size.unwrap_or(automatic_minimum_size);
 
-        let hypothetical_inner_min_main = min_content_size
-            .main(constants.dir)
-            .maybe_clamp(child.resolved_minimum_size.main(constants.dir).into(), child.size.main(constants.dir))
-            .into();
+        let hypothetical_inner_min_main = if child.overflow.main(constants.dir).is_scroll_container() {
+            child.resolved_minimum_size.main(constants.dir)
+        } else {
+            min_content_size
+                .main(constants.dir)
+                .maybe_clamp(child.resolved_minimum_size.main(constants.dir).into(), child.size.main(constants.dir))
+        }
+        .into();
         child.hypothetical_inner_size.set_main(
             constants.dir,
             child.flex_basis.maybe_clamp(hypothetical_inner_min_main, child.max_size.main(constants.dir)),
@@ -658,13 +803,13 @@ fn determine_flex_base_size(
 ///
 ///         **Note that the "collect as many" line will collect zero-sized flex items onto the end of the previous line even if the last non-zero item exactly "filled up" the line**.
 #[inline]
-fn collect_flex_lines<'a>(
-    tree: &impl LayoutTree,
-    node: Node,
+fn collect_flex_lines<'a, Tree: LayoutTree>(
+    tree: &Tree,
+    node: Tree::NodeId,
     constants: &AlgoConstants,
     available_space: Size<AvailableSpace>,
-    flex_items: &'a mut Vec<FlexItem>,
-) -> Vec<FlexLine<'a>> {
+    flex_items: &'a mut Vec<FlexItem<Tree::NodeId>>,
+) -> Vec<FlexLine<'a, Tree::NodeId>> {
     let mut lines = crate::sys::new_vec_with_capacity(1);
 
     if tree.style(node).flex_wrap == FlexWrap::NoWrap {
@@ -673,6 +818,7 @@ fn collect_flex_lines<'a>(
             container_main_size_contribution: 0.0,
             cross_size: 0.0,
             offset_cross: 0.0,
+            strut_size: None,
         });
     } else {
         let mut flex_items = &mut flex_items[..];
@@ -684,6 +830,10 @@ fn collect_flex_lines<'a>(
                 .iter()
                 .enumerate()
                 .find(|&(idx, child)| {
+                    // Collapsed items are treated as having zero main size in the second layout round
+                    if child.strut_size.is_some() {
+                        return false;
+                    }
                     // Gaps only occur between items (not before the first one or after the last one)
                     // So first item in the line does not contribute a gap to the line length
                     let gap_contribution = if idx == 0 { 0.0 } else { main_axis_gap };
@@ -698,11 +848,30 @@ fn collect_flex_lines<'a>(
                 .unwrap_or(flex_items.len());
 
             let (items, rest) = flex_items.split_at_mut(index);
-            lines.push(FlexLine { items, container_main_size_contribution: 0.0, cross_size: 0.0, offset_cross: 0.0 });
+            lines.push(FlexLine {
+                items,
+                container_main_size_contribution: 0.0,
+                cross_size: 0.0,
+                offset_cross: 0.0,
+                strut_size: None,
+            });
             flex_items = rest;
         }
     }
 
+    // The rest of the second layout round ignores collapsed items, so move them to the end of their line and exclude
+    // them from its items (noting the largest of their strut sizes)
+    for line in lines.iter_mut() {
+        if line.items.iter().all(|item| item.strut_size.is_none()) {
+            continue;
+        }
+        line.items.sort_unstable_by_key(|item| (item.strut_size.is_some(), item.order));
+        let visible_item_count = line.items.iter().take_while(|item| item.strut_size.is_none()).count();
+        let (items, collapsed_items) = core::mem::take(&mut line.items).split_at_mut(visible_item_count);
+        line.strut_size = collapsed_items.iter().filter_map(|item| item.strut_size).reduce(f32::max);
+        line.items = items;
+    }
+
     lines
 }
 
@@ -710,9 +879,9 @@ fn collect_flex_lines<'a>(
 ///
 /// # [9.7. Resolving Flexible Lengths](https://www.w3.org/TR/css-flexbox-1/#resolve-flexible-lengths)
 #[inline]
-fn resolve_flexible_lengths(
-    tree: &mut impl LayoutTree,
-    line: &mut FlexLine,
+fn resolve_flexible_lengths<Tree: LayoutTree>(
+    tree: &mut Tree,
+    line: &mut FlexLine<Tree::NodeId>,
     constants: &AlgoConstants,
     original_gap: Size<f32>,
 ) {
@@ -750,7 +919,12 @@ fn resolve_flexible_lengths(
         //
         // Ultimately, this was not found by reading the spec, but by trial and error fixing tests to align with Webkit/Firefox output.
         // (see the `flex_basis_unconstraint_row` and `flex_basis_uncontraint_column` generated tests which demonstrate this)
-        if constants.node_inner_size.main(constants.dir).is_none() && constants.is_row {
+        //
+        // Scroll containers are excluded, as their automatic minimum size is zero rather than being based on their content.
+        if constants.node_inner_size.main(constants.dir).is_none()
+            && constants.is_row
+            && !child.overflow.main(constants.dir).is_scroll_container()
+        {
             child.target_size.set_main(
                 constants.dir,
                 child.size.main(constants.dir).unwrap_or(0.0).maybe_clamp(
@@ -823,7 +997,8 @@ fn resolve_flexible_lengths(
                 })
                 .sum::<f32>();
 
-        let mut unfrozen: Vec<&mut FlexItem> = line.items.iter_mut().filter(|child| !child.frozen).collect();
+        let mut unfrozen: Vec<&mut FlexItem<Tree::NodeId>> =
+            line.items.iter_mut().filter(|child| !child.frozen).collect();
 
         let (sum_flex_grow, sum_flex_shrink): (f32, f32) =
             unfrozen.iter().fold((0.0, 0.0), |(flex_grow, flex_shrink), item| {
@@ -932,9 +1107,9 @@ fn resolve_flexible_lengths(
 /// - [**Determine the hypothetical cross size of each item**](https://www.w3.org/TR/css-flexbox-1/#algo-cross-item)
 ///     by performing layout with the used main size and the available space, treating auto as fit-content.
 #[inline]
-fn determine_hypothetical_cross_size(
-    tree: &mut impl LayoutTree,
-    line: &mut FlexLine,
+fn determine_hypothetical_cross_size<Tree: LayoutTree>(
+    tree: &mut Tree,
+    line: &mut FlexLine<Tree::NodeId>,
     constants: &AlgoConstants,
     available_space: Size<AvailableSpace>,
 ) {
@@ -968,6 +1143,7 @@ fn determine_hypothetical_cross_size(
                 RunMode::ComputeSize,
                 SizingMode::ContentSize,
             )
+            .size
             .cross(constants.dir)
             .maybe_clamp(child.min_size.cross(constants.dir), child.max_size.cross(constants.dir)),
         );
@@ -981,27 +1157,16 @@ fn determine_hypothetical_cross_size(
 
 /// Calculate the base lines of the children.
 #[inline]
-fn calculate_children_base_lines(
-    tree: &mut impl LayoutTree,
-    node: Node,
+fn calculate_children_base_lines<Tree: LayoutTree>(
+    tree: &mut Tree,
     node_size: Size<Option<f32>>,
     available_space: Size<AvailableSpace>,
-    flex_lines: &mut [FlexLine],
+    flex_lines: &mut [FlexLine<Tree::NodeId>],
     constants: &AlgoConstants,
 ) {
-    /// Recursively calculates the baseline for children
-    fn calc_baseline(db: &impl LayoutTree, node: Node, layout: &Layout) -> f32 {
-        if let Some(first_child) = db.children(node).next() {
-            let layout = db.layout(*first_child);
-            calc_baseline(db, *first_child, layout)
-        } else {
-            layout.size.height
-        }
-    }
-
     for line in flex_lines {
         for child in line.items.iter_mut() {
-            let preliminary_size = compute_node_layout(
+            let preliminary_size_and_baseline = compute_node_layout(
                 tree,
                 child.node,
                 Size {
@@ -1032,15 +1197,7 @@ fn calculate_children_base_lines(
                 SizingMode::ContentSize,
             );
 
-            child.baseline = calc_baseline(
-                tree,
-                child.node,
-                &Layout {
-                    order: tree.children(node).position(|n| *n == child.node).unwrap() as u32,
-                    size: preliminary_size,
-                    location: Point::zero(),
-                },
-            );
+            child.baseline = preliminary_size_and_baseline.first_baseline_or_height();
         }
     }
 }
@@ -1066,9 +1223,9 @@ fn calculate_children_base_lines(
 ///         If the flex container is single-line, then clamp the line’s cross-size to be within the container’s computed min and max cross sizes.
 ///         **Note that if CSS 2.1’s definition of min/max-width/height applied more generally, this behavior would fall out automatically**.
 #[inline]
-fn calculate_cross_size(
-    tree: &mut impl LayoutTree,
-    flex_lines: &mut [FlexLine],
+fn calculate_cross_size<Tree: LayoutTree>(
+    tree: &mut Tree,
+    flex_lines: &mut [FlexLine<Tree::NodeId>],
     node_size: Size<Option<f32>>,
     constants: &AlgoConstants,
 ) {
@@ -1119,10 +1276,10 @@ fn calculate_cross_size(
 ///     and the sum of the flex lines' cross sizes is less than the flex container’s inner cross size,
 ///     increase the cross size of each flex line by equal amounts such that the sum of their cross sizes exactly equals the flex container’s inner cross size.
 #[inline]
-fn handle_align_content_stretch(
-    tree: &mut impl LayoutTree,
-    flex_lines: &mut [FlexLine],
-    node: Node,
+fn handle_align_content_stretch<Tree: LayoutTree>(
+    tree: &mut Tree,
+    flex_lines: &mut [FlexLine<Tree::NodeId>],
+    node: Tree::NodeId,
     node_size: Size<Option<f32>>,
     constants: &AlgoConstants,
 ) {
@@ -1154,7 +1311,11 @@ fn handle_align_content_stretch(
 ///
 ///     **Note that this step does not affect the main size of the flex item, even if it has an intrinsic aspect ratio**.
 #[inline]
-fn determine_used_cross_size(tree: &mut impl LayoutTree, flex_lines: &mut [FlexLine], constants: &AlgoConstants) {
+fn determine_used_cross_size<Tree: LayoutTree>(
+    tree: &mut Tree,
+    flex_lines: &mut [FlexLine<Tree::NodeId>],
+    constants: &AlgoConstants,
+) {
     for line in flex_lines {
         let line_cross_size = line.cross_size;
 
@@ -1193,10 +1354,10 @@ fn determine_used_cross_size(tree: &mut impl LayoutTree, flex_lines: &mut [FlexL
 ///
 ///     2. Align the items along the main-axis per `justify-content`.
 #[inline]
-fn distribute_remaining_free_space(
-    tree: &mut impl LayoutTree,
-    flex_lines: &mut [FlexLine],
-    node: Node,
+fn distribute_remaining_free_space<Tree: LayoutTree>(
+    tree: &mut Tree,
+    flex_lines: &mut [FlexLine<Tree::NodeId>],
+    node: Tree::NodeId,
     constants: &AlgoConstants,
 ) {
     for line in flex_lines {
@@ -1207,11 +1368,11 @@ fn distribute_remaining_free_space(
         let mut num_auto_margins = 0;
 
         for child in line.items.iter_mut() {
-            let child_style = tree.style(child.node);
-            if child_style.margin.main_start(constants.dir) == LengthPercentageAuto::Auto {
+            let child_margin = tree.style(child.node).margin.for_direction(constants.direction);
+            if child_margin.main_start(constants.dir) == LengthPercentageAuto::Auto {
                 num_auto_margins += 1;
             }
-            if child_style.margin.main_end(constants.dir) == LengthPercentageAuto::Auto {
+            if child_margin.main_end(constants.dir) == LengthPercentageAuto::Auto {
                 num_auto_margins += 1;
             }
         }
@@ -1220,15 +1381,15 @@ fn distribute_remaining_free_space(
             let margin = free_space / num_auto_margins as f32;
 
             for child in line.items.iter_mut() {
-                let child_style = tree.style(child.node);
-                if child_style.margin.main_start(constants.dir) == LengthPercentageAuto::Auto {
+                let child_margin = tree.style(child.node).margin.for_direction(constants.direction);
+                if child_margin.main_start(constants.dir) == LengthPercentageAuto::Auto {
                     if constants.is_row {
                         child.margin.left = margin;
                     } else {
                         child.margin.top = margin;
                     }
                 }
-                if child_style.margin.main_end(constants.dir) == LengthPercentageAuto::Auto {
+                if child_margin.main_end(constants.dir) == LengthPercentageAuto::Auto {
                     if constants.is_row {
                         child.margin.right = margin;
                     } else {
@@ -1243,7 +1404,7 @@ fn distribute_remaining_free_space(
             let justify_content_mode: JustifyContent =
                 tree.style(node).justify_content.unwrap_or(JustifyContent::Start);
 
-            let justify_item = |(i, child): (usize, &mut FlexItem)| {
+            let justify_item = |(i, child): (usize, &mut FlexItem<Tree::NodeId>)| {
                 child.offset_main =
                     compute_alignment_offset(free_space, num_items, gap, justify_content_mode, layout_reverse, i == 0);
             };
@@ -1270,17 +1431,21 @@ fn distribute_remaining_free_space(
 ///     - Otherwise, if the block-start or inline-start margin (whichever is in the cross axis) is auto, set it to zero.
 ///         Set the opposite margin so that the outer cross size of the item equals the cross size of its flex line.
 #[inline]
-fn resolve_cross_axis_auto_margins(tree: &mut impl LayoutTree, flex_lines: &mut [FlexLine], constants: &AlgoConstants) {
+fn resolve_cross_axis_auto_margins<Tree: LayoutTree>(
+    tree: &mut Tree,
+    flex_lines: &mut [FlexLine<Tree::NodeId>],
+    constants: &AlgoConstants,
+) {
     for line in flex_lines {
         let line_cross_size = line.cross_size;
         let max_baseline: f32 = line.items.iter_mut().map(|child| child.baseline).fold(0.0, |acc, x| acc.max(x));
 
         for child in line.items.iter_mut() {
             let free_space = line_cross_size - child.outer_target_size.cross(constants.dir);
-            let child_style = tree.style(child.node);
+            let child_margin = tree.style(child.node).margin.for_direction(constants.direction);
 
-            if child_style.margin.cross_start(constants.dir) == LengthPercentageAuto::Auto
-                && child_style.margin.cross_end(constants.dir) == LengthPercentageAuto::Auto
+            if child_margin.cross_start(constants.dir) == LengthPercentageAuto::Auto
+                && child_margin.cross_end(constants.dir) == LengthPercentageAuto::Auto
             {
                 if constants.is_row {
                     child.margin.top = free_space / 2.0;
@@ -1289,13 +1454,13 @@ fn resolve_cross_axis_auto_margins(tree: &mut impl LayoutTree, flex_lines: &mut
                     child.margin.left = free_space / 2.0;
                     child.margin.right = free_space / 2.0;
                 }
-            } else if child_style.margin.cross_start(constants.dir) == LengthPercentageAuto::Auto {
+            } else if child_margin.cross_start(constants.dir) == LengthPercentageAuto::Auto {
                 if constants.is_row {
                     child.margin.top = free_space;
                 } else {
                     child.margin.left = free_space;
                 }
-            } else if child_style.margin.cross_end(constants.dir) == LengthPercentageAuto::Auto {
+            } else if child_margin.cross_end(constants.dir) == LengthPercentageAuto::Auto {
                 if constants.is_row {
                     child.margin.bottom = free_space;
                 } else {
@@ -1316,8 +1481,8 @@ fn resolve_cross_axis_auto_margins(tree: &mut impl LayoutTree, flex_lines: &mut
 /// - [**Align all flex items along the cross-axis**](https://www.w3.org/TR/css-flexbox-1/#algo-cross-align) per `align-self`,
 ///     if neither of the item's cross-axis margins are `auto`.
 #[inline]
-fn align_flex_items_along_cross_axis(
-    child: &mut FlexItem,
+fn align_flex_items_along_cross_axis<NodeId>(
+    child: &mut FlexItem<NodeId>,
     free_space: f32,
     max_baseline: f32,
     constants: &AlgoConstants,
@@ -1372,8 +1537,8 @@ fn align_flex_items_along_cross_axis(
 ///     - Otherwise, use the sum of the flex lines' cross sizes, clamped by the used min and max cross sizes of the flex container.
 #[inline]
 #[must_use]
-fn determine_container_cross_size(
-    flex_lines: &mut [FlexLine],
+fn determine_container_cross_size<NodeId>(
+    flex_lines: &mut [FlexLine<NodeId>],
     node_size: Size<Option<f32>>,
     constants: &mut AlgoConstants,
 ) -> f32 {
@@ -1401,10 +1566,10 @@ fn determine_container_cross_size(
 ///
 /// - [**Align all flex lines**](https://www.w3.org/TR/css-flexbox-1/#algo-line-align) per `align-content`.
 #[inline]
-fn align_flex_lines_per_align_content(
-    tree: &impl LayoutTree,
-    flex_lines: &mut [FlexLine],
-    node: Node,
+fn align_flex_lines_per_align_content<Tree: LayoutTree>(
+    tree: &Tree,
+    flex_lines: &mut [FlexLine<Tree::NodeId>],
+    node: Tree::NodeId,
     constants: &AlgoConstants,
     total_cross_size: f32,
 ) {
@@ -1414,7 +1579,7 @@ fn align_flex_lines_per_align_content(
     let total_cross_axis_gap = sum_axis_gaps(gap, num_lines);
     let free_space = constants.inner_container_size.cross(constants.dir) - total_cross_size - total_cross_axis_gap;
 
-    let align_line = |(i, line): (usize, &mut FlexLine)| {
+    let align_line = |(i, line): (usize, &mut FlexLine<Tree::NodeId>)| {
         line.offset_cross =
             compute_alignment_offset(free_space, num_lines, gap, align_content_mode, constants.is_wrap_reverse, i == 0);
     };
@@ -1428,17 +1593,16 @@ fn align_flex_lines_per_align_content(
 
 /// Calculates the layout for a flex-item
 #[allow(clippy::too_many_arguments)]
-fn calculate_flex_item(
-    tree: &mut impl LayoutTree,
-    node: Node,
-    item: &mut FlexItem,
+fn calculate_flex_item<Tree: LayoutTree>(
+    tree: &mut Tree,
+    item: &mut FlexItem<Tree::NodeId>,
     total_offset_main: &mut f32,
     total_offset_cross: f32,
     line_offset_cross: f32,
     container_size: Size<f32>,
     direction: FlexDirection,
 ) {
-    let preliminary_size = compute_node_layout(
+    let SizeAndBaseline { size: preliminary_size, first_baseline, content_size } = compute_node_layout(
         tree,
         item.node,
         item.target_size.map(|s| s.into()),
@@ -1450,33 +1614,36 @@ fn calculate_flex_item(
     let offset_main = *total_offset_main
         + item.offset_main
         + item.margin.main_start(direction)
-        + (item.inset.main_start(direction).unwrap_or(0.0) - item.inset.main_end(direction).unwrap_or(0.0));
+        + item.relative_offset.main(direction);
 
     let offset_cross = total_offset_cross
         + item.offset_cross
         + line_offset_cross
         + item.margin.cross_start(direction)
-        + (item.inset.cross_start(direction).unwrap_or(0.0) - item.inset.cross_end(direction).unwrap_or(0.0));
-
-    let order = tree.children(node).position(|n| *n == item.node).unwrap() as u32;
+        + item.relative_offset.cross(direction);
 
     *tree.layout_mut(item.node) = Layout {
-        order,
+        order: item.order,
         size: preliminary_size,
         location: Point {
             x: if direction.is_row() { offset_main } else { offset_cross },
             y: if direction.is_column() { offset_main } else { offset_cross },
         },
+        padding: item.padding,
+        border: item.border,
+        margin: item.margin,
+        content_size,
+        first_baseline,
+        visibility: item.visibility,
     };
 
     *total_offset_main += item.offset_main + item.margin.main_axis_sum(direction) + preliminary_size.main(direction);
 }
 
 /// Calculates the layout line
-fn calculate_layout_line(
-    tree: &mut impl LayoutTree,
-    node: Node,
-    line: &mut FlexLine,
+fn calculate_layout_line<Tree: LayoutTree>(
+    tree: &mut Tree,
+    line: &mut FlexLine<Tree::NodeId>,
     total_offset_cross: &mut f32,
     container_size: Size<f32>,
     padding_border: Rect<f32>,
@@ -1489,7 +1656,6 @@ fn calculate_layout_line(
         for item in line.items.iter_mut().rev() {
             calculate_flex_item(
                 tree,
-                node,
                 item,
                 &mut total_offset_main,
                 *total_offset_cross,
@@ -1502,7 +1668,6 @@ fn calculate_layout_line(
         for item in line.items.iter_mut() {
             calculate_flex_item(
                 tree,
-                node,
                 item,
                 &mut total_offset_main,
                 *total_offset_cross,
@@ -1518,14 +1683,30 @@ fn calculate_layout_line(
 
 /// Do a final layout pass and collect the resulting layouts.
 #[inline]
-fn final_layout_pass(tree: &mut impl LayoutTree, node: Node, flex_lines: &mut [FlexLine], constants: &AlgoConstants) {
+fn final_layout_pass<Tree: LayoutTree>(
+    tree: &mut Tree,
+    flex_lines: &mut [FlexLine<Tree::NodeId>],
+    constants: &AlgoConstants,
+) {
+    // The items are laid out with definite sizes, so their subtrees can be laid out independently of each other
+    let inputs: Vec<_> = flex_lines
+        .iter()
+        .flat_map(|line| line.items.iter())
+        .map(|item| LayoutInput {
+            node: item.node,
+            known_dimensions: item.target_size.map(|s| s.into()),
+            available_space: constants.container_size.map(|s| s.into()),
+            sizing_mode: SizingMode::ContentSize,
+        })
+        .collect();
+    tree.prepare_child_layouts(&inputs);
+
     let mut total_offset_cross = constants.padding_border.cross_start(constants.dir);
 
     if constants.is_wrap_reverse {
         for line in flex_lines.iter_mut().rev() {
             calculate_layout_line(
                 tree,
-                node,
                 line,
                 &mut total_offset_cross,
                 constants.container_size,
@@ -1537,7 +1718,6 @@ fn final_layout_pass(tree: &mut impl LayoutTree, node: Node, flex_lines: &mut [F
         for line in flex_lines.iter_mut() {
             calculate_layout_line(
                 tree,
-                node,
                 line,
                 &mut total_offset_cross,
                 constants.container_size,
@@ -1548,30 +1728,129 @@ fn final_layout_pass(tree: &mut impl LayoutTree, node: Node, flex_lines: &mut [F
     }
 }
 
+/// Determine the first baseline of the flex container.
+///
+/// If any of the items in the first flex line participate in baseline alignment then the container's first baseline is
+/// the baseline of those items. Otherwise it is the baseline of the first item in the first line. A container without any
+/// flex items has no baseline.
+#[inline]
+fn determine_first_baseline<Tree: LayoutTree>(
+    tree: &Tree,
+    flex_lines: &[FlexLine<Tree::NodeId>],
+    constants: &AlgoConstants,
+) -> Option<f32> {
+    let item = first_baseline_item(flex_lines, constants)?;
+
+    // Items without a baseline have one synthesized from the bottom edge of their border box
+    let layout = tree.layout(item.node);
+    Some(layout.location.y + layout.first_baseline.unwrap_or(layout.size.height))
+}
+
+/// Determine the first baseline of the flex container without laying out its items.
+///
+/// The item that provides the baseline (see [`determine_first_baseline`]) is sized for its own baseline, and the position
+/// that the final layout pass would give it is determined from the offsets and sizes of the items.
+fn compute_first_baseline<Tree: LayoutTree>(
+    tree: &mut Tree,
+    flex_lines: &[FlexLine<Tree::NodeId>],
+    constants: &AlgoConstants,
+) -> Option<f32> {
+    let item = first_baseline_item(flex_lines, constants)?;
+    let dir = constants.dir;
+
+    // The first line is laid out last if the lines are wrapped in reverse, after the lines that follow it
+    let preceding_lines = if constants.is_wrap_reverse { &flex_lines[1..] } else { &[] };
+    let offset_cross = constants.padding_border.cross_start(dir)
+        + preceding_lines.iter().map(|line| line.offset_cross + line.cross_size).sum::<f32>()
+        + flex_lines[0].offset_cross
+        + item.offset_cross
+        + item.margin.cross_start(dir)
+        + item.relative_offset.cross(dir);
+
+    // Items are laid out at their target size, in reverse order if the main axis is reversed
+    let mut line_items: Vec<&FlexItem<Tree::NodeId>> = flex_lines[0].items.iter().collect();
+    if dir.is_reverse() {
+        line_items.reverse();
+    }
+    let offset_main = constants.padding_border.main_start(dir)
+        + line_items
+            .iter()
+            .take_while(|line_item| line_item.node != item.node)
+            .map(|line_item| {
+                line_item.offset_main + line_item.margin.main_axis_sum(dir) + line_item.target_size.main(dir)
+            })
+            .sum::<f32>()
+        + item.offset_main
+        + item.margin.main_start(dir)
+        + item.relative_offset.main(dir);
+
+    let first_baseline = compute_node_layout(
+        tree,
+        item.node,
+        item.target_size.map(|s| s.into()),
+        constants.container_size.map(|s| s.into()),
+        RunMode::ComputeSize,
+        SizingMode::ContentSize,
+    )
+    .first_baseline;
+
+    let y = if dir.is_column() { offset_main } else { offset_cross };
+    Some(y + first_baseline.unwrap_or(item.target_size.height))
+}
+
+/// The item whose baseline is the first baseline of the flex container: the first item in the first line that
+/// participates in baseline alignment, or else the first item in the first line
+#[inline]
+fn first_baseline_item<'a, NodeId>(
+    flex_lines: &'a [FlexLine<NodeId>],
+    constants: &AlgoConstants,
+) -> Option<&'a FlexItem<NodeId>> {
+    let first_line = flex_lines.first()?;
+    first_line
+        .items
+        .iter()
+        .find(|item| constants.is_row && item.align_self == AlignSelf::Baseline)
+        .or_else(|| first_line.items.first())
+}
+
 /// Perform absolute layout on all absolutely positioned children.
 #[inline]
-fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node: Node, constants: &AlgoConstants) {
+fn perform_absolute_layout_on_absolute_children<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    constants: &AlgoConstants,
+) {
+    // Absolutely positioned children of static nodes are laid out by their containing block instead
+    if !tree.style(node).position.is_positioned() {
+        return;
+    }
+
     // TODO: remove number of Vec<_> generated
+    let painting_order = compute_painting_order(tree, node);
     let candidates = tree
         .children(node)
-        .cloned()
         .enumerate()
         .filter(|(_, child)| tree.style(*child).position == Position::Absolute)
         .collect::<Vec<_>>();
 
-    for (order, child) in candidates {
+    for (index, child) in candidates {
+        let order = painting_order[index];
         let container_width = constants.container_size.width;
         let container_height = constants.container_size.height;
 
         let child_style = tree.style(child);
+        let child_inset = child_style.inset.for_direction(constants.direction);
+        let child_margin = child_style.margin.for_direction(constants.direction);
 
         // X-axis
-        let child_position_start = child_style.inset.left.maybe_resolve(container_width);
-        let child_margin_start = child_style.margin.left.maybe_resolve(container_width);
+        // Note: for right-to-left containers the "start" edge is the right edge. This means that the right inset wins
+        // when both the left and right insets are set but the node's width is also constrained.
+        let child_position_start = child_inset.left.maybe_resolve(container_width);
+        let child_margin_start = child_margin.left.maybe_resolve(container_width);
         let start = child_position_start.maybe_add(child_margin_start);
 
-        let child_position_end = child_style.inset.right.maybe_resolve(container_width);
-        let child_margin_end = child_style.margin.right.maybe_resolve(container_width);
+        let child_position_end = child_inset.right.maybe_resolve(container_width);
+        let child_margin_end = child_margin.right.maybe_resolve(container_width);
         let end = child_position_end.maybe_add(child_margin_end);
 
         // Y-axis
@@ -1600,7 +1879,7 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
             known_dimensions.height = Some(container_height.maybe_sub(top).maybe_sub(bottom));
         }
 
-        let preliminary_size = compute_node_layout(
+        let SizeAndBaseline { size: preliminary_size, first_baseline, content_size } = compute_node_layout(
             tree,
             child,
             known_dimensions,
@@ -1616,6 +1895,15 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
         // This shortens the lifetime of the original binding
         let child_style = tree.style(child);
 
+        let padding = child_style.padding.resolve_or_zero(Some(container_width));
+        let border = child_style.border.resolve_or_zero(Some(container_width));
+        let margin = Rect {
+            left: child_margin_start.unwrap_or(0.0),
+            right: child_margin_end.unwrap_or(0.0),
+            top: child_margin_top.unwrap_or(0.0),
+            bottom: child_margin_bottom.unwrap_or(0.0),
+        };
+
         let free_main_space = constants.container_size.main(constants.dir)
             - preliminary_size
                 .main(constants.dir)
@@ -1698,13 +1986,20 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
             }
         };
 
+        let visibility = tree.style(child).visibility.unwrap_or(constants.visibility);
         *tree.layout_mut(child) = Layout {
-            order: order as u32,
+            order,
             size: preliminary_size,
             location: Point {
                 x: if constants.is_row { offset_main } else { offset_cross },
                 y: if constants.is_column { offset_main } else { offset_cross },
             },
+            padding,
+            border,
+            margin,
+            content_size,
+            first_baseline,
+            visibility,
         };
     }
 }
@@ -1733,7 +2028,7 @@ mod tests {
         math::MaybeMath,
         prelude::{Rect, Size},
         resolve::ResolveOrZero,
-        style::{FlexWrap, Style},
+        style::{Direction, FlexWrap, Style, Visibility},
         Taffy,
     };
 
@@ -1748,7 +2043,13 @@ mod tests {
         let node_size = Size::NONE;
         let parent_size = Size::MAX_CONTENT;
 
-        let constants = super::compute_constants(tree.style(node_id).unwrap(), node_size, parent_size);
+        let constants = super::compute_constants(
+            tree.style(node_id).unwrap(),
+            Direction::Ltr,
+            Visibility::Visible,
+            node_size,
+            parent_size,
+        );
         // let constants = super::compute_constants(&tree.nodes[node_id], node_size, parent_size);
 
         assert!(constants.dir == style.flex_direction);
@@ -1,14 +1,15 @@
 //! Alignment of tracks and final positioning of items
-use super::types::GridTrack;
-use crate::axis::InBothAbsAxis;
+use super::types::{GridItem, GridTrack};
+use crate::axis::{AbsoluteAxis, InBothAbsAxis};
 use crate::compute::common::alignment::compute_alignment_offset;
+use crate::compute::common::relative_position::compute_relative_offset;
 use crate::compute::compute_node_layout;
 use crate::geometry::{Line, Point, Rect, Size};
-use crate::layout::{Layout, RunMode, SizingMode};
+use crate::layout::{Layout, LayoutInput, RunMode, SizeAndBaseline, SizingMode};
 use crate::math::MaybeMath;
-use crate::node::Node;
-use crate::resolve::MaybeResolve;
-use crate::style::{AlignContent, AlignItems, AlignSelf, AvailableSpace, Position};
+use crate::resolve::{MaybeResolve, ResolveOrZero};
+use crate::style::{AlignContent, AlignItems, AlignSelf, AvailableSpace, Direction, Display, Position, Visibility};
+use crate::style_helpers::TaffyMaxContent;
 use crate::sys::{f32_max, f32_min};
 use crate::tree::LayoutTree;
 
@@ -69,25 +70,115 @@ pub(super) fn align_tracks(
     });
 }
 
-/// Align and size a grid item into it's final position
-pub(super) fn align_and_position_item(
-    tree: &mut impl LayoutTree,
-    node: Node,
-    order: u32,
+/// Determine the first baseline of the grid container from the final layouts of its items.
+///
+/// If any of the items in the first row participate in baseline alignment then the container's first baseline is the
+/// shared baseline of those items. Otherwise it is the baseline of the first item in the first row (in grid order).
+/// A container without any items in its first row has no baseline. In masonry containers only the items at the start of
+/// the masonry axis are considered to be in the first row.
+pub(super) fn determine_first_baseline<Tree: LayoutTree>(
+    tree: &Tree,
+    items: &[GridItem<Tree::NodeId>],
+    container_align_items: Option<AlignItems>,
+) -> Option<f32> {
+    let item = first_baseline_item(tree, items, container_align_items)?;
+
+    // Items without a baseline have one synthesized from the bottom edge of their border box.
+    // The baseline shim is excluded here as it is already accounted for in the item's location.
+    let layout = tree.layout(item.node);
+    Some(layout.location.y + layout.first_baseline.unwrap_or(layout.size.height))
+}
+
+/// The item whose baseline is the first baseline of the grid container (see [`determine_first_baseline`])
+pub(super) fn first_baseline_item<'a, Tree: LayoutTree>(
+    tree: &Tree,
+    items: &'a [GridItem<Tree::NodeId>],
+    container_align_items: Option<AlignItems>,
+) -> Option<&'a GridItem<Tree::NodeId>> {
+    let first_row_items =
+        || items.iter().filter(|item| item.row_indexes.start == 0 && item.masonry_extent.start == 0.0);
+    first_row_items()
+        .find(|item| item.is_baseline_aligned(tree, container_align_items))
+        .or_else(|| first_row_items().min_by_key(|item| item.column_indexes.start))
+}
+
+/// Compute the first baseline of a grid item within the grid container (i.e. the first baseline of the container if
+/// the item is its [`first_baseline_item`]) without laying the item out.
+///
+/// The item is sized for its baseline and aligned within its grid area as it is by [`align_and_position_item`].
+pub(super) fn compute_item_first_baseline<Tree: LayoutTree>(
+    tree: &mut Tree,
+    sizing: ItemSizing<Tree::NodeId>,
+    direction: Direction,
+    grid_area: Rect<f32>,
+    baseline_shim: f32,
+) -> f32 {
+    let ItemSizing { input, alignment_styles, margin, inset_vertical, .. } = sizing;
+    let grid_area_size = Size { width: grid_area.right - grid_area.left, height: grid_area.bottom - grid_area.top };
+
+    let SizeAndBaseline { size: measured_size, first_baseline, .. } = compute_node_layout(
+        tree,
+        input.node,
+        input.known_dimensions,
+        input.available_space,
+        RunMode::ComputeSize,
+        input.sizing_mode,
+    );
+
+    let style = tree.style(input.node);
+    let (y, height, _) = align_and_size_item_within_area(
+        Line { start: grid_area.top, end: grid_area.bottom },
+        style.align_self.unwrap_or(alignment_styles.vertical),
+        input.known_dimensions.height,
+        measured_size.height,
+        style.position,
+        inset_vertical,
+        margin.vertical_components(),
+        baseline_shim,
+    );
+    let relative_offset = compute_relative_offset(style, direction, grid_area_size.map(Some));
+
+    y + relative_offset.y + first_baseline.unwrap_or(height)
+}
+
+/// The inputs with which a grid item is laid out, along with the resolved styles that are used to align it within its
+/// grid area
+pub(super) struct ItemSizing<NodeId> {
+    /// The inputs of the layout of the item
+    pub(super) input: LayoutInput<NodeId>,
+    /// The default alignment of the item in each axis
+    alignment_styles: InBothAbsAxis<AlignSelf>,
+    /// The margins of the item (`None` for auto margins)
+    margin: Rect<Option<f32>>,
+    /// The horizontal insets of the item
+    inset_horizontal: Line<Option<f32>>,
+    /// The vertical insets of the item
+    inset_vertical: Line<Option<f32>>,
+}
+
+/// Resolve the inputs with which a grid item is laid out within its grid area
+///
+/// For right-to-left containers the margins and insets are those of the mirrored item (see [`Rect::for_direction`])
+pub(super) fn resolve_item_sizing<Tree: LayoutTree>(
+    tree: &Tree,
+    node: Tree::NodeId,
+    direction: Direction,
     grid_area: Rect<f32>,
     container_content_box: Size<f32>,
     container_alignment_styles: InBothAbsAxis<Option<AlignItems>>,
-) {
+) -> ItemSizing<Tree::NodeId> {
     let grid_area_size = Size { width: grid_area.right - grid_area.left, height: grid_area.bottom - grid_area.top };
 
     let style = tree.style(node);
     let aspect_ratio = style.aspect_ratio;
     let justify_self = style.justify_self;
     let align_self = style.align_self;
-
     let position = style.position;
-    let inset_horizontal =
-        style.inset.horizontal_components().map(|size| size.resolve_to_option(container_content_box.width));
+    let inset_horizontal = style
+        .inset
+        .for_direction(direction)
+        .horizontal_components()
+        .map(|size| size.resolve_to_option(container_content_box.width));
     let inset_vertical =
         style.inset.vertical_components().map(|size| size.resolve_to_option(container_content_box.height));
     let inherent_size = style.size.maybe_resolve(container_content_box);
@@ -114,15 +205,23 @@ pub(super) fn align_and_position_item(
 
     // Note: This is not a bug. It is part of the CSS spec that both horizontal and vertical margins
     // resolve against the WIDTH of the grid area.
-    let margin = style.margin.map(|margin| margin.resolve_to_option(grid_area_size.width));
+    let margin = style.margin.for_direction(direction).map(|margin| margin.resolve_to_option(grid_area_size.width));
     let grid_area_minus_item_margins_size = Size {
         width: grid_area_size.width.maybe_sub(margin.left).maybe_sub(margin.right),
         height: grid_area_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom),
     };
 
+    // Subgrids fill their grid area in the axes in which they inherit the tracks of the grid container
+    // Note: absolutely positioned grids are never subgrids
+    let is_subgrid = |axis| style.display == Display::Grid && position != Position::Absolute && style.is_subgrid(axis);
+    let subgrid_size = Size {
+        width: is_subgrid(AbsoluteAxis::Horizontal).then_some(grid_area_minus_item_margins_size.width),
+        height: is_subgrid(AbsoluteAxis::Vertical).then_some(grid_area_minus_item_margins_size.height),
+    };
+
     // If node is absolutely positioned and width is not set explicitly, then deduce it
     // from left, right and container_content_box if both are set.
-    let width = inherent_size.width.or_else(|| {
+    let width = subgrid_size.width.or(inherent_size.width).or_else(|| {
         // Apply width derived from both the left and right properties of an absolutely
         // positioned element being set
         if position == Position::Absolute {
@@ -145,7 +244,7 @@ pub(super) fn align_and_position_item(
 
         None
     });
-    let height = inherent_size.height.or_else(|| {
+    let height = subgrid_size.height.or(inherent_size.height).or_else(|| {
         if position == Position::Absolute {
             if let (Some(top), Some(bottom)) = (inset_vertical.start, inset_vertical.end) {
                 return Some(f32_max(grid_area_size.height - top - bottom, 0.0));
@@ -169,17 +268,127 @@ pub(super) fn align_and_position_item(
         None
     });
 
+    ItemSizing {
+        input: LayoutInput {
+            node,
+            known_dimensions: Size { width, height },
+            available_space: grid_area_minus_item_margins_size.map(AvailableSpace::Definite),
+            sizing_mode: SizingMode::InherentSize,
+        },
+        alignment_styles,
+        margin,
+        inset_horizontal,
+        inset_vertical,
+    }
+}
+
+/// Compute the outer size (including margins) in the masonry axis of an in-flow item of a masonry container, when the
+/// item is laid out in a grid area of size `grid_area_size` in the grid axis
+///
+/// Items are sized in the grid axis as they are within any grid area, but they are not stretched in the masonry axis.
+/// For right-to-left containers the margins are those of the mirrored item (see [`Rect::for_direction`])
+pub(super) fn compute_masonry_item_outer_size<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    direction: Direction,
+    masonry_axis: AbsoluteAxis,
+    grid_area_size: f32,
+    container_content_box: Size<Option<f32>>,
+    container_alignment_styles: InBothAbsAxis<Option<AlignItems>>,
+) -> f32 {
+    let grid_axis = masonry_axis.other_axis();
+    let style = tree.style(node);
+    let inherent_size = style.size.maybe_resolve(container_content_box);
+    let min_size = style.min_size.maybe_resolve(container_content_box);
+    let max_size = style.max_size.maybe_resolve(container_content_box);
+
+    // Margins resolve against the width of the grid area, which isn't known until the item has been sized if the
+    // masonry axis is horizontal
+    let margin_basis = if grid_axis == AbsoluteAxis::Horizontal { grid_area_size } else { 0.0 };
+    let margin = style.margin.for_direction(direction).map(|margin| margin.resolve_to_option(margin_basis));
+    let axis_margin = |axis| match axis {
+        AbsoluteAxis::Horizontal => margin.horizontal_components(),
+        AbsoluteAxis::Vertical => margin.vertical_components(),
+    };
+    let grid_axis_margin = axis_margin(grid_axis);
+    let masonry_axis_margin = axis_margin(masonry_axis);
+    let grid_area_minus_item_margins_size =
+        grid_area_size - grid_axis_margin.start.unwrap_or(0.0) - grid_axis_margin.end.unwrap_or(0.0);
+
+    // Items are stretched in the grid axis under the same conditions as in `resolve_item_sizing`
+    let self_alignment = match grid_axis {
+        AbsoluteAxis::Horizontal => style.justify_self,
+        AbsoluteAxis::Vertical => style.align_self,
+    };
+    let alignment_style = container_alignment_styles.get(grid_axis).or(self_alignment).unwrap_or_else(|| {
+        if inherent_size.get_abs(grid_axis).is_some() || style.aspect_ratio.is_some() {
+            AlignSelf::Start
+        } else {
+            AlignSelf::Stretch
+        }
+    });
+    let grid_axis_size = inherent_size.get_abs(grid_axis).or_else(|| {
+        let is_stretched =
+            grid_axis_margin.start.is_some() && grid_axis_margin.end.is_some() && alignment_style == AlignSelf::Stretch;
+        is_stretched.then(|| {
+            grid_area_minus_item_margins_size
+                .maybe_min(max_size.get_abs(grid_axis))
+                .maybe_max(min_size.get_abs(grid_axis))
+        })
+    });
+
+    let mut known_dimensions = Size::NONE;
+    known_dimensions.set(grid_axis.as_abstract_naive(), grid_axis_size);
+    known_dimensions.set(masonry_axis.as_abstract_naive(), inherent_size.get_abs(masonry_axis));
+    let mut available_space = Size::MAX_CONTENT;
+    available_space.set(grid_axis.as_abstract_naive(), AvailableSpace::Definite(grid_area_minus_item_margins_size));
+
+    let size = compute_node_layout(
+        tree,
+        node,
+        known_dimensions,
+        available_space,
+        RunMode::ComputeSize,
+        SizingMode::InherentSize,
+    )
+    .size;
+
+    size.get_abs(masonry_axis) + masonry_axis_margin.start.unwrap_or(0.0) + masonry_axis_margin.end.unwrap_or(0.0)
+}
+
+/// Align and size a grid item into it's final position
+///
+/// For right-to-left containers the item is positioned within the mirrored container (see [`Rect::for_direction`])
+pub(super) fn align_and_position_item<Tree: LayoutTree>(
+    tree: &mut Tree,
+    sizing: ItemSizing<Tree::NodeId>,
+    order: u32,
+    direction: Direction,
+    visibility: Visibility,
+    grid_area: Rect<f32>,
+    baseline_shim: f32,
+) {
+    let ItemSizing { input, alignment_styles, margin, inset_horizontal, inset_vertical } = sizing;
+    let node = input.node;
+    let Size { width, height } = input.known_dimensions;
+    let grid_area_size = Size { width: grid_area.right - grid_area.left, height: grid_area.bottom - grid_area.top };
+
+    let style = tree.style(node);
+    let justify_self = style.justify_self;
+    let align_self = style.align_self;
+    let position = style.position;
+
     // Layout node
-    let measured_size = compute_node_layout(
+    let SizeAndBaseline { size: measured_size, first_baseline, content_size } = compute_node_layout(
         tree,
         node,
-        Size { width, height },
-        grid_area_minus_item_margins_size.map(AvailableSpace::Definite),
+        input.known_dimensions,
+        input.available_space,
         RunMode::PeformLayout,
-        SizingMode::InherentSize,
+        input.sizing_mode,
     );
 
-    let (x, width) = align_and_size_item_within_area(
+    let (x, width, margin_horizontal) = align_and_size_item_within_area(
         Line { start: grid_area.left, end: grid_area.right },
         justify_self.unwrap_or(alignment_styles.horizontal),
         width,
@@ -187,8 +396,9 @@ pub(super) fn align_and_position_item(
         position,
         inset_horizontal,
         margin.horizontal_components(),
+        0.0,
     );
-    let (y, height) = align_and_size_item_within_area(
+    let (y, height, margin_vertical) = align_and_size_item_within_area(
         Line { start: grid_area.top, end: grid_area.bottom },
         align_self.unwrap_or(alignment_styles.vertical),
         height,
@@ -196,12 +406,42 @@ pub(super) fn align_and_position_item(
         position,
         inset_vertical,
         margin.vertical_components(),
+        baseline_shim,
     );
 
-    *tree.layout_mut(node) = Layout { order, size: Size { width, height }, location: Point { x, y } };
+    // Relatively positioned items are offset from their aligned position. Their containing block is the grid area.
+    // Note: horizontal offsets are flipped for right-to-left containers as they are laid out mirrored
+    let style = tree.style(node);
+    let relative_offset = compute_relative_offset(style, direction, grid_area_size.map(Some));
+    let x = if direction.is_rtl() { x - relative_offset.x } else { x + relative_offset.x };
+    let y = y + relative_offset.y;
+
+    // Note: both horizontal and vertical percentage padding/borders are resolved against the width of the grid area
+    let padding = style.padding.resolve_or_zero(Some(grid_area_size.width));
+    let border = style.border.resolve_or_zero(Some(grid_area_size.width));
+
+    *tree.layout_mut(node) = Layout {
+        order,
+        size: Size { width, height },
+        location: Point { x, y },
+        padding,
+        border,
+        margin: Rect {
+            left: margin_horizontal.start,
+            right: margin_horizontal.end,
+            top: margin_vertical.start,
+            bottom: margin_vertical.end,
+        },
+        content_size,
+        first_baseline,
+        visibility,
+    };
 }
 
 /// Align and size a grid item along a single axis
+///
+/// Returns the item's offset, size and resolved margins in the axis
+#[allow(clippy::too_many_arguments)]
 pub(super) fn align_and_size_item_within_area(
     grid_area: Line<f32>,
     alignment_style: AlignSelf,
@@ -210,7 +450,8 @@ pub(super) fn align_and_size_item_within_area(
     position: Position,
     inset: Line<Option<f32>>,
     margin: Line<Option<f32>>,
-) -> (f32, f32) {
+    baseline_shim: f32,
+) -> (f32, f32, Line<f32>) {
     // Calculate grid area dimension in the axis
     let non_auto_margin = Line { start: margin.start.unwrap_or(0.0), end: margin.end.unwrap_or(0.0) };
     let grid_area_size = f32_max(grid_area.end - grid_area.start, 0.0);
@@ -236,27 +477,23 @@ pub(super) fn align_and_size_item_within_area(
         AlignSelf::Start => resolved_margin.start,
         AlignSelf::End => grid_area_size - size - resolved_margin.end,
         AlignSelf::Center => (grid_area_size - size + resolved_margin.start - resolved_margin.end) / 2.0,
-        // TODO: Add support for baseline alignment. For now we treat it as "start".
-        AlignSelf::Baseline => resolved_margin.start,
+        // Baseline alignment is "start" alignment with the item shifted down by its baseline shim. Items that
+        // don't share a baseline with another item have a shim of zero.
+        AlignSelf::Baseline => resolved_margin.start + baseline_shim,
         AlignSelf::Stretch => resolved_margin.start,
     };
 
-    let offset_within_area = if position == Position::Absolute {
+    let (offset_within_area, used_margin) = if position == Position::Absolute {
         if let Some(start) = inset.start {
-            start + non_auto_margin.start
+            (start + non_auto_margin.start, non_auto_margin)
         } else if let Some(end) = inset.end {
-            grid_area_size - end - size - non_auto_margin.end
+            (grid_area_size - end - size - non_auto_margin.end, non_auto_margin)
         } else {
-            alignment_based_offset
+            (alignment_based_offset, resolved_margin)
         }
     } else {
-        alignment_based_offset
+        (alignment_based_offset, resolved_margin)
     };
 
-    let mut start = grid_area.start + offset_within_area;
-    if position == Position::Relative {
-        start += inset.start.or(inset.end.map(|pos| -pos)).unwrap_or(0.0);
-    }
-
-    (start, size)
+    (grid_area.start + offset_within_area, size, used_margin)
 }
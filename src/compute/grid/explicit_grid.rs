@@ -9,25 +9,38 @@ use crate::style::{
 };
 use crate::style_helpers::TaffyAuto;
 use crate::sys::{GridTrackVec, Vec};
-use core::cmp::{max, min};
 
-/// Compute the number of rows and columns in the explicit grid
+/// Compute the number of rows and columns in the explicit grid that are defined by the grid template
+/// (grid-template-rows / grid-template-columns). The explicit grid may be further extended by grid-template-areas.
 pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAxis) -> u16 {
     // Load the grid-template-rows or grid-template-columns definition (depending on the axis)
     let template = style.grid_template_tracks(axis);
 
     // If template contains no tracks, then there are trivially zero explcit tracks
-    if template.is_empty() {
+    if template.iter().all(|track_def| track_def.is_line_names()) {
+        return 0;
+    }
+
+    // A subgrid's explicit tracks are those it inherits from its parent grid rather than those defined by its template.
+    // A grid that is not a subgrid treats the `subgrid` template as though it defines no tracks.
+    if style.is_subgrid(axis) {
+        return 0;
+    }
+
+    // The masonry axis has no tracks (and a `masonry` template that doesn't define the masonry axis defines no tracks)
+    if template.first().is_some_and(TrackSizingFunction::is_masonry) {
         return 0;
     }
 
     let auto_repetition_count = template.iter().filter(|track_def| track_def.is_auto_repetition()).count() as u16;
-    let non_repeating_track_count = (template.len() as u16) - auto_repetition_count;
+    let non_repeating_track_count =
+        template.iter().map(fixed_track_count).fold(0u16, |count, track_count| count.saturating_add(track_count));
     let all_track_defs_have_fixed_component = template.iter().all(|track_def| match track_def {
         TrackSizingFunction::Single(sizing_function) => sizing_function.has_fixed_component(),
         TrackSizingFunction::AutoRepeat(_, tracks) => {
             tracks.iter().all(|sizing_function| sizing_function.has_fixed_component())
         }
+        TrackSizingFunction::LineNames(_) | TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry => true,
     });
 
     let template_is_valid =
@@ -39,17 +52,23 @@ pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAx
         return 0;
     }
 
-    // If there are no repetitions, then the number of explicit tracks is simply equal to the lengths of the track definition
-    // vector (as each item in the Vec represents one track).
+    // If there are no auto-repetitions, then the number of explicit tracks is simply equal to the number of tracks
+    // generated by the single track definitions and the repetitions with a fixed count.
     if auto_repetition_count == 0 {
-        return template.len() as u16;
+        return non_repeating_track_count;
     }
 
     let repetition_definition = template
         .iter()
         .find_map(|def| match def {
-            TrackSizingFunction::Single(_) => None,
-            TrackSizingFunction::AutoRepeat(_, tracks) => Some(tracks),
+            TrackSizingFunction::AutoRepeat(GridTrackRepetition::AutoFill | GridTrackRepetition::AutoFit, tracks) => {
+                Some(tracks)
+            }
+            TrackSizingFunction::Single(_)
+            | TrackSizingFunction::AutoRepeat(GridTrackRepetition::Count(_), _)
+            | TrackSizingFunction::LineNames(_)
+            | TrackSizingFunction::Subgrid
+            | TrackSizingFunction::Masonry => None,
         })
         .unwrap();
     let repetition_track_count = repetition_definition.len() as u16;
@@ -102,7 +121,17 @@ pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAx
                     TrackSizingFunction::Single(sizing_function) => {
                         track_definite_value(sizing_function, available_space)
                     }
-                    TrackSizingFunction::AutoRepeat(_, _) => 0.0,
+                    TrackSizingFunction::AutoRepeat(GridTrackRepetition::Count(count), tracks) => {
+                        let per_repetition_used_space: f32 = tracks
+                            .iter()
+                            .map(|sizing_function| track_definite_value(sizing_function, available_space))
+                            .sum();
+                        per_repetition_used_space * (*count as f32)
+                    }
+                    TrackSizingFunction::AutoRepeat(_, _)
+                    | TrackSizingFunction::LineNames(_)
+                    | TrackSizingFunction::Subgrid
+                    | TrackSizingFunction::Masonry => 0.0,
                 })
                 .sum();
             let gap_size = style.gap.get_abs(axis).resolve_or_zero(Some(inner_container_size));
@@ -148,11 +177,36 @@ pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAx
     non_repeating_track_count + (repetition_track_count * num_repetitions)
 }
 
+/// The number of tracks generated by a track definition, not counting auto-repeated tracks (as the number of those depends
+/// on the size of the grid container)
+pub(super) fn fixed_track_count(track_def: &TrackSizingFunction) -> u16 {
+    match track_def {
+        TrackSizingFunction::Single(_) => 1,
+        TrackSizingFunction::AutoRepeat(GridTrackRepetition::Count(count), tracks) => {
+            count.saturating_mul(tracks.len() as u16)
+        }
+        TrackSizingFunction::AutoRepeat(_, _)
+        | TrackSizingFunction::LineNames(_)
+        | TrackSizingFunction::Subgrid
+        | TrackSizingFunction::Masonry => 0,
+    }
+}
+
+/// Compute the number of rows or columns spanned by the named areas defined in grid-template-areas.
+/// The explicit grid contains at least this many tracks.
+pub(crate) fn compute_area_track_count_in_axis(style: &Style, axis: AbsoluteAxis) -> u16 {
+    style.grid_template_areas.iter().map(|area| area.lines(axis).end.saturating_sub(1)).max().unwrap_or(0)
+}
+
 /// Resolve the track sizing functions of explicit tracks, automatically created tracks, and gutters
 /// given a set of track counts and all of the relevant styles
+///
+/// `template_track_count` is the number of explicit tracks defined by `track_template`. Any further explicit tracks
+/// (created by grid-template-areas) are sized using `auto_tracks`.
 pub(super) fn initialize_grid_tracks(
     tracks: &mut Vec<GridTrack>,
     counts: TrackCounts,
+    template_track_count: u16,
     track_template: &GridTrackVec<TrackSizingFunction>,
     auto_tracks: &Vec<NonRepeatedTrackSizingFunction>,
     gap: LengthPercentage,
@@ -169,9 +223,8 @@ pub(super) fn initialize_grid_tracks(
         let iter = core::iter::repeat(NonRepeatedTrackSizingFunction::AUTO);
         create_implicit_tracks(tracks, counts.negative_implicit, iter, gap)
     } else {
-        let max_count = max(auto_tracks.len(), counts.negative_implicit as usize);
-        let min_count = min(auto_tracks.len(), counts.negative_implicit as usize);
-        let offset = max_count % min_count;
+        // The last negative implicit track (the one adjacent to the explicit grid) receives the last auto track size
+        let offset = auto_tracks.len() - (counts.negative_implicit as usize % auto_tracks.len());
         let iter = auto_tracks.iter().copied().cycle().skip(offset);
         create_implicit_tracks(tracks, counts.negative_implicit, iter, gap)
     }
@@ -181,7 +234,9 @@ pub(super) fn initialize_grid_tracks(
     // Create explicit tracks
     // An explicit check against the count (rather than just relying on track_template being empty) is required here
     // because a count of zero can result from the track_template being invalid, in which case it should be ignored.
-    if counts.explicit > 0 {
+    if template_track_count > 0 {
+        let non_auto_repeated_track_count: usize =
+            track_template.iter().map(|track_def| fixed_track_count(track_def) as usize).sum();
         track_template.iter().for_each(|track_sizing_function| match track_sizing_function {
             TrackSizingFunction::Single(sizing_function) => {
                 tracks
@@ -190,9 +245,14 @@ pub(super) fn initialize_grid_tracks(
                 current_track_index += 1;
             }
             TrackSizingFunction::AutoRepeat(repetition_kind, repeated_tracks) => {
-                let auto_repeated_track_count = (counts.explicit - (track_template.len() as u16 - 1)) as usize;
+                let repeated_track_count = match repetition_kind {
+                    GridTrackRepetition::Count(_) => fixed_track_count(track_sizing_function) as usize,
+                    GridTrackRepetition::AutoFill | GridTrackRepetition::AutoFit => {
+                        template_track_count as usize - non_auto_repeated_track_count
+                    }
+                };
                 let iter = repeated_tracks.iter().copied().cycle();
-                for track_def in iter.take(auto_repeated_track_count) {
+                for track_def in iter.take(repeated_track_count) {
                     let mut track = GridTrack::new(track_def.min_sizing_function(), track_def.max_sizing_function());
                     let mut gutter = GridTrack::gutter(gap);
 
@@ -208,15 +268,20 @@ pub(super) fn initialize_grid_tracks(
                     current_track_index += 1;
                 }
             }
+            TrackSizingFunction::LineNames(_) | TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry => {}
         });
     }
 
-    // Create positive implicit tracks
+    // Create explicit tracks that are not defined by the template (because grid-template-areas spans more tracks than
+    // the template defines) followed by positive implicit tracks. Both are sized using grid-auto-rows/grid-auto-columns.
+    let area_track_count = counts.explicit.saturating_sub(template_track_count);
     if auto_tracks.is_empty() {
-        let iter = core::iter::repeat(NonRepeatedTrackSizingFunction::AUTO);
+        let mut iter = core::iter::repeat(NonRepeatedTrackSizingFunction::AUTO);
+        create_implicit_tracks(tracks, area_track_count, &mut iter, gap);
         create_implicit_tracks(tracks, counts.positive_implicit, iter, gap)
     } else {
-        let iter = auto_tracks.iter().copied().cycle();
+        let mut iter = auto_tracks.iter().copied().cycle();
+        create_implicit_tracks(tracks, area_track_count, &mut iter, gap);
         create_implicit_tracks(tracks, counts.positive_implicit, iter, gap)
     }
 
@@ -405,6 +470,63 @@ mod test {
         assert_eq!(height, 4); // 20px vertical padding
     }
 
+    #[test]
+    fn explicit_grid_sizing_count_repeats() {
+        let grid_style = Style {
+            display: Display::Grid,
+            grid_template_columns: vec![points(10.0), repeat(3, vec![points(20.0), points(30.0)])],
+            grid_template_rows: vec![repeat(12, vec![flex(1.0)])],
+            ..Default::default()
+        };
+        let width = compute_explicit_grid_size_in_axis(&grid_style, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, AbsoluteAxis::Vertical);
+        assert_eq!(width, 7); // 1 non-repeated track + 3 repetitions * 2 repeated tracks
+        assert_eq!(height, 12);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_mix_count_repeat_and_auto_fill() {
+        use GridTrackRepetition::AutoFill;
+        let grid_style = Style {
+            display: Display::Grid,
+            size: Size { width: points(200.0), height: points(200.0) },
+            grid_template_columns: vec![repeat(2, vec![points(40.0)]), repeat(AutoFill, vec![points(20.0)])],
+            grid_template_rows: vec![repeat(2, vec![auto()]), repeat(AutoFill, vec![points(20.0)])],
+            gap: points(10.0),
+            ..Default::default()
+        };
+        let width = compute_explicit_grid_size_in_axis(&grid_style, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, AbsoluteAxis::Vertical);
+        assert_eq!(width, 5); // 2 count-repeated tracks + 3 auto-repeated tracks + 4 gaps
+        assert_eq!(height, 0); // Auto-repetition requires every track in the template to have a fixed size
+    }
+
+    #[test]
+    fn test_initialize_grid_tracks_count_repeat() {
+        let px0 = LengthPercentage::Points(0.0);
+        let px100 = LengthPercentage::Points(100.0);
+
+        let track_template = vec![points(100.0), repeat(2, vec![auto(), flex(1.0)])];
+        let track_counts = TrackCounts { negative_implicit: 0, explicit: 5, positive_implicit: 0 };
+
+        let mut tracks = Vec::new();
+        initialize_grid_tracks(&mut tracks, track_counts, 5, &track_template, &Vec::new(), px0, |_| false);
+
+        let expected = vec![
+            (MinTrackSizingFunction::Fixed(px100), MaxTrackSizingFunction::Fixed(px100)),
+            (MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Auto),
+            (MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Flex(1.0)),
+            (MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Auto),
+            (MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Flex(1.0)),
+        ];
+        let actual: Vec<_> = tracks
+            .iter()
+            .filter(|track| track.kind == GridTrackKind::Track)
+            .map(|track| (track.min_track_sizing_function, track.max_track_sizing_function))
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_initialize_grid_tracks() {
         let px0 = LengthPercentage::Points(0.0);
@@ -420,7 +542,15 @@ mod test {
 
         // Call function
         let mut tracks = Vec::new();
-        initialize_grid_tracks(&mut tracks, track_counts, &track_template, &auto_tracks, gap, |_| false);
+        initialize_grid_tracks(
+            &mut tracks,
+            track_counts,
+            track_template.len() as u16,
+            &track_template,
+            &auto_tracks,
+            gap,
+            |_| false,
+        );
 
         // Assertions
         let expected = vec![
@@ -4,6 +4,7 @@ use crate::geometry::Line;
 use crate::style::{GridPlacement, Style};
 use core::cmp::{max, min};
 
+use super::named::NamedLineResolver;
 use super::types::TrackCounts;
 use super::util::css_grid_line_into_origin_zero_coords;
 
@@ -18,12 +19,13 @@ use super::util::css_grid_line_into_origin_zero_coords;
 pub(crate) fn compute_grid_size_estimate<'a>(
     explicit_col_count: u16,
     explicit_row_count: u16,
+    name_resolver: &NamedLineResolver,
     child_styles_iter: impl Iterator<Item = &'a Style>,
 ) -> (TrackCounts, TrackCounts) {
     // Iterate over children, producing an estimate of the min and max grid lines (in origin-zero coordinates where)
     // along with the span of each itme
     let (col_min, col_max, col_max_span, row_min, row_max, row_max_span) =
-        get_known_child_positions(child_styles_iter, explicit_col_count, explicit_row_count);
+        get_known_child_positions(child_styles_iter, name_resolver, explicit_col_count, explicit_row_count);
 
     // Compute *track* count estimates for each axis from:
     //   - The explicit track counts
@@ -62,6 +64,7 @@ pub(crate) fn compute_grid_size_estimate<'a>(
 /// The span is measured in tracks spanned
 fn get_known_child_positions<'a>(
     children_iter: impl Iterator<Item = &'a Style>,
+    name_resolver: &NamedLineResolver,
     explicit_col_count: u16,
     explicit_row_count: u16,
 ) -> (i16, i16, u16, i16, i16, u16) {
@@ -69,10 +72,12 @@ fn get_known_child_positions<'a>(
     children_iter.for_each(|child_style: &Style| {
         // Note: that the children reference the lines in between (and around) the tracks not tracks themselves,
         // and thus we must subtract 1 to get an accurate estimate of the number of tracks
-        let (child_col_min, child_col_max, child_col_span) =
-            child_min_line_max_line_span(child_style.grid_column, explicit_col_count);
+        let (child_col_min, child_col_max, child_col_span) = child_min_line_max_line_span(
+            name_resolver.resolve_column_names(&child_style.grid_column),
+            explicit_col_count,
+        );
         let (child_row_min, child_row_max, child_row_span) =
-            child_min_line_max_line_span(child_style.grid_row, explicit_row_count);
+            child_min_line_max_line_span(name_resolver.resolve_row_names(&child_style.grid_row), explicit_row_count);
         col_min = min(col_min, child_col_min);
         col_max = max(col_max, child_col_max);
         col_max_span = max(col_max_span, child_col_span);
@@ -87,7 +92,7 @@ fn get_known_child_positions<'a>(
 /// Helper function for `compute_grid_size_estimate`
 /// Produces a conservative estimate of the greatest and smallest grid lines used by a single grid item
 ///
-/// Values are returned in origin-zero coordinates
+/// Values are returned in origin-zero coordinates. Any named lines in the placement must already have been resolved.
 #[inline]
 fn child_min_line_max_line_span(line: Line<GridPlacement>, explicit_track_count: u16) -> (i16, i16, u16) {
     use GridPlacement::*;
@@ -124,6 +129,9 @@ fn child_min_line_max_line_span(line: Line<GridPlacement>, explicit_track_count:
         // Only spans or autos
         // We ignore spans here by returning 1 which never effect the estimate as these are accounted for separately
         (Auto | Span(_), Auto | Span(_)) => 1,
+
+        // Named lines are resolved into line indexes before computing the estimate
+        _ => panic!("child_min_line_max_line_span should only be called once named lines have been resolved"),
     };
 
     let max = match (line.start, line.end) {
@@ -150,6 +158,9 @@ fn child_min_line_max_line_span(line: Line<GridPlacement>, explicit_track_count:
         // Only spans or autos
         // We ignore spans here by returning 1 which never effect the estimate as these are accounted for separately
         (Auto | Span(_), Auto | Span(_)) => 1,
+
+        // Named lines are resolved into line indexes before computing the estimate
+        _ => panic!("child_min_line_max_line_span should only be called once named lines have been resolved"),
     };
 
     // Calculate span only for indefinitely placed items as we don't need for other items (whose required space will
@@ -189,8 +200,10 @@ mod tests {
 
     mod test_intial_grid_sizing {
         use super::super::compute_grid_size_estimate;
+        use crate::compute::grid::named::NamedLineResolver;
         use crate::compute::grid::util::test_helpers::*;
         use crate::style::GridPlacement::*;
+        use crate::style::Style;
 
         #[test]
         fn explicit_grid_sizing_with_children() {
@@ -200,8 +213,10 @@ mod tests {
                 (Line(1), Span(2), Line(2), Auto).into_grid_child(),
                 (Line(-4), Auto, Line(-2), Auto).into_grid_child(),
             ];
+            let grid_style = Style::DEFAULT;
+            let name_resolver = NamedLineResolver::new(&grid_style, explicit_col_count, explicit_row_count);
             let (inline, block) =
-                compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles.iter());
+                compute_grid_size_estimate(explicit_col_count, explicit_row_count, &name_resolver, child_styles.iter());
             assert_eq!(inline.negative_implicit, 0);
             assert_eq!(inline.explicit, explicit_col_count);
             assert_eq!(inline.positive_implicit, 0);
@@ -218,8 +233,10 @@ mod tests {
                 (Line(-6), Span(2), Line(-8), Auto).into_grid_child(),
                 (Line(4), Auto, Line(3), Auto).into_grid_child(),
             ];
+            let grid_style = Style::DEFAULT;
+            let name_resolver = NamedLineResolver::new(&grid_style, explicit_col_count, explicit_row_count);
             let (inline, block) =
-                compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles.iter());
+                compute_grid_size_estimate(explicit_col_count, explicit_row_count, &name_resolver, child_styles.iter());
             assert_eq!(inline.negative_implicit, 1);
             assert_eq!(inline.explicit, explicit_col_count);
             assert_eq!(inline.positive_implicit, 0);
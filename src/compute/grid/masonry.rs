@@ -0,0 +1,117 @@
+//! Masonry layout: grid containers that stack their items in one axis rather than placing them in tracks
+//! <https://drafts.csswg.org/css-grid-3/>
+//!
+//! A masonry container only has tracks in one axis (the grid axis). These are sized by the track sizing algorithm like
+//! the tracks of any grid, and each item is then placed in the tracks of the grid axis in which it is closest to the
+//! start of the other axis (the masonry axis), directly after the items already placed in those tracks. The masonry axis
+//! is represented by a single track that holds all of the items, so that it is aligned by `align-content` (or
+//! `justify-content`) like the tracks of any other grid.
+//!
+//! Subgrids don't inherit the tracks of a masonry container, and named lines and areas in the masonry axis are ignored.
+use super::alignment::compute_masonry_item_outer_size;
+use super::named::NamedLineResolver;
+use super::placement::{place_masonry_item, MasonryPlacement};
+use super::types::{GridItem, GridTrack, TrackCounts};
+use crate::axis::{AbsoluteAxis, InBothAbsAxis};
+use crate::geometry::{Line, Size};
+use crate::resolve::ResolveOrZero;
+use crate::style::{Direction, Display, MaxTrackSizingFunction, MinTrackSizingFunction, Style, Visibility};
+use crate::style_helpers::zero;
+use crate::sys::{f32_max, GridTrackVec, Vec};
+use crate::tree::LayoutTree;
+
+use super::super::common::order::order_modified_document_order;
+
+/// Initialize the single track of the masonry axis (and the zero-sized gutters at its edges)
+pub(super) fn initialize_masonry_track(tracks: &mut GridTrackVec<GridTrack>) {
+    tracks.push(GridTrack::gutter(zero()));
+    tracks.push(GridTrack::new(MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Auto));
+    tracks.push(GridTrack::gutter(zero()));
+}
+
+/// Place the in-flow children of a masonry container in the tracks of its grid axis, which must already be sized, and
+/// stack them in the masonry axis. Returns the placed items, and sizes the track of the masonry axis to fit them.
+///
+/// Children with a definite position in the grid axis are placed before the other children, each in order-modified
+/// document order.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn place_masonry_items<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    style: &Style,
+    direction: Direction,
+    visibility: Visibility,
+    masonry_axis: AbsoluteAxis,
+    name_resolver: &NamedLineResolver,
+    grid_axis_track_counts: TrackCounts,
+    grid_axis_tracks: &[GridTrack],
+    masonry_axis_tracks: &mut [GridTrack],
+    container_content_box: Size<Option<f32>>,
+) -> Vec<GridItem<Tree::NodeId>> {
+    let grid_axis = masonry_axis.other_axis();
+    let gap = style.gap.get_abs(masonry_axis).resolve_or_zero(container_content_box.get_abs(masonry_axis));
+    let container_alignment_styles = InBothAbsAxis { horizontal: style.justify_items, vertical: style.align_items };
+
+    let children: Vec<_> = order_modified_document_order(tree, node)
+        .into_iter()
+        .map(|index| (index, tree.child(node, index)))
+        .filter(|&(_, child)| {
+            let child_style = tree.style(child);
+            child_style.display != Display::None
+                && !child_style.position.is_out_of_flow()
+                && child_style.visibility.unwrap_or(visibility) != Visibility::Collapse
+        })
+        .map(|(index, child)| {
+            let placement =
+                MasonryPlacement::resolve(tree.style(child), grid_axis, grid_axis_track_counts.explicit, name_resolver);
+            (index, child, placement)
+        })
+        .collect();
+    let is_definite = |placement: &MasonryPlacement| matches!(placement, MasonryPlacement::Definite(_));
+    let definitely_placed_children = children.iter().filter(|(_, _, placement)| is_definite(placement));
+    let auto_placed_children = children.iter().filter(|(_, _, placement)| !is_definite(placement));
+
+    let mut running_positions: Vec<f32> = (0..grid_axis_track_counts.len()).map(|_| 0.0).collect();
+    let mut items = Vec::with_capacity(children.len());
+    for &(index, child, placement) in definitely_placed_children.chain(auto_placed_children) {
+        let (tracks, position) = place_masonry_item(&running_positions, grid_axis_track_counts, placement);
+
+        // The grid area of the item in the grid axis spans its tracks and the gutters between them
+        let grid_area_size: f32 =
+            grid_axis_tracks[(2 * tracks.start + 1)..(2 * tracks.end)].iter().map(|track| track.base_size).sum();
+        let size = compute_masonry_item_outer_size(
+            tree,
+            child,
+            direction,
+            masonry_axis,
+            grid_area_size,
+            container_content_box,
+            container_alignment_styles,
+        );
+        running_positions[tracks.clone()]
+            .iter_mut()
+            .for_each(|running_position| *running_position = position + size + gap);
+
+        let grid_axis_lines =
+            grid_axis_track_counts.track_range_to_oz_line_range((tracks.start as i16)..(tracks.end as i16));
+        let masonry_axis_lines = Line { start: 0, end: 1 };
+        let (column, row) = match grid_axis {
+            AbsoluteAxis::Horizontal => (grid_axis_lines, masonry_axis_lines),
+            AbsoluteAxis::Vertical => (masonry_axis_lines, grid_axis_lines),
+        };
+        let mut item =
+            GridItem::new_with_placement_style_and_order(child, column, row, tree.style(child), index as u16);
+        item.masonry_extent = Line { start: position, end: position + size };
+        item.direction = tree.style(child).direction.unwrap_or(direction);
+        item.visibility = tree.style(child).visibility.unwrap_or(visibility);
+        items.push(item);
+    }
+
+    // The gap after the last item in each track is not part of the content
+    let content_size = running_positions.iter().copied().fold(0.0, f32_max);
+    let masonry_track = &mut masonry_axis_tracks[1];
+    masonry_track.base_size = if items.is_empty() { 0.0 } else { f32_max(content_size - gap, 0.0) };
+    masonry_track.growth_limit = masonry_track.base_size;
+
+    items
+}
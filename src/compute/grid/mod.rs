@@ -2,28 +2,46 @@
 //! https://www.w3.org/TR/css-grid-1/
 use crate::axis::{AbsoluteAxis, AbstractAxis, InBothAbsAxis};
 use crate::geometry::{Line, Rect, Size};
-use crate::layout::{Layout, RunMode, SizingMode};
+use crate::layout::{Layout, RunMode, SizeAndBaseline, SizingMode};
 use crate::math::MaybeMath;
-use crate::node::Node;
 use crate::resolve::{MaybeResolve, ResolveOrZero};
-use crate::style::{AlignContent, AvailableSpace, Display, Position};
+use crate::style::{AlignContent, AvailableSpace, Direction, Display, Position, Style, Visibility};
 use crate::style_helpers::*;
 use crate::sys::{GridTrackVec, Vec};
 use crate::tree::LayoutTree;
-use alignment::{align_and_position_item, align_tracks};
-use explicit_grid::{compute_explicit_grid_size_in_axis, initialize_grid_tracks};
+use alignment::{
+    align_and_position_item, align_tracks, compute_item_first_baseline, determine_first_baseline, first_baseline_item,
+    resolve_item_sizing,
+};
+use core::cmp::max;
+use explicit_grid::{compute_area_track_count_in_axis, compute_explicit_grid_size_in_axis, initialize_grid_tracks};
 use implicit_grid::compute_grid_size_estimate;
-use placement::place_grid_items;
-use track_sizing::{determine_if_item_crosses_flexible_tracks, resolve_item_track_indexes, track_sizing_algorithm};
-use types::{CellOccupancyMatrix, GridTrack};
+use masonry::{initialize_masonry_track, place_masonry_items};
+use named::NamedLineResolver;
+use placement::{place_grid_items, place_masonry_items_for_track_sizing};
+use subgrid::{
+    clamp_placement_to_inherited_lines, collect_subgrid_items, inset_items_at_inherited_edges, perform_subgrid_layout,
+    InheritedTracks,
+};
+use track_sizing::{
+    collapse_tracks_occupied_by_collapsed_items, determine_if_item_crosses_flexible_tracks, resolve_item_track_indexes,
+    track_sizing_algorithm,
+};
+use types::{CellOccupancyMatrix, GridItem, GridTrack, TrackCounts};
 use util::coordinates::css_grid_line_into_origin_zero_coords;
 
-use super::compute_node_layout;
+use super::common::content_size::compute_content_size;
+use super::common::direction::mirror_children_horizontally;
+use super::common::order::{compute_painting_order, order_modified_document_order};
+use super::{compute_node_layout, perform_hidden_layout};
 
 mod alignment;
 mod explicit_grid;
 mod implicit_grid;
+mod masonry;
+mod named;
 mod placement;
+mod subgrid;
 mod track_sizing;
 mod types;
 mod util;
@@ -34,72 +52,243 @@ mod util;
 ///   - Placing items (which also resolves the implicit grid)
 ///   - Track (row/column) sizing
 ///   - Alignment & Final item placement
-pub fn compute(tree: &mut impl LayoutTree, node: Node, available_space: Size<AvailableSpace>) -> Size<f32> {
-    let get_child_styles_iter = |node| tree.children(node).map(|child_node: &Node| tree.style(*child_node));
-    let style = tree.style(node).clone();
+pub fn compute<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+) -> SizeAndBaseline {
+    compute_grid(
+        tree,
+        node,
+        known_dimensions,
+        available_space,
+        run_mode,
+        InBothAbsAxis { horizontal: None, vertical: None },
+    )
+}
+
+/// The explicit grid of a grid container, and the placement of its in-flow children in the grid
+struct ItemPlacement<NodeId> {
+    /// The number of tracks defined by the grid template in each axis
+    template_track_counts: InBothAbsAxis<u16>,
+    /// The resolver of the named lines and areas of the grid
+    name_resolver: NamedLineResolver,
+    /// The cells occupied by the items. This also holds the final number of tracks in each axis (as auto-placement can
+    /// expand the implicit grid).
+    cell_occupancy_matrix: CellOccupancyMatrix,
+    /// The placed items, in the order in which they were placed
+    items: Vec<GridItem<NodeId>>,
+    /// The masonry axis of the grid, if it is a masonry container. The items of masonry containers are placed in every
+    /// position they could end up in, as the positions aren't known until the tracks have been sized.
+    masonry_axis: Option<AbsoluteAxis>,
+}
+
+/// Resolve the explicit grid of a grid container and place its in-flow children in it
+///
+/// In the axes in which the container is a subgrid (those for which a track count is passed in `inherited_track_counts`)
+/// the explicit grid consists of the inherited tracks, and there are no implicit tracks. Subgrids are never masonry
+/// containers.
+///
+/// `direction` and `visibility` are the resolved direction and visibility of the container, which are inherited by
+/// the items that don't set their own.
+fn place_items<Tree: LayoutTree>(
+    tree: &Tree,
+    node: Tree::NodeId,
+    style: &Style,
+    inherited_track_counts: InBothAbsAxis<Option<u16>>,
+    direction: Direction,
+    visibility: Visibility,
+) -> ItemPlacement<Tree::NodeId> {
+    let get_child_styles_iter = |node| tree.children(node).map(|child_node: Tree::NodeId| tree.style(child_node));
     let child_styles_iter = get_child_styles_iter(node);
 
     // 1. Resolve the explicit grid
-    // Exactly compute the number of rows and columns in the explicit grid.
-    let explicit_col_count = compute_explicit_grid_size_in_axis(&style, AbsoluteAxis::Horizontal);
-    let explicit_row_count = compute_explicit_grid_size_in_axis(&style, AbsoluteAxis::Vertical);
+    // Exactly compute the number of rows and columns in the explicit grid. This is the larger of the number of tracks
+    // defined by the grid template and the number of tracks spanned by the named grid areas.
+    let template_col_count = inherited_track_counts
+        .horizontal
+        .unwrap_or_else(|| compute_explicit_grid_size_in_axis(style, AbsoluteAxis::Horizontal));
+    let template_row_count = inherited_track_counts
+        .vertical
+        .unwrap_or_else(|| compute_explicit_grid_size_in_axis(style, AbsoluteAxis::Vertical));
+    let explicit_col_count = max(template_col_count, compute_area_track_count_in_axis(style, AbsoluteAxis::Horizontal));
+    let explicit_row_count = max(template_row_count, compute_area_track_count_in_axis(style, AbsoluteAxis::Vertical));
+
+    // Named lines and areas are resolved against the explicit grid
+    let name_resolver = NamedLineResolver::new(style, explicit_col_count, explicit_row_count);
 
     // 2. Implicit Grid: Estimate Track Counts
     // Estimate the number of rows and columns in the implicit grid (= the entire grid)
     // This is necessary as part of placement. Doing it early here is a perf optimisation to reduce allocations.
-    let (est_col_counts, est_row_counts) =
-        compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
+    let (mut est_col_counts, mut est_row_counts) =
+        compute_grid_size_estimate(explicit_col_count, explicit_row_count, &name_resolver, child_styles_iter);
+
+    // The masonry axis of a masonry container consists of a single track that holds all of the items
+    let is_subgrid = inherited_track_counts.horizontal.is_some() || inherited_track_counts.vertical.is_some();
+    let masonry_axis = style.masonry_axis().filter(|_| !is_subgrid);
+    match masonry_axis {
+        Some(AbsoluteAxis::Horizontal) => est_col_counts = TrackCounts::from_raw(0, 1, 0),
+        Some(AbsoluteAxis::Vertical) => est_row_counts = TrackCounts::from_raw(0, 1, 0),
+        None => {}
+    }
 
     // 2. Grid Item Placement
     // Match items (children) to a definite grid position (row start/end and column start/end position)
     let mut items = Vec::with_capacity(tree.child_count(node));
     let mut cell_occupancy_matrix = CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts);
     let grid_auto_flow = style.grid_auto_flow;
+    // Items are placed in order-modified document order (i.e. sorted by their `order` style)
+    let child_indexes_in_order = order_modified_document_order(tree, node);
     let in_flow_children_iter = || {
-        tree.children(node)
-            .copied()
-            .enumerate()
+        child_indexes_in_order
+            .iter()
+            .map(|&index| (index, tree.child(node, index)))
             .map(|(index, child_node)| (index, child_node, tree.style(child_node)))
-            .filter(|(_, _, style)| style.display != Display::None && style.position != Position::Absolute)
+            .filter(|(_, _, style)| style.display != Display::None && !style.position.is_out_of_flow())
     };
-    place_grid_items(&mut cell_occupancy_matrix, &mut items, in_flow_children_iter, grid_auto_flow);
+    match masonry_axis {
+        Some(masonry_axis) => place_masonry_items_for_track_sizing(
+            &mut cell_occupancy_matrix,
+            &mut items,
+            in_flow_children_iter,
+            masonry_axis,
+            &name_resolver,
+            visibility,
+        ),
+        None => place_grid_items(
+            &mut cell_occupancy_matrix,
+            &mut items,
+            in_flow_children_iter,
+            grid_auto_flow,
+            &name_resolver,
+        ),
+    }
+
+    for item in items.iter_mut() {
+        let item_style = tree.style(item.node);
+        item.direction = item_style.direction.unwrap_or(direction);
+        item.visibility = item_style.visibility.unwrap_or(visibility);
+    }
+
+    // Items that were placed outside of the inherited lines of a subgrid are clamped into them
+    if let Some(column_count) = inherited_track_counts.horizontal {
+        items.iter_mut().for_each(|item| item.column = clamp_placement_to_inherited_lines(item.column, column_count));
+    }
+    if let Some(row_count) = inherited_track_counts.vertical {
+        items.iter_mut().for_each(|item| item.row = clamp_placement_to_inherited_lines(item.row, row_count));
+    }
+
+    ItemPlacement {
+        template_track_counts: InBothAbsAxis { horizontal: template_col_count, vertical: template_row_count },
+        name_resolver,
+        cell_occupancy_matrix,
+        items,
+        masonry_axis,
+    }
+}
+
+/// Grid layout algorithm for a grid container that may be a subgrid
+///
+/// The tracks that a subgrid inherits from its parent grid are passed in `inherited_tracks`. These are used as they
+/// are rather than being sized by the grid container, and determine the size of the subgrid in their axis. Grid
+/// containers that aren't subgrids inherit no tracks.
+fn compute_grid<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+    inherited_tracks: InBothAbsAxis<Option<&InheritedTracks>>,
+) -> SizeAndBaseline {
+    let style = tree.style(node);
+    let min_size = style.min_size.maybe_resolve(available_space.into_options());
+    let max_size = style.max_size.maybe_resolve(available_space.into_options());
+    let clamped_style_size = style.size.maybe_resolve(available_space.into_options()).maybe_clamp(min_size, max_size);
+    let node_size = Size {
+        width: inherited_tracks.horizontal.map(|inherited| inherited.border_box_size),
+        height: inherited_tracks.vertical.map(|inherited| inherited.border_box_size),
+    }
+    .or(known_dimensions)
+    .or(clamped_style_size);
+
+    // Return early if both width and height are known and we only need the node's size
+    if run_mode == RunMode::ComputeSize {
+        if let Size { width: Some(width), height: Some(height) } = node_size {
+            return Size { width, height }.into();
+        }
+    }
+
+    let style = style.clone();
+    let direction = tree.direction(node);
+    let visibility = tree.visibility(node);
+
+    // 1-2. Resolve the explicit grid and place items
+    let inherited_track_counts = InBothAbsAxis {
+        horizontal: inherited_tracks.horizontal.map(InheritedTracks::track_count),
+        vertical: inherited_tracks.vertical.map(InheritedTracks::track_count),
+    };
+    let ItemPlacement { template_track_counts, name_resolver, cell_occupancy_matrix, mut items, masonry_axis } =
+        place_items(tree, node, &style, inherited_track_counts, direction, visibility);
 
     // Extract track counts from previous step (auto-placement can expand the number of tracks)
     let final_col_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal);
     let final_row_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical);
+    let final_col_counts = match inherited_track_counts.horizontal {
+        Some(count) => TrackCounts::from_raw(0, count, 0),
+        None => final_col_counts,
+    };
+    let final_row_counts = match inherited_track_counts.vertical {
+        Some(count) => TrackCounts::from_raw(0, count, 0),
+        None => final_row_counts,
+    };
 
     // 3. Initialize Tracks
     // Initialize (explicit and implicit) grid tracks (and gutters)
     // This resolves the min and max track sizing functions for all tracks and gutters
+    // Note: inherited tracks already have fixed sizing functions of their size in the parent grid
     let mut columns = GridTrackVec::new();
     let mut rows = GridTrackVec::new();
-    initialize_grid_tracks(
-        &mut columns,
-        final_col_counts,
-        &style.grid_template_columns,
-        &style.grid_auto_columns,
-        style.gap.width,
-        |column_index| cell_occupancy_matrix.column_is_occupied(column_index),
-    );
-    initialize_grid_tracks(
-        &mut rows,
-        final_row_counts,
-        &style.grid_template_rows,
-        &style.grid_auto_rows,
-        style.gap.height,
-        |row_index| cell_occupancy_matrix.row_is_occupied(row_index),
-    );
+    match inherited_tracks.horizontal {
+        Some(inherited) => columns.clone_from(&inherited.tracks),
+        None if masonry_axis == Some(AbsoluteAxis::Horizontal) => initialize_masonry_track(&mut columns),
+        None => initialize_grid_tracks(
+            &mut columns,
+            final_col_counts,
+            template_track_counts.horizontal,
+            &style.grid_template_columns,
+            &style.grid_auto_columns,
+            style.gap.width,
+            |column_index| cell_occupancy_matrix.column_is_occupied(column_index),
+        ),
+    }
+    match inherited_tracks.vertical {
+        Some(inherited) => rows.clone_from(&inherited.tracks),
+        None if masonry_axis == Some(AbsoluteAxis::Vertical) => initialize_masonry_track(&mut rows),
+        None => initialize_grid_tracks(
+            &mut rows,
+            final_row_counts,
+            template_track_counts.vertical,
+            &style.grid_template_rows,
+            &style.grid_auto_rows,
+            style.gap.height,
+            |row_index| cell_occupancy_matrix.row_is_occupied(row_index),
+        ),
+    }
 
     // 4. Compute "available grid space"
     // https://www.w3.org/TR/css-grid-1/#available-grid-space
-    let padding = style.padding.resolve_or_zero(available_space.width.into_option());
-    let border = style.border.resolve_or_zero(available_space.width.into_option());
-    let min_size = style.min_size.maybe_resolve(available_space.into_options());
-    let max_size = style.max_size.maybe_resolve(available_space.into_options());
-    let size = style.size.maybe_resolve(available_space.into_options());
+    // Note: space reserved for scrollbars is treated as additional padding at the end edge of each axis
+    // Note: right-to-left grids are laid out with their horizontal edges swapped, and then mirrored (see step 8)
+    let scrollbar_gutter = style.scrollbar_gutter();
+    let mut padding = style.padding.resolve_or_zero(available_space.width.into_option());
+    padding.right += scrollbar_gutter.width;
+    padding.bottom += scrollbar_gutter.height;
+    let padding = padding.for_direction(direction);
+    let border = style.border.resolve_or_zero(available_space.width.into_option()).for_direction(direction);
 
-    let constrained_available_space = size
-        .maybe_clamp(min_size, max_size)
+    let constrained_available_space = node_size
         .map(|size| size.map(AvailableSpace::Definite))
         .unwrap_or(available_space.maybe_clamp(min_size, max_size));
 
@@ -112,6 +301,22 @@ pub fn compute(tree: &mut impl LayoutTree, node: Node, available_space: Size<Ava
             .map_definite_value(|space| space - padding.vertical_axis_sum() - border.vertical_axis_sum()),
     };
 
+    // The items at the edges of a subgrid are inset from the lines it inherits by its margin, border and padding
+    if let Some(inherited) = inherited_tracks.horizontal {
+        let edges = Line { start: border.left + padding.left, end: border.right + padding.right };
+        inset_items_at_inherited_edges(&mut items, AbstractAxis::Inline, inherited, edges);
+    }
+    if let Some(inherited) = inherited_tracks.vertical {
+        let edges = Line { start: border.top + padding.top, end: border.bottom + padding.bottom };
+        inset_items_at_inherited_edges(&mut items, AbstractAxis::Block, inherited, edges);
+    }
+
+    // The children of subgrids take part in sizing the tracks that the subgrids inherit from this grid
+    // Note: subgrids don't inherit the tracks of masonry containers
+    if masonry_axis.is_none() {
+        collect_subgrid_items(tree, &mut items, available_space.width.into_option());
+    }
+
     // 5. Track Sizing
 
     // Convert grid placements in origin-zero coordinates to indexes into the GridTrack (rows and columns) vectors
@@ -119,131 +324,255 @@ pub fn compute(tree: &mut impl LayoutTree, node: Node, available_space: Size<Ava
     // each axis, and doing it up-front here means we don't have to keep repeating that calculation
     resolve_item_track_indexes(&mut items, final_col_counts, final_row_counts);
 
+    // Tracks that are only occupied by collapsed items are collapsed, and collapsed items otherwise don't take part in
+    // layout (as if they were not displayed)
+    // Note: the tracks inherited by subgrids are collapsed by their parent grid, and the tracks of masonry containers
+    // are sized before their items are placed in them
+    if masonry_axis.is_none() {
+        if inherited_tracks.horizontal.is_none() {
+            collapse_tracks_occupied_by_collapsed_items(AbstractAxis::Inline, &mut columns, &items);
+        }
+        if inherited_tracks.vertical.is_none() {
+            collapse_tracks_occupied_by_collapsed_items(AbstractAxis::Block, &mut rows, &items);
+        }
+    }
+    // Note: the collapsed children of masonry containers are never placed
+    let collapsed_items: Vec<_> = match masonry_axis {
+        Some(_) => (0..tree.child_count(node))
+            .map(|index| (tree.child(node, index), index as u16))
+            .filter(|&(child, _)| {
+                let child_style = tree.style(child);
+                child_style.display != Display::None
+                    && !child_style.position.is_out_of_flow()
+                    && child_style.visibility.unwrap_or(visibility) == Visibility::Collapse
+            })
+            .collect(),
+        None => items
+            .iter()
+            .filter(|item| !item.is_subgrid_item && item.is_collapsed())
+            .map(|item| (item.node, item.source_order))
+            .collect(),
+    };
+    items.retain(|item| !item.is_collapsed());
+
     // For each item, and in each axis, determine whether the item crosses any flexible (fr) tracks
     // Record this as a boolean (per-axis) on each item for later use in the track-sizing algorithm
     determine_if_item_crosses_flexible_tracks(&mut items, &columns, &rows);
 
+    // Masonry containers only have tracks in the grid axis, and these are sized once: the sizes of the items in the
+    // grid axis don't depend on the size of the masonry axis
+    let grid_axis = masonry_axis.map(|masonry_axis| masonry_axis.other_axis().as_abstract_naive());
+
     // Run track sizing algorithm for Inline axis
-    track_sizing_algorithm(
-        tree,
-        AbstractAxis::Inline,
-        available_space,
-        available_grid_space,
-        &style,
-        &mut columns,
-        &mut rows,
-        &mut items,
-        |track: &GridTrack, available_space: AvailableSpace| {
-            track.max_track_sizing_function.definite_value(available_space)
-        },
-    );
+    if grid_axis != Some(AbstractAxis::Block) {
+        track_sizing_algorithm(
+            tree,
+            AbstractAxis::Inline,
+            available_space,
+            available_grid_space,
+            &style,
+            &mut columns,
+            &mut rows,
+            &mut items,
+            |track: &GridTrack, available_space: AvailableSpace| {
+                track.max_track_sizing_function.definite_value(available_space)
+            },
+        );
+    }
     // Run track sizing algorithm for Block axis
-    track_sizing_algorithm(
-        tree,
-        AbstractAxis::Block,
-        available_space,
-        available_grid_space,
-        &style,
-        &mut rows,
-        &mut columns,
-        &mut items,
-        |track: &GridTrack, available_space: AvailableSpace| {
-            track.max_track_sizing_function.definite_value(available_space)
-        },
-    );
-    // Re-run track sizing algorithm for Inline axis
-    track_sizing_algorithm(
-        tree,
-        AbstractAxis::Inline,
-        available_space,
-        available_grid_space,
-        &style,
-        &mut columns,
-        &mut rows,
-        &mut items,
-        |track: &GridTrack, _| Some(track.base_size),
-    );
-    // Re-run track sizing algorithm for Block axis
-    track_sizing_algorithm(
-        tree,
-        AbstractAxis::Block,
-        available_space,
-        available_grid_space,
-        &style,
-        &mut rows,
-        &mut columns,
-        &mut items,
-        |track: &GridTrack, _| Some(track.base_size),
-    );
+    if grid_axis != Some(AbstractAxis::Inline) {
+        track_sizing_algorithm(
+            tree,
+            AbstractAxis::Block,
+            available_space,
+            available_grid_space,
+            &style,
+            &mut rows,
+            &mut columns,
+            &mut items,
+            |track: &GridTrack, available_space: AvailableSpace| {
+                track.max_track_sizing_function.definite_value(available_space)
+            },
+        );
+    }
+    if grid_axis.is_none() {
+        // Re-run track sizing algorithm for Inline axis
+        track_sizing_algorithm(
+            tree,
+            AbstractAxis::Inline,
+            available_space,
+            available_grid_space,
+            &style,
+            &mut columns,
+            &mut rows,
+            &mut items,
+            |track: &GridTrack, _| Some(track.base_size),
+        );
+        // Re-run track sizing algorithm for Block axis
+        track_sizing_algorithm(
+            tree,
+            AbstractAxis::Block,
+            available_space,
+            available_grid_space,
+            &style,
+            &mut rows,
+            &mut columns,
+            &mut items,
+            |track: &GridTrack, _| Some(track.base_size),
+        );
+    }
+
+    // The children of subgrids are laid out by their subgrids
+    items.retain(|item| !item.is_subgrid_item);
+
+    // The items of masonry containers are placed in the tracks of the grid axis now that these have been sized, and
+    // stacked in the masonry axis
+    if let Some(masonry_axis) = masonry_axis {
+        let (grid_axis_track_counts, grid_axis_tracks, masonry_axis_tracks) = match masonry_axis {
+            AbsoluteAxis::Horizontal => (final_row_counts, &rows, &mut columns),
+            AbsoluteAxis::Vertical => (final_col_counts, &columns, &mut rows),
+        };
+        items = place_masonry_items(
+            tree,
+            node,
+            &style,
+            direction,
+            visibility,
+            masonry_axis,
+            &name_resolver,
+            grid_axis_track_counts,
+            grid_axis_tracks,
+            masonry_axis_tracks,
+            available_grid_space.into_options(),
+        );
+        resolve_item_track_indexes(&mut items, final_col_counts, final_row_counts);
+    }
 
     // 6. Compute container size
-    let resolved_style_size = style.size.maybe_resolve(available_space.into_options());
     let container_border_box = Size {
-        width: resolved_style_size.get(AbstractAxis::Inline).unwrap_or_else(|| {
+        width: node_size.width.unwrap_or_else(|| {
             columns.iter().map(|track| track.base_size).sum::<f32>()
                 + padding.horizontal_axis_sum()
                 + border.horizontal_axis_sum()
         }),
-        height: resolved_style_size.get(AbstractAxis::Block).unwrap_or_else(|| {
+        height: node_size.height.unwrap_or_else(|| {
             rows.iter().map(|track| track.base_size).sum::<f32>()
                 + padding.vertical_axis_sum()
                 + border.vertical_axis_sum()
         }),
     };
+
     let container_content_box = Size {
         width: container_border_box.width - padding.horizontal_axis_sum() - border.horizontal_axis_sum(),
         height: container_border_box.height - padding.vertical_axis_sum() - border.vertical_axis_sum(),
     };
 
     // 7. Track Alignment
+    // Note: inherited tracks are already positioned
 
     // Align columns
-    align_tracks(
-        container_content_box.get(AbstractAxis::Inline),
-        Line { start: padding.left, end: padding.right },
-        Line { start: border.left, end: border.right },
-        &mut columns,
-        style.justify_content.unwrap_or(AlignContent::Stretch),
-    );
+    if inherited_tracks.horizontal.is_none() {
+        align_tracks(
+            container_content_box.get(AbstractAxis::Inline),
+            Line { start: padding.left, end: padding.right },
+            Line { start: border.left, end: border.right },
+            &mut columns,
+            style.justify_content.unwrap_or(AlignContent::Stretch),
+        );
+    }
     // Align rows
-    align_tracks(
-        container_content_box.get(AbstractAxis::Block),
-        Line { start: padding.top, end: padding.bottom },
-        Line { start: border.top, end: border.bottom },
-        &mut rows,
-        style.align_content.unwrap_or(AlignContent::Stretch),
-    );
+    if inherited_tracks.vertical.is_none() {
+        align_tracks(
+            container_content_box.get(AbstractAxis::Block),
+            Line { start: padding.top, end: padding.bottom },
+            Line { start: border.top, end: border.bottom },
+            &mut rows,
+            style.align_content.unwrap_or(AlignContent::Stretch),
+        );
+    }
+
+    let container_alignment_styles = InBothAbsAxis { horizontal: style.justify_items, vertical: style.align_items };
+
+    // If our caller only needs the size of the grid container then we are done now, once the first baseline of the
+    // container has been determined by sizing the item that provides it
+    if run_mode == RunMode::ComputeSize {
+        let first_baseline = first_baseline_item(tree, &items, style.align_items).map(|item| {
+            let grid_area = item_grid_area(item, &columns, &rows, masonry_axis);
+            let sizing = resolve_item_sizing(
+                tree,
+                item.node,
+                direction,
+                grid_area,
+                container_content_box,
+                container_alignment_styles,
+            );
+            compute_item_first_baseline(tree, sizing, direction, grid_area, item.baseline_shim)
+        });
+        return SizeAndBaseline { size: container_border_box, first_baseline, content_size: Size::ZERO };
+    }
 
     // 8. Size, Align, and Position Grid Items
 
     // Sort items back into original order to allow them to be matched up with styles
     items.sort_by_key(|item| item.source_order);
 
-    let container_alignment_styles = InBothAbsAxis { horizontal: style.justify_items, vertical: style.align_items };
+    let painting_order = compute_painting_order(tree, node);
 
-    // Position in-flow children (stored in items vector)
-    for (index, item) in items.iter().enumerate() {
-        let grid_area = Rect {
-            top: rows[item.row_indexes.start as usize + 1].offset,
-            bottom: rows[item.row_indexes.end as usize].offset,
-            left: columns[item.column_indexes.start as usize + 1].offset,
-            right: columns[item.column_indexes.end as usize].offset,
-        };
+    // Resolve the sizes of in-flow children (stored in items vector) within their grid areas
+    let item_areas_and_sizings: Vec<_> = items
+        .iter()
+        .map(|item| {
+            let grid_area = item_grid_area(item, &columns, &rows, masonry_axis);
+            let sizing = resolve_item_sizing(
+                tree,
+                item.node,
+                direction,
+                grid_area,
+                container_content_box,
+                container_alignment_styles,
+            );
+            (grid_area, sizing)
+        })
+        .collect();
+
+    // Subgrids are laid out in the tracks that they inherit from this grid, which aren't captured by their layout inputs
+    for (item, (_, sizing)) in items.iter().zip(&item_areas_and_sizings) {
+        if item.inherits_columns || item.inherits_rows {
+            perform_subgrid_layout(tree, item, sizing.input, direction, &columns, &rows);
+        }
+    }
+
+    // The sizes of the items no longer depend on each other, so their subtrees can be laid out independently
+    let inputs: Vec<_> = item_areas_and_sizings.iter().map(|(_, sizing)| sizing.input).collect();
+    tree.prepare_child_layouts(&inputs);
+
+    // Position in-flow children
+    for (item, (grid_area, sizing)) in items.iter().zip(item_areas_and_sizings) {
         align_and_position_item(
             tree,
-            item.node,
-            index as u32,
+            sizing,
+            painting_order[item.source_order as usize],
+            direction,
+            item.visibility,
             grid_area,
-            container_content_box,
-            container_alignment_styles,
+            item.baseline_shim,
         );
     }
 
+    // Collapsed items are not displayed, so they (and their descendants) are given a hidden layout
+    for (child, source_order) in collapsed_items {
+        let order = painting_order[source_order as usize];
+        *tree.layout_mut(child) = Layout { visibility: Visibility::Collapse, ..Layout::with_order(order) };
+        perform_hidden_layout(tree, child);
+    }
+
     // Position hidden and absolutely positioned children
-    let mut order = items.len() as u32;
+    // Note: absolutely positioned children of static grids are laid out by their containing block instead
+    let is_containing_block = style.position.is_positioned();
     (0..tree.child_count(node)).for_each(|index| {
         let child = tree.child(node, index);
         let child_style = tree.style(child);
+        let order = painting_order[index];
 
         // Position hidden child
         if child_style.display == Display::None {
@@ -256,30 +585,39 @@ pub fn compute(tree: &mut impl LayoutTree, node: Node, available_space: Size<Ava
                 RunMode::PeformLayout,
                 SizingMode::InherentSize,
             );
-            order += 1;
             return;
         }
 
         // Position absolutely positioned child
-        if child_style.position == Position::Absolute {
+        if child_style.position == Position::Absolute && is_containing_block {
             // Convert grid-col-{start/end} into Option's of indexes into the columns vector
             // The Option is None if the style property is Auto and an unresolvable Span
-            let maybe_grid_cols = child_style.grid_column.resolve_absolutely_positioned_grid_tracks();
-            let maybe_col_indexes = maybe_grid_cols.map(|maybe_grid_line| {
-                maybe_grid_line.map(|grid_line| {
-                    let oz_line = css_grid_line_into_origin_zero_coords(grid_line, final_col_counts.explicit);
-                    final_col_counts.oz_line_to_grid_track_vec_index(oz_line) as usize
-                })
-            });
+            let maybe_grid_cols = name_resolver
+                .resolve_column_names(&child_style.grid_column)
+                .resolve_absolutely_positioned_grid_tracks();
+            // Note: absolutely positioned children span the whole masonry axis of masonry containers
+            let maybe_col_indexes = match masonry_axis {
+                Some(AbsoluteAxis::Horizontal) => Line { start: None, end: None },
+                _ => maybe_grid_cols.map(|maybe_grid_line| {
+                    maybe_grid_line.map(|grid_line| {
+                        let oz_line = css_grid_line_into_origin_zero_coords(grid_line, final_col_counts.explicit);
+                        final_col_counts.oz_line_to_grid_track_vec_index(oz_line) as usize
+                    })
+                }),
+            };
             // Convert grid-row-{start/end} into Option's of indexes into the row vector
             // The Option is None if the style property is Auto and an unresolvable Span
-            let maybe_grid_rows = child_style.grid_row.resolve_absolutely_positioned_grid_tracks();
-            let maybe_row_indexes = maybe_grid_rows.map(|maybe_grid_line| {
-                maybe_grid_line.map(|grid_line| {
-                    let oz_line = css_grid_line_into_origin_zero_coords(grid_line, final_row_counts.explicit);
-                    final_row_counts.oz_line_to_grid_track_vec_index(oz_line) as usize
-                })
-            });
+            let maybe_grid_rows =
+                name_resolver.resolve_row_names(&child_style.grid_row).resolve_absolutely_positioned_grid_tracks();
+            let maybe_row_indexes = match masonry_axis {
+                Some(AbsoluteAxis::Vertical) => Line { start: None, end: None },
+                _ => maybe_grid_rows.map(|maybe_grid_line| {
+                    maybe_grid_line.map(|grid_line| {
+                        let oz_line = css_grid_line_into_origin_zero_coords(grid_line, final_row_counts.explicit);
+                        final_row_counts.oz_line_to_grid_track_vec_index(oz_line) as usize
+                    })
+                }),
+            };
 
             let grid_area = Rect {
                 top: maybe_row_indexes.start.map(|index| rows[index].offset).unwrap_or(0.0),
@@ -287,10 +625,55 @@ pub fn compute(tree: &mut impl LayoutTree, node: Node, available_space: Size<Ava
                 left: maybe_col_indexes.start.map(|index| columns[index].offset).unwrap_or(0.0),
                 right: maybe_col_indexes.end.map(|index| columns[index].offset).unwrap_or(container_border_box.width),
             };
-            align_and_position_item(tree, child, order, grid_area, container_content_box, container_alignment_styles);
-            order += 1;
+            let sizing = resolve_item_sizing(
+                tree,
+                child,
+                direction,
+                grid_area,
+                container_content_box,
+                container_alignment_styles,
+            );
+            let child_visibility = tree.style(child).visibility.unwrap_or(visibility);
+            align_and_position_item(tree, sizing, order, direction, child_visibility, grid_area, 0.0);
         }
     });
 
-    container_border_box
+    // Columns of right-to-left grids are placed from the right, so mirror the positions of the children
+    if direction.is_rtl() {
+        mirror_children_horizontally(tree, node, container_border_box.width);
+    }
+
+    // 9. Determine the first baseline and the content size of the grid container
+    let first_baseline = determine_first_baseline(tree, &items, style.align_items);
+    let content_size = compute_content_size(tree, node);
+
+    SizeAndBaseline { size: container_border_box, first_baseline, content_size }
+}
+
+/// The grid area of an in-flow item, from the offsets of the (aligned) tracks that it spans
+fn item_grid_area<NodeId>(
+    item: &GridItem<NodeId>,
+    columns: &[GridTrack],
+    rows: &[GridTrack],
+    masonry_axis: Option<AbsoluteAxis>,
+) -> Rect<f32> {
+    let mut grid_area = Rect {
+        top: rows[item.row_indexes.start as usize + 1].offset + item.subgrid_margin.top,
+        bottom: rows[item.row_indexes.end as usize].offset - item.subgrid_margin.bottom,
+        left: columns[item.column_indexes.start as usize + 1].offset + item.subgrid_margin.left,
+        right: columns[item.column_indexes.end as usize].offset - item.subgrid_margin.right,
+    };
+    // The grid area of an item of a masonry container in the masonry axis is the space that it was placed in
+    match masonry_axis {
+        Some(AbsoluteAxis::Horizontal) => {
+            grid_area.left = columns[1].offset + item.masonry_extent.start;
+            grid_area.right = columns[1].offset + item.masonry_extent.end;
+        }
+        Some(AbsoluteAxis::Vertical) => {
+            grid_area.top = rows[1].offset + item.masonry_extent.start;
+            grid_area.bottom = rows[1].offset + item.masonry_extent.end;
+        }
+        None => {}
+    }
+    grid_area
 }
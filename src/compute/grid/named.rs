@@ -0,0 +1,365 @@
+//! Resolves named grid lines and named grid areas into CSS grid line indexes.
+//! https://www.w3.org/TR/css-grid-1/#line-placement
+use super::explicit_grid::{compute_explicit_grid_size_in_axis, fixed_track_count};
+use super::util::{css_grid_line_into_origin_zero_coords, origin_zero_coords_into_css_grid_line};
+use crate::axis::AbsoluteAxis;
+use crate::geometry::Line;
+use crate::style::{GridLineName, GridPlacement, GridTemplateArea, GridTrackRepetition, Style, TrackSizingFunction};
+use crate::sys::Vec;
+use core::cmp::{max, min};
+
+/// A line name along with the (1-based) index of the explicit grid line that it names
+#[derive(Copy, Clone, Debug)]
+struct NamedGridLine {
+    /// The name of the line
+    name: GridLineName,
+    /// The CSS grid line index of the line
+    line: u16,
+}
+
+/// Whether a placement is the start or the end position of a grid item
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum PlacementEdge {
+    /// grid-row-start / grid-column-start
+    Start,
+    /// grid-row-end / grid-column-end
+    End,
+}
+
+/// Resolves the named lines and named areas referred to by grid items' placements into plain line indexes
+/// (`GridPlacement::Line` and `GridPlacement::Span`), using the line names and areas defined on the grid container.
+pub(super) struct NamedLineResolver {
+    /// The named column lines, including the lines implicitly named by areas (in ascending line order)
+    column_lines: Vec<NamedGridLine>,
+    /// The named row lines, including the lines implicitly named by areas (in ascending line order)
+    row_lines: Vec<NamedGridLine>,
+    /// The number of columns in the explicit grid
+    explicit_column_count: u16,
+    /// The number of rows in the explicit grid
+    explicit_row_count: u16,
+}
+
+impl NamedLineResolver {
+    /// Create a resolver for the grid container with the specified style and explicit grid size
+    pub(super) fn new(style: &Style, explicit_column_count: u16, explicit_row_count: u16) -> Self {
+        Self {
+            column_lines: collect_line_names(style, AbsoluteAxis::Horizontal),
+            row_lines: collect_line_names(style, AbsoluteAxis::Vertical),
+            explicit_column_count,
+            explicit_row_count,
+        }
+    }
+
+    /// Resolve any named lines or areas in a grid item's column placement
+    #[inline]
+    pub(super) fn resolve_column_names(&self, placement: &Line<GridPlacement>) -> Line<GridPlacement> {
+        self.resolve_names(placement, AbsoluteAxis::Horizontal)
+    }
+
+    /// Resolve any named lines or areas in a grid item's row placement
+    #[inline]
+    pub(super) fn resolve_row_names(&self, placement: &Line<GridPlacement>) -> Line<GridPlacement> {
+        self.resolve_names(placement, AbsoluteAxis::Vertical)
+    }
+
+    /// Resolve any named lines or areas in a grid item's placement in the specified axis.
+    ///
+    /// The returned placement only contains `Auto`, `Line` and `Span` values (in CSS grid line coordinates).
+    fn resolve_names(&self, placement: &Line<GridPlacement>, axis: AbsoluteAxis) -> Line<GridPlacement> {
+        use GridPlacement as GP;
+
+        let start = self.resolve_line(placement.start, PlacementEdge::Start, axis);
+        let end = self.resolve_line(placement.end, PlacementEdge::End, axis);
+
+        // Named spans count named lines away from the line at the opposite end of the placement. If the opposite end
+        // is not a definite line then the named span is treated as a span of 1.
+        match (start, end) {
+            (GP::NamedSpan(name, span), GP::Line(line)) if line != 0 => {
+                Line { start: GP::Line(self.find_nth_line_before(name, span, line, axis)), end }
+            }
+            (GP::Line(line), GP::NamedSpan(name, span)) if line != 0 => {
+                Line { start, end: GP::Line(self.find_nth_line_after(name, span, line, axis)) }
+            }
+            (start, end) => Line { start: unnamed_span(start), end: unnamed_span(end) },
+        }
+    }
+
+    /// Resolve a single named line or area placement into a `GridPlacement::Line`. Named spans are returned unmodified
+    /// as they can only be resolved relative to the opposite end of the placement.
+    fn resolve_line(&self, placement: GridPlacement, edge: PlacementEdge, axis: AbsoluteAxis) -> GridPlacement {
+        match placement {
+            GridPlacement::NamedLine(_, 0) => GridPlacement::Auto,
+            GridPlacement::NamedLine(name, index) => GridPlacement::Line(self.find_nth_line(name, index, axis)),
+            GridPlacement::NamedArea(name) => {
+                // The edge of a named area is the first <name>-start or <name>-end line, which is either named implicitly
+                // by a named area or explicitly (defining an implicit named area)
+                let edge_line_name = match edge {
+                    PlacementEdge::Start => GridTemplateArea::start_line_name(name),
+                    PlacementEdge::End => GridTemplateArea::end_line_name(name),
+                };
+                let (lines, _) = self.axis_lines(axis);
+                match lines.iter().any(|line| line.name == edge_line_name) {
+                    true => GridPlacement::Line(self.find_nth_line(edge_line_name, 1, axis)),
+                    // If there is no named area (and thus no <name>-start or <name>-end line) then the placement is
+                    // treated as referring to the first line with the name
+                    false => GridPlacement::Line(self.find_nth_line(name, 1, axis)),
+                }
+            }
+            placement => placement,
+        }
+    }
+
+    /// Get the explicit line names and explicit track count for the specified axis
+    fn axis_lines(&self, axis: AbsoluteAxis) -> (&[NamedGridLine], u16) {
+        match axis {
+            AbsoluteAxis::Horizontal => (&self.column_lines, self.explicit_column_count),
+            AbsoluteAxis::Vertical => (&self.row_lines, self.explicit_row_count),
+        }
+    }
+
+    /// Find the nth line with the specified name, counting from the start of the explicit grid if the index is positive
+    /// or from the end of the explicit grid if the index is negative.
+    ///
+    /// If there are not enough lines with the name, all implicit grid lines are assumed to have the name.
+    fn find_nth_line(&self, name: GridLineName, index: i16, axis: AbsoluteAxis) -> i16 {
+        let (lines, explicit_track_count) = self.axis_lines(axis);
+        let mut matching_lines = lines.iter().filter(|line| line.name == name).map(|line| line.line as i16 - 1);
+        let match_count = matching_lines.clone().count() as i16;
+
+        let oz_line = if index > 0 {
+            matching_lines.nth((index - 1) as usize).unwrap_or(explicit_track_count as i16 + (index - match_count))
+        } else {
+            let index = -index;
+            if index <= match_count {
+                matching_lines.nth((match_count - index) as usize).unwrap()
+            } else {
+                -(index - match_count)
+            }
+        };
+
+        origin_zero_coords_into_css_grid_line(oz_line, explicit_track_count)
+    }
+
+    /// Find the nth line with the specified name after the specified line.
+    ///
+    /// If there are not enough lines with the name, all implicit grid lines after the explicit grid are assumed to have the name.
+    fn find_nth_line_after(&self, name: GridLineName, span: u16, line: i16, axis: AbsoluteAxis) -> i16 {
+        let (lines, explicit_track_count) = self.axis_lines(axis);
+        let oz_line = css_grid_line_into_origin_zero_coords(line, explicit_track_count);
+        let span = max(span, 1) as i16;
+
+        let mut matching_lines =
+            lines.iter().filter(|line| line.name == name).map(|line| line.line as i16 - 1).filter(|&l| l > oz_line);
+        let match_count = matching_lines.clone().count() as i16;
+
+        let oz_result = matching_lines
+            .nth((span - 1) as usize)
+            .unwrap_or(max(oz_line, explicit_track_count as i16) + (span - match_count));
+
+        origin_zero_coords_into_css_grid_line(oz_result, explicit_track_count)
+    }
+
+    /// Find the nth line with the specified name before the specified line.
+    ///
+    /// If there are not enough lines with the name, all implicit grid lines before the explicit grid are assumed to have the name.
+    fn find_nth_line_before(&self, name: GridLineName, span: u16, line: i16, axis: AbsoluteAxis) -> i16 {
+        let (lines, explicit_track_count) = self.axis_lines(axis);
+        let oz_line = css_grid_line_into_origin_zero_coords(line, explicit_track_count);
+        let span = max(span, 1) as i16;
+
+        let mut matching_lines = lines
+            .iter()
+            .rev()
+            .filter(|line| line.name == name)
+            .map(|line| line.line as i16 - 1)
+            .filter(|&l| l < oz_line);
+        let match_count = matching_lines.clone().count() as i16;
+
+        let oz_result = matching_lines.nth((span - 1) as usize).unwrap_or(min(oz_line, 0) - (span - match_count));
+
+        origin_zero_coords_into_css_grid_line(oz_result, explicit_track_count)
+    }
+}
+
+/// A named span which could not be resolved against a definite line is treated as a span of 1
+#[inline]
+fn unnamed_span(placement: GridPlacement) -> GridPlacement {
+    match placement {
+        GridPlacement::NamedSpan(_, _) => GridPlacement::Span(1),
+        placement => placement,
+    }
+}
+
+/// Collect the names of the explicit grid lines in the specified axis from the grid template, along with the
+/// `<name>-start` and `<name>-end` lines implicitly named by the named areas
+fn collect_line_names(style: &Style, axis: AbsoluteAxis) -> Vec<NamedGridLine> {
+    let mut named_lines = collect_template_line_names(style, axis);
+    if style.grid_template_areas.is_empty() {
+        return named_lines;
+    }
+
+    for area in style.grid_template_areas.iter() {
+        let lines = area.lines(axis);
+        named_lines.push(NamedGridLine { name: GridTemplateArea::start_line_name(area.name), line: lines.start });
+        named_lines.push(NamedGridLine { name: GridTemplateArea::end_line_name(area.name), line: lines.end });
+    }
+    named_lines.sort_by_key(|named_line| named_line.line);
+
+    named_lines
+}
+
+/// Collect the names of the explicit grid lines in the specified axis from the grid template
+fn collect_template_line_names(style: &Style, axis: AbsoluteAxis) -> Vec<NamedGridLine> {
+    let template = style.grid_template_tracks(axis);
+    let mut named_lines = Vec::new();
+
+    // Avoid resolving the size of the explicit grid if there are no names to collect
+    if !template.iter().any(|track_def| track_def.is_line_names()) {
+        return named_lines;
+    }
+
+    // Line names in an invalid template are ignored along with the rest of the template
+    let template_track_count = compute_explicit_grid_size_in_axis(style, axis);
+    if template_track_count == 0 {
+        return named_lines;
+    }
+
+    let non_auto_repeated_track_count =
+        template.iter().map(fixed_track_count).fold(0u16, |count, track_count| count.saturating_add(track_count));
+    let auto_repeated_track_count = template_track_count - non_auto_repeated_track_count;
+
+    let mut line = 1;
+    for track_def in template.iter() {
+        match track_def {
+            TrackSizingFunction::Single(_) => line += 1,
+            TrackSizingFunction::AutoRepeat(GridTrackRepetition::Count(_), _) => line += fixed_track_count(track_def),
+            TrackSizingFunction::AutoRepeat(_, _) => line += auto_repeated_track_count,
+            TrackSizingFunction::LineNames(names) => {
+                named_lines.extend(names.iter().map(|&name| NamedGridLine { name, line }));
+            }
+            TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry => {}
+        }
+    }
+
+    named_lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NamedLineResolver;
+    use crate::geometry::Line;
+    use crate::prelude::*;
+
+    const A: GridLineName = GridLineName::new(1).unwrap();
+    const B: GridLineName = GridLineName::new(2).unwrap();
+    const MISSING: GridLineName = GridLineName::new(3).unwrap();
+
+    fn grid_style() -> Style {
+        Style {
+            display: Display::Grid,
+            grid_template_columns: vec![
+                line_names(vec![A]),
+                points(10.0),
+                line_names(vec![B]),
+                points(10.0),
+                line_names(vec![A, B]),
+                points(10.0),
+                line_names(vec![A]),
+            ],
+            grid_template_areas: vec![GridTemplateArea {
+                name: B,
+                row_start: 2,
+                row_end: 4,
+                column_start: 1,
+                column_end: 3,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn resolves_named_lines() {
+        let style = grid_style();
+        let resolver = NamedLineResolver::new(&style, 3, 3);
+        let resolve = |start, end| resolver.resolve_column_names(&Line { start, end });
+
+        assert_eq!(resolve(named_line(A, 1), named_line(A, 2)), Line { start: line(1), end: line(3) });
+        assert_eq!(resolve(named_line(A, -1), named_line(B, -1)), Line { start: line(4), end: line(3) });
+        assert_eq!(resolve(named_line(A, 4), named_line(A, -4)), Line { start: line(5), end: line(-5) });
+        assert_eq!(
+            resolve(named_line(MISSING, 1), GridPlacement::Auto),
+            Line { start: line(5), end: GridPlacement::Auto }
+        );
+    }
+
+    #[test]
+    fn resolves_named_spans() {
+        let style = grid_style();
+        let resolver = NamedLineResolver::new(&style, 3, 3);
+        let resolve = |start, end| resolver.resolve_column_names(&Line { start, end });
+
+        assert_eq!(resolve(line(1), named_span(A, 1)), Line { start: line(1), end: line(3) });
+        assert_eq!(resolve(line(1), named_span(A, 3)), Line { start: line(1), end: line(5) });
+        assert_eq!(resolve(named_span(B, 2), line(4)), Line { start: line(2), end: line(4) });
+        assert_eq!(resolve(named_span(B, 3), line(4)), Line { start: line(-5), end: line(4) });
+        assert_eq!(resolve(named_span(B, 1), GridPlacement::Auto), Line { start: span(1), end: GridPlacement::Auto });
+    }
+
+    #[test]
+    fn resolves_named_areas() {
+        let style = grid_style();
+        let resolver = NamedLineResolver::new(&style, 3, 3);
+
+        let area: Line<GridPlacement> = named_area(B);
+        assert_eq!(resolver.resolve_column_names(&area), Line { start: line(1), end: line(3) });
+        assert_eq!(resolver.resolve_row_names(&area), Line { start: line(2), end: line(4) });
+
+        // Without a matching area, a named area falls back to the first line with the name
+        let area: Line<GridPlacement> = named_area(A);
+        assert_eq!(resolver.resolve_column_names(&area), Line { start: line(1), end: line(1) });
+    }
+
+    #[test]
+    fn resolves_area_edge_line_names() {
+        let style = grid_style();
+        let resolver = NamedLineResolver::new(&style, 3, 3);
+        let (b_start, b_end) = (GridTemplateArea::start_line_name(B), GridTemplateArea::end_line_name(B));
+
+        // The named area implicitly names its edge lines in both axes
+        let placement = Line { start: named_line(b_start, 1), end: named_line(b_end, 1) };
+        assert_eq!(resolver.resolve_column_names(&placement), Line { start: line(1), end: line(3) });
+        assert_eq!(resolver.resolve_row_names(&placement), Line { start: line(2), end: line(4) });
+        let placement = Line { start: line(2), end: named_span(b_end, 1) };
+        assert_eq!(resolver.resolve_column_names(&placement), Line { start: line(2), end: line(3) });
+    }
+
+    #[test]
+    fn edge_line_names_define_implicit_areas() {
+        let style = Style {
+            display: Display::Grid,
+            grid_template_columns: vec![
+                points(10.0),
+                line_names(vec![GridTemplateArea::start_line_name(MISSING)]),
+                points(10.0),
+                points(10.0),
+                line_names(vec![GridTemplateArea::end_line_name(MISSING)]),
+            ],
+            ..Default::default()
+        };
+        let resolver = NamedLineResolver::new(&style, 3, 0);
+
+        let area: Line<GridPlacement> = named_area(MISSING);
+        assert_eq!(resolver.resolve_column_names(&area), Line { start: line(2), end: line(4) });
+        // There are no edge lines in the other axis, so the area falls back to the first line with the name (which is
+        // the first implicit line after the explicit grid)
+        assert_eq!(resolver.resolve_row_names(&area), Line { start: line(2), end: line(2) });
+    }
+
+    #[test]
+    fn names_cannot_alias_edge_line_names() {
+        assert_eq!(GridLineName::new(GridLineName::MAX_ID).map(GridLineName::id), Some(0x3FFF));
+        assert_eq!(GridLineName::new(GridTemplateArea::start_line_name(A).id()), None);
+        assert_eq!(GridLineName::new(GridTemplateArea::end_line_name(A).id()), None);
+        assert_eq!(GridLineName::new(u16::MAX), None);
+    }
+}
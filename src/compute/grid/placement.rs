@@ -1,24 +1,27 @@
 //! Implements placing items in the grid and resolving the implicit grid.
 //! https://www.w3.org/TR/css-grid-1/#placement
-use super::types::{CellOccupancyMatrix, CellOccupancyState, GridItem};
+use super::named::NamedLineResolver;
+use super::types::{CellOccupancyMatrix, CellOccupancyState, GridItem, TrackCounts};
 use super::util::css_grid_line_into_origin_zero_coords;
 use crate::axis::{AbsoluteAxis, InBothAbsAxis};
 use crate::geometry::Line;
-use crate::node::Node;
-use crate::style::{GridAutoFlow, GridPlacement, Style};
-use crate::sys::Vec;
+use crate::style::{GridAutoFlow, GridPlacement, Style, Visibility};
+use crate::sys::{f32_max, Vec};
+use core::cmp::min;
+use core::ops::Range;
 
 /// 8.5. Grid Item Placement Algorithm
 /// Place items into the grid, generating new rows/column into the implicit grid as required
 ///
 /// [Specification](https://www.w3.org/TR/css-grid-2/#auto-placement-algo)
-pub(super) fn place_grid_items<'a, ChildIter>(
+pub(super) fn place_grid_items<'a, NodeId: Copy, ChildIter>(
     cell_occupancy_matrix: &mut CellOccupancyMatrix,
-    items: &mut Vec<GridItem>,
+    items: &mut Vec<GridItem<NodeId>>,
     children_iter: impl Fn() -> ChildIter,
     grid_auto_flow: GridAutoFlow,
+    name_resolver: &NamedLineResolver,
 ) where
-    ChildIter: Iterator<Item = (usize, Node, &'a Style)>,
+    ChildIter: Iterator<Item = (usize, NodeId, &'a Style)>,
 {
     let primary_axis = grid_auto_flow.primary_axis();
     let secondary_axis = primary_axis.other_axis();
@@ -26,12 +29,12 @@ pub(super) fn place_grid_items<'a, ChildIter>(
     let map_child_style_to_origin_zero_placement = {
         let explicit_col_count = cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal).explicit;
         let explicit_row_count = cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical).explicit;
-        move |(index, node, style): (usize, Node, &'a Style)| -> (_, _, _, &'a Style) {
+        move |(index, node, style): (usize, NodeId, &'a Style)| -> (_, _, _, &'a Style) {
             let origin_zero_placement = InBothAbsAxis {
-                horizontal: style.grid_column.map(|placement| {
+                horizontal: name_resolver.resolve_column_names(&style.grid_column).map(|placement| {
                     placement.map_track(|track| css_grid_line_into_origin_zero_coords(track, explicit_col_count))
                 }),
-                vertical: style.grid_row.map(|placement| {
+                vertical: name_resolver.resolve_row_names(&style.grid_row).map(|placement| {
                     placement.map_track(|track| css_grid_line_into_origin_zero_coords(track, explicit_row_count))
                 }),
             };
@@ -203,9 +206,15 @@ fn place_indefinitely_positioned_item(
     let primary_placement_style = placement.get(primary_axis);
     let secondary_placement_style = placement.get(primary_axis.other_axis());
 
-    let primary_span = primary_placement_style.indefinite_span();
-    let secondary_span = secondary_placement_style.indefinite_span();
     let has_definite_primary_axis_position = primary_placement_style.is_definite();
+    let primary_span = match has_definite_primary_axis_position {
+        true => {
+            let definite_primary_placement = primary_placement_style.resolve_definite_grid_tracks();
+            (definite_primary_placement.end - definite_primary_placement.start) as u16
+        }
+        false => primary_placement_style.indefinite_span(),
+    };
+    let secondary_span = secondary_placement_style.indefinite_span();
     let primary_axis_length = cell_occupancy_matrix.track_counts(primary_axis).len() as i16;
 
     let track_area_is_unoccupied = |primary_range, secondary_range| {
@@ -272,13 +281,147 @@ fn place_indefinitely_positioned_item(
     }
 }
 
+/// The placement of an item in the grid axis of a masonry container
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(super) enum MasonryPlacement {
+    /// The item is placed between these grid lines (in origin-zero coordinates)
+    Definite(Line<i16>),
+    /// The item is placed automatically, and spans this number of tracks
+    Auto(u16),
+}
+
+impl MasonryPlacement {
+    /// Resolve the placement of an item in the grid axis of a masonry container
+    pub(super) fn resolve(
+        style: &Style,
+        grid_axis: AbsoluteAxis,
+        explicit_track_count: u16,
+        name_resolver: &NamedLineResolver,
+    ) -> Self {
+        let placement = match grid_axis {
+            AbsoluteAxis::Horizontal => name_resolver.resolve_column_names(&style.grid_column),
+            AbsoluteAxis::Vertical => name_resolver.resolve_row_names(&style.grid_row),
+        }
+        .map(|placement| {
+            placement.map_track(|track| css_grid_line_into_origin_zero_coords(track, explicit_track_count))
+        });
+
+        match placement.is_definite() {
+            true => Self::Definite(placement.resolve_definite_grid_tracks()),
+            false => Self::Auto(placement.indefinite_span()),
+        }
+    }
+}
+
+/// Masonry layout: place the items of a masonry container for the purpose of sizing the tracks of its grid axis
+///
+/// Items with a definite position in the grid axis are placed in the tracks they span (expanding the implicit grid as
+/// necessary). Items with an automatic position could end up in any tracks, so they are placed in every position in
+/// which they could be placed (the item is recorded once for each position). All items are placed in the single track
+/// of the masonry axis.
+///
+/// [Specification](https://drafts.csswg.org/css-grid-3/#track-sizing)
+pub(super) fn place_masonry_items_for_track_sizing<'a, NodeId: Copy, ChildIter>(
+    cell_occupancy_matrix: &mut CellOccupancyMatrix,
+    items: &mut Vec<GridItem<NodeId>>,
+    children_iter: impl Fn() -> ChildIter,
+    masonry_axis: AbsoluteAxis,
+    name_resolver: &NamedLineResolver,
+    container_visibility: Visibility,
+) where
+    ChildIter: Iterator<Item = (usize, NodeId, &'a Style)>,
+{
+    let grid_axis = masonry_axis.other_axis();
+    let explicit_track_count = cell_occupancy_matrix.track_counts(grid_axis).explicit;
+    let masonry_axis_span = Line { start: 0, end: 1 };
+    let placements: Vec<_> = children_iter()
+        .filter(|&(_, _, style)| style.visibility.unwrap_or(container_visibility) != Visibility::Collapse)
+        .map(|(index, node, style)| {
+            (index, node, style, MasonryPlacement::resolve(style, grid_axis, explicit_track_count, name_resolver))
+        })
+        .collect();
+
+    // 1. Place children with definite positions in the grid axis
+    for &(index, node, style, placement) in &placements {
+        if let MasonryPlacement::Definite(grid_axis_span) = placement {
+            record_grid_placement(
+                cell_occupancy_matrix,
+                items,
+                node,
+                index,
+                style,
+                grid_axis,
+                grid_axis_span,
+                masonry_axis_span,
+                CellOccupancyState::DefinitelyPlaced,
+            );
+        }
+    }
+
+    // 2. Place the remaining children in every position in the grid axis
+    for &(index, node, style, placement) in &placements {
+        if let MasonryPlacement::Auto(span) = placement {
+            let track_count = cell_occupancy_matrix.track_counts(grid_axis).len() as i16;
+            let span = span as i16;
+            for start in 0..=(track_count - span).max(0) {
+                let grid_axis_span = cell_occupancy_matrix.tracks_to_lines(grid_axis, start..(start + span));
+                record_grid_placement(
+                    cell_occupancy_matrix,
+                    items,
+                    node,
+                    index,
+                    style,
+                    grid_axis,
+                    grid_axis_span,
+                    masonry_axis_span,
+                    CellOccupancyState::AutoPlaced,
+                );
+            }
+        }
+    }
+}
+
+/// Masonry layout: place an item in the tracks of the grid axis of a masonry container
+///
+/// `running_positions` holds the position in the masonry axis at which the next item can be placed in each track of the
+/// grid axis. Items with a definite position in the grid axis are placed in the tracks they span. Other items are placed
+/// in the tracks in which they are closest to the start of the masonry axis (the first of these if several are equally
+/// close). Returns the tracks (as indexes into `running_positions`) and the position in the masonry axis of the item.
+///
+/// [Specification](https://drafts.csswg.org/css-grid-3/#masonry-layout-algorithm)
+pub(super) fn place_masonry_item(
+    running_positions: &[f32],
+    track_counts: TrackCounts,
+    placement: MasonryPlacement,
+) -> (Range<usize>, f32) {
+    let position_in_tracks =
+        |tracks: &Range<usize>| running_positions[tracks.clone()].iter().copied().fold(0.0, f32_max);
+
+    let tracks = match placement {
+        MasonryPlacement::Definite(lines) => {
+            let tracks = track_counts.oz_line_range_to_track_range(lines);
+            (tracks.start as usize)..(tracks.end as usize)
+        }
+        MasonryPlacement::Auto(span) => {
+            let span = min(span as usize, running_positions.len());
+            (0..=(running_positions.len() - span))
+                .map(|start| start..(start + span))
+                .min_by(|a, b| position_in_tracks(a).total_cmp(&position_in_tracks(b)))
+                .unwrap()
+        }
+    };
+    let position = position_in_tracks(&tracks);
+
+    (tracks, position)
+}
+
 /// Record the grid item in both CellOccupancyMatric and the GridItems list
 /// once a definite placement has been determined
 #[allow(clippy::too_many_arguments)]
-fn record_grid_placement(
+fn record_grid_placement<NodeId: Copy>(
     cell_occupancy_matrix: &mut CellOccupancyMatrix,
-    items: &mut Vec<GridItem>,
-    node: Node,
+    items: &mut Vec<GridItem<NodeId>>,
+    node: NodeId,
     index: usize,
     style: &Style,
     primary_axis: AbsoluteAxis,
@@ -317,6 +460,7 @@ mod tests {
 
     mod test_placement_algorithm {
         use crate::compute::grid::implicit_grid::compute_grid_size_estimate;
+        use crate::compute::grid::named::NamedLineResolver;
         use crate::compute::grid::types::TrackCounts;
         use crate::compute::grid::util::*;
         use crate::compute::grid::CellOccupancyMatrix;
@@ -339,13 +483,16 @@ mod tests {
             // Setup test
             let children_iter = || children.iter().map(|(index, node, style, _)| (*index, *node, style));
             let child_styles_iter = children.iter().map(|(_, _, style, _)| style);
-            let estimated_sizes = compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
+            let grid_style = Style::DEFAULT;
+            let name_resolver = NamedLineResolver::new(&grid_style, explicit_col_count, explicit_row_count);
+            let estimated_sizes =
+                compute_grid_size_estimate(explicit_col_count, explicit_row_count, &name_resolver, child_styles_iter);
             let mut items = Vec::new();
             let mut cell_occupancy_matrix =
                 CellOccupancyMatrix::with_track_counts(estimated_sizes.0, estimated_sizes.1);
 
             // Run placement algorithm
-            place_grid_items(&mut cell_occupancy_matrix, &mut items, children_iter, flow);
+            place_grid_items(&mut cell_occupancy_matrix, &mut items, children_iter, flow, &name_resolver);
 
             // Assert that each item has been placed in the right location
             let mut sorted_children = children.clone();
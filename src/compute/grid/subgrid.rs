@@ -0,0 +1,313 @@
+//! Subgrids: grid containers that lay their children out in the tracks they span in their parent grid
+//! <https://www.w3.org/TR/css-grid-2/#subgrids>
+//!
+//! The parent grid sizes the tracks that a subgrid inherits taking the size contributions of the subgrid's children into
+//! account, and then lays the subgrid out in those tracks. A subgrid uses the gutters of its parent between the tracks
+//! it inherits (rather than its own `gap`), and line names are not inherited.
+use super::types::{GridItem, GridTrack};
+use super::{compute_grid, place_items};
+use crate::axis::{AbsoluteAxis, AbstractAxis, InBothAbsAxis};
+use crate::compute::compute_node_layout_with;
+use crate::geometry::{Line, Rect, Size};
+use crate::layout::{LayoutInput, RunMode};
+use crate::resolve::ResolveOrZero;
+use crate::style::{
+    AvailableSpace, Direction, Display, LengthPercentage, MaxTrackSizingFunction, MinTrackSizingFunction, Style,
+};
+use crate::style_helpers::{TaffyMaxContent, TaffyMinContent};
+use crate::sys::{GridTrackVec, Vec};
+use crate::tree::LayoutTree;
+
+/// The tracks that a subgrid inherits from its parent grid in one axis
+pub(super) struct InheritedTracks {
+    /// The inherited tracks and the gutters between them, bounded by zero-sized gutters at the subgrid's edges. The
+    /// tracks have fixed sizing functions of their size in the parent, and their offsets are relative to the subgrid's
+    /// border box.
+    pub tracks: GridTrackVec<GridTrack>,
+    /// The size of the subgrid's border box in the axis
+    pub border_box_size: f32,
+}
+
+impl InheritedTracks {
+    /// Inherit the tracks of a parent grid that have been sized and positioned
+    ///
+    /// `parent_tracks` are the tracks and gutters between (and including) the lines that the subgrid is placed at, and
+    /// `margin` is the margin of the subgrid in the axis
+    pub fn new(parent_tracks: &[GridTrack], margin: Line<f32>) -> Self {
+        // The grid area starts after the gutter at the subgrid's start line, and ends at the gutter at its end line
+        let last_index = parent_tracks.len() - 1;
+        let grid_area = Line { start: parent_tracks[1].offset, end: parent_tracks[last_index].offset };
+        let origin = grid_area.start + margin.start;
+
+        let tracks = parent_tracks
+            .iter()
+            .enumerate()
+            .map(|(index, parent_track)| {
+                let size = if index == 0 || index == last_index { 0.0 } else { parent_track.base_size };
+                let mut track = parent_track.clone();
+                track.min_track_sizing_function = MinTrackSizingFunction::Fixed(LengthPercentage::Points(size));
+                track.max_track_sizing_function = MaxTrackSizingFunction::Fixed(LengthPercentage::Points(size));
+                track.base_size = size;
+                track.growth_limit = size;
+                track.offset = if index == 0 { grid_area.start } else { parent_track.offset } - origin;
+                track
+            })
+            .collect();
+
+        Self { tracks, border_box_size: grid_area.end - grid_area.start - margin.sum() }
+    }
+
+    /// Inherit the tracks of a parent grid whose tracks have not been positioned yet (because it is still sizing them),
+    /// using the provided estimate of the size of each track
+    pub fn estimate(
+        parent_tracks: &[GridTrack],
+        margin: Line<f32>,
+        track_size_estimate: impl Fn(&GridTrack) -> f32,
+    ) -> Self {
+        let last_index = parent_tracks.len() - 1;
+        let mut offset = 0.0;
+        let tracks: GridTrackVec<GridTrack> = parent_tracks
+            .iter()
+            .enumerate()
+            .map(|(index, parent_track)| {
+                let mut track = parent_track.clone();
+                track.base_size =
+                    if index == 0 || index == last_index { 0.0 } else { track_size_estimate(parent_track) };
+                track.offset = offset;
+                offset += track.base_size;
+                track
+            })
+            .collect();
+        Self::new(&tracks, margin)
+    }
+
+    /// The number of tracks inherited
+    pub fn track_count(&self) -> u16 {
+        (self.tracks.len() / 2) as u16
+    }
+}
+
+/// The margin, border and padding of a subgrid. This is the space between the edges of its grid area and its content
+/// box, by which the items at its edges are inset.
+fn subgrid_edges(style: &Style, direction: Direction, percentage_basis: Option<f32>) -> Rect<f32> {
+    let margin = style.margin.map(|margin| margin.resolve_or_zero(percentage_basis));
+    let border = style.border.resolve_or_zero(percentage_basis);
+    let padding = style.padding.resolve_or_zero(percentage_basis);
+    let edges = Rect {
+        left: margin.left + border.left + padding.left,
+        right: margin.right + border.right + padding.right,
+        top: margin.top + border.top + padding.top,
+        bottom: margin.bottom + border.bottom + padding.bottom,
+    };
+    edges.for_direction(direction)
+}
+
+/// The margin of a subgrid in the specified axis
+fn subgrid_margin(style: &Style, direction: Direction, axis: AbsoluteAxis, percentage_basis: Option<f32>) -> Line<f32> {
+    let margin = style.margin.for_direction(direction).map(|margin| margin.resolve_or_zero(percentage_basis));
+    match axis {
+        AbsoluteAxis::Horizontal => margin.horizontal_components(),
+        AbsoluteAxis::Vertical => margin.vertical_components(),
+    }
+}
+
+/// Add the children of the subgrids among the grid container's items (and the children of any subgrids nested within
+/// them) to the items, so that they contribute to the sizes of the tracks that the subgrids inherit
+///
+/// A subgrid doesn't contribute to the sizes of the tracks that it inherits itself. Its children are placed within the
+/// lines that it is placed at, and only contribute to the sizes of the tracks in the axes in which the subgrid inherits
+/// them. In the other axis they are treated as spanning the whole subgrid. They are marked as subgrid items so that
+/// they can be removed once the tracks have been sized.
+pub(super) fn collect_subgrid_items<Tree: LayoutTree>(
+    tree: &Tree,
+    items: &mut Vec<GridItem<Tree::NodeId>>,
+    percentage_basis: Option<f32>,
+) {
+    // The children of subgrids are appended to the items, so subgrids nested within subgrids are expanded in turn
+    let mut index = 0;
+    while index < items.len() {
+        let subgrid = &mut items[index];
+        index += 1;
+
+        // Only the tracks of the grid container can be inherited, so the children of a subgrid can only be subgrids
+        // in the axes in which their parent subgrid inherits its tracks (the axes in which they contribute to them)
+        let node = subgrid.node;
+        let style = tree.style(node);
+        let is_grid = style.display == Display::Grid;
+        subgrid.inherits_columns =
+            is_grid && subgrid.contributes_to_columns && style.is_subgrid(AbsoluteAxis::Horizontal);
+        subgrid.inherits_rows = is_grid && subgrid.contributes_to_rows && style.is_subgrid(AbsoluteAxis::Vertical);
+        if !subgrid.inherits_columns && !subgrid.inherits_rows {
+            continue;
+        }
+        subgrid.contributes_to_columns &= !subgrid.inherits_columns;
+        subgrid.contributes_to_rows &= !subgrid.inherits_rows;
+
+        let inherits_columns = subgrid.inherits_columns;
+        let inherits_rows = subgrid.inherits_rows;
+        let column_span = subgrid.span(AbstractAxis::Inline);
+        let row_span = subgrid.span(AbstractAxis::Block);
+        let (column, row, margin) = (subgrid.column, subgrid.row, subgrid.subgrid_margin);
+
+        let inherited_track_counts = InBothAbsAxis {
+            horizontal: inherits_columns.then_some(column_span),
+            vertical: inherits_rows.then_some(row_span),
+        };
+        let (direction, visibility) = (subgrid.direction, subgrid.visibility);
+        let subgrid_items = place_items(tree, node, style, inherited_track_counts, direction, visibility).items;
+
+        // The children at the edges of the subgrid are inset by its margin, border and padding, which the tracks at its
+        // edges must make room for. If there are no children at an edge, then the children in the occupied track
+        // closest to it make room for it instead.
+        let edges = subgrid_edges(style, direction, percentage_basis);
+        let first_column = subgrid_items.iter().map(|item| item.column.start).min().unwrap_or(0);
+        let last_column = subgrid_items.iter().map(|item| item.column.end).max().unwrap_or(0);
+        let first_row = subgrid_items.iter().map(|item| item.row.start).min().unwrap_or(0);
+        let last_row = subgrid_items.iter().map(|item| item.row.end).max().unwrap_or(0);
+
+        for mut item in subgrid_items {
+            item.is_subgrid_item = true;
+            if inherits_columns {
+                if item.column.start == first_column {
+                    item.subgrid_margin.left = margin.left + edges.left;
+                }
+                if item.column.end == last_column {
+                    item.subgrid_margin.right = margin.right + edges.right;
+                }
+                item.column = item.column.map(|line| line + column.start);
+            } else {
+                item.column = column;
+                item.contributes_to_columns = false;
+            }
+            if inherits_rows {
+                if item.row.start == first_row {
+                    item.subgrid_margin.top = margin.top + edges.top;
+                }
+                if item.row.end == last_row {
+                    item.subgrid_margin.bottom = margin.bottom + edges.bottom;
+                }
+                item.row = item.row.map(|line| line + row.start);
+            } else {
+                item.row = row;
+                item.contributes_to_rows = false;
+            }
+            items.push(item);
+        }
+    }
+}
+
+/// Compute the min-content and max-content contributions of the subgrids among the items to the sizes of the tracks in
+/// the axis being sized
+///
+/// These depend on the tracks that the subgrids inherit in the other axis, so rather than being measured as independent
+/// grids the subgrids are laid out in those tracks (as estimated at this point of the track sizing algorithm).
+pub(super) fn compute_subgrid_contributions<Tree: LayoutTree>(
+    tree: &mut Tree,
+    axis: AbstractAxis,
+    other_axis_tracks: &[GridTrack],
+    items: &mut [GridItem<Tree::NodeId>],
+    available_space: Size<AvailableSpace>,
+    get_track_size_estimate: impl Fn(&GridTrack, AvailableSpace) -> Option<f32>,
+) {
+    let other_axis_available_space = available_space.get(axis.other());
+    for item in items.iter_mut().filter(|item| item.inherits_tracks(axis.other())) {
+        let node = item.node;
+        let margin = subgrid_margin(
+            tree.style(node),
+            item.direction,
+            axis.other().as_abs_naive(),
+            available_space.width.into_option(),
+        );
+        let indexes = item.placement_indexes(axis.other());
+        let inherited = InheritedTracks::estimate(
+            &other_axis_tracks[indexes.start as usize..=indexes.end as usize],
+            margin,
+            |track| {
+                get_track_size_estimate(track, other_axis_available_space).unwrap_or(track.base_size)
+                    + track.content_alignment_adjustment
+            },
+        );
+        let inherited_tracks = match axis.other() {
+            AbstractAxis::Inline => InBothAbsAxis { horizontal: Some(&inherited), vertical: None },
+            AbstractAxis::Block => InBothAbsAxis { horizontal: None, vertical: Some(&inherited) },
+        };
+
+        let mut min_content_space = Size::MIN_CONTENT;
+        min_content_space.set(axis.other(), AvailableSpace::Definite(inherited.border_box_size));
+        let mut max_content_space = Size::MAX_CONTENT;
+        max_content_space.set(axis.other(), AvailableSpace::Definite(inherited.border_box_size));
+        let compute_size = |tree: &mut Tree, available_space| {
+            compute_grid(tree, node, Size::NONE, available_space, RunMode::ComputeSize, inherited_tracks).size
+        };
+        item.min_content_contribution_cache = Some(compute_size(tree, min_content_space));
+        item.max_content_contribution_cache = Some(compute_size(tree, max_content_space));
+    }
+}
+
+/// Lay a subgrid out in the tracks that it inherits from the grid container, caching the result as its final layout so
+/// that laying it out with the same inputs when it is positioned reuses this layout
+pub(super) fn perform_subgrid_layout<Tree: LayoutTree>(
+    tree: &mut Tree,
+    item: &GridItem<Tree::NodeId>,
+    input: LayoutInput<Tree::NodeId>,
+    direction: Direction,
+    columns: &[GridTrack],
+    rows: &[GridTrack],
+) {
+    let node = item.node;
+    let column_tracks = &columns[item.column_indexes.start as usize..=item.column_indexes.end as usize];
+    let row_tracks = &rows[item.row_indexes.start as usize..=item.row_indexes.end as usize];
+
+    // Note: both horizontal and vertical margins resolve against the width of the grid area
+    let grid_area_width = column_tracks[column_tracks.len() - 1].offset - column_tracks[1].offset;
+    let style = tree.style(node);
+    let margin = |axis| subgrid_margin(style, direction, axis, Some(grid_area_width));
+    let inherited_columns =
+        item.inherits_columns.then(|| InheritedTracks::new(column_tracks, margin(AbsoluteAxis::Horizontal)));
+    let inherited_rows = item.inherits_rows.then(|| InheritedTracks::new(row_tracks, margin(AbsoluteAxis::Vertical)));
+
+    compute_node_layout_with(tree, node, input.known_dimensions, input.available_space, |tree| {
+        let inherited_tracks =
+            InBothAbsAxis { horizontal: inherited_columns.as_ref(), vertical: inherited_rows.as_ref() };
+        compute_grid(tree, node, input.known_dimensions, input.available_space, RunMode::PeformLayout, inherited_tracks)
+    });
+}
+
+/// Inset the items at the edges of a subgrid from the lines that it inherits in the specified axis, by the subgrid's
+/// margin, border and padding. `edges` is the sum of the subgrid's border and padding at each edge.
+pub(super) fn inset_items_at_inherited_edges<NodeId: Copy>(
+    items: &mut [GridItem<NodeId>],
+    axis: AbstractAxis,
+    inherited: &InheritedTracks,
+    edges: Line<f32>,
+) {
+    // The offsets of the inherited lines are relative to the subgrid's border box
+    let last_line_offset = inherited.tracks[inherited.tracks.len() - 1].offset;
+    let inset = Line {
+        start: edges.start - inherited.tracks[0].offset,
+        end: last_line_offset - (inherited.border_box_size - edges.end),
+    };
+    let last_line = inherited.track_count() as i16;
+    for item in items {
+        let placement = item.placement(axis);
+        let (start_margin, end_margin) = match axis {
+            AbstractAxis::Inline => (&mut item.subgrid_margin.left, &mut item.subgrid_margin.right),
+            AbstractAxis::Block => (&mut item.subgrid_margin.top, &mut item.subgrid_margin.bottom),
+        };
+        if placement.start == 0 {
+            *start_margin = inset.start;
+        }
+        if placement.end == last_line {
+            *end_margin = inset.end;
+        }
+    }
+}
+
+/// Clamp the placement of an item of a subgrid into the lines that the subgrid inherits. A subgrid has no implicit
+/// tracks in the axes in which it inherits the tracks of its parent.
+pub(super) fn clamp_placement_to_inherited_lines(placement: Line<i16>, track_count: u16) -> Line<i16> {
+    let last_line = track_count.max(1) as i16;
+    let start = placement.start.clamp(0, last_line - 1);
+    let end = placement.end.clamp(start + 1, last_line);
+    Line { start, end }
+}
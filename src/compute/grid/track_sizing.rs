@@ -1,13 +1,17 @@
 //! Implements the track sizing algorithm
 //! https://www.w3.org/TR/css-grid-1/#layout-algorithm
+use super::subgrid::compute_subgrid_contributions;
 use super::types::{GridItem, GridTrack, TrackCounts};
 use crate::axis::AbstractAxis;
+use crate::compute::compute_node_layout;
 use crate::geometry::Size;
+use crate::layout::{RunMode, SizingMode};
 use crate::math::MaybeMath;
 use crate::prelude::LayoutTree;
 use crate::resolve::{MaybeResolve, ResolveOrZero};
 use crate::style::{AlignContent, AvailableSpace, MaxTrackSizingFunction, MinTrackSizingFunction, Style};
-use crate::sys::{f32_max, f32_min};
+use crate::style_helpers::TaffyMaxContent;
+use crate::sys::{f32_max, f32_min, Vec};
 use core::cmp::Ordering;
 
 /// Takes an axis, and a list of grid items sorted firstly by whether they cross a flex track
@@ -32,7 +36,10 @@ impl ItemBatcher {
 
     /// This is basically a manual version of Iterator::next which passes `items`
     /// in as a parameter on each iteration to work around borrow checker rules
-    fn next<'items>(&mut self, items: &'items mut [GridItem]) -> Option<(&'items mut [GridItem], bool)> {
+    fn next<'items, NodeId: Copy>(
+        &mut self,
+        items: &'items mut [GridItem<NodeId>],
+    ) -> Option<(&'items mut [GridItem<NodeId>], bool)> {
         if self.current_is_flex || self.index_offset >= items.len() {
             return None;
         }
@@ -46,7 +53,7 @@ impl ItemBatcher {
         } else {
             items
                 .iter()
-                .position(|item: &GridItem| {
+                .position(|item: &GridItem<NodeId>| {
                     item.crosses_flexible_track(self.axis) || item.span(self.axis) > self.current_span
                 })
                 .unwrap_or(items.len())
@@ -63,10 +70,10 @@ impl ItemBatcher {
 /// To make track sizing efficient we want to order tracks
 /// Here a placement is either a Line<i16> representing a row-start/row-end or a column-start/column-end
 #[inline(always)]
-pub(super) fn cmp_by_cross_flex_then_span_then_start(
+pub(super) fn cmp_by_cross_flex_then_span_then_start<NodeId: Copy>(
     axis: AbstractAxis,
-) -> impl FnMut(&GridItem, &GridItem) -> Ordering {
-    move |item_a: &GridItem, item_b: &GridItem| -> Ordering {
+) -> impl FnMut(&GridItem<NodeId>, &GridItem<NodeId>) -> Ordering {
+    move |item_a: &GridItem<NodeId>, item_b: &GridItem<NodeId>| -> Ordering {
         match (item_a.crosses_flexible_track(axis), item_b.crosses_flexible_track(axis)) {
             (false, true) => Ordering::Less,
             (true, false) => Ordering::Greater,
@@ -134,7 +141,11 @@ pub(super) fn compute_alignment_gutter_adjustment(
 }
 
 /// Convert origin-zero coordinates track placement in grid track vector indexes
-pub(super) fn resolve_item_track_indexes(items: &mut [GridItem], column_counts: TrackCounts, row_counts: TrackCounts) {
+pub(super) fn resolve_item_track_indexes<NodeId: Copy>(
+    items: &mut [GridItem<NodeId>],
+    column_counts: TrackCounts,
+    row_counts: TrackCounts,
+) {
     for item in items {
         item.column_indexes = item.column.map(|oz_index| column_counts.oz_line_to_grid_track_vec_index(oz_index));
         item.row_indexes = item.row.map(|oz_index| row_counts.oz_line_to_grid_track_vec_index(oz_index));
@@ -142,8 +153,8 @@ pub(super) fn resolve_item_track_indexes(items: &mut [GridItem], column_counts:
 }
 
 /// Determine (in each axis) whether the item crosses any flexible tracks
-pub(super) fn determine_if_item_crosses_flexible_tracks(
-    items: &mut Vec<GridItem>,
+pub(super) fn determine_if_item_crosses_flexible_tracks<NodeId: Copy>(
+    items: &mut Vec<GridItem<NodeId>>,
     columns: &[GridTrack],
     rows: &[GridTrack],
 ) {
@@ -155,6 +166,34 @@ pub(super) fn determine_if_item_crosses_flexible_tracks(
     }
 }
 
+/// Collapse the tracks in the specified axis that are only occupied by collapsed items (those with `visibility: collapse`)
+/// along with the gutters that follow them, in the same way as the empty tracks of `auto-fit` repetitions
+pub(super) fn collapse_tracks_occupied_by_collapsed_items<NodeId: Copy>(
+    axis: AbstractAxis,
+    tracks: &mut [GridTrack],
+    items: &[GridItem<NodeId>],
+) {
+    // Whether each track is occupied by any visible items, and by any collapsed items
+    let mut occupancy: Vec<(bool, bool)> = tracks.iter().map(|_| (false, false)).collect();
+    for item in items.iter().filter(|item| item.contributes_to_tracks(axis)) {
+        let is_collapsed = item.is_collapsed();
+        for (is_occupied_by_visible_item, is_occupied_by_collapsed_item) in
+            &mut occupancy[item.track_range_excluding_lines(axis)]
+        {
+            *is_occupied_by_visible_item |= !is_collapsed;
+            *is_occupied_by_collapsed_item |= is_collapsed;
+        }
+    }
+
+    // Tracks are at the odd indexes of the tracks vector (which starts and ends with a grid line)
+    for index in (1..tracks.len()).step_by(2) {
+        if occupancy[index] == (false, true) {
+            tracks[index].collapse();
+            tracks[index + 1].collapse();
+        }
+    }
+}
+
 /// Track sizing algorithm
 /// Note: Gutters are treated as empty fixed-size tracks for the purpose of the track sizing algorithm.
 #[allow(clippy::too_many_arguments)]
@@ -166,13 +205,32 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutTree>(
     container_style: &Style,
     axis_tracks: &mut [GridTrack],
     other_axis_tracks: &mut [GridTrack],
-    items: &mut [GridItem],
+    items: &mut [GridItem<Tree::NodeId>],
     get_track_size_estimate: impl Fn(&GridTrack, AvailableSpace) -> Option<f32>,
 ) {
     // 11.4 Initialise Track sizes
     // Initialize each track’s base size and growth limit.
     initialize_track_sizes(axis_tracks, available_space.get(axis));
 
+    // Only the items that contribute to the sizes of the tracks in this axis take part in sizing them. The others are
+    // subgrids that inherit the tracks, and the items of subgrids that don't inherit them.
+    items.sort_by_key(|item| !item.contributes_to_tracks(axis));
+    let contributing_item_count = items.iter().take_while(|item| item.contributes_to_tracks(axis)).count();
+    let items = &mut items[..contributing_item_count];
+
+    // Resolve the baseline shims of baseline-aligned items. This must happen even if the rows are all fixed size
+    // (and the rest of this function is skipped) as the shims are also used to align the items.
+    if axis == AbstractAxis::Block {
+        resolve_item_baselines(
+            tree,
+            items,
+            container_style,
+            other_axis_tracks,
+            available_space,
+            &get_track_size_estimate,
+        );
+    }
+
     // If all tracks have base_size = growth_limit, then skip the rest of this function.
     // Note: this can only happen both track sizing function have the same fixed track sizing function
     if axis_tracks.iter().all(|track| track.base_size == track.growth_limit) {
@@ -183,6 +241,9 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutTree>(
     // These caches are only valid for a single run of the track sizing algorithm, so ensure that they are clear.
     items.iter_mut().for_each(|item| item.clear_contribution_caches());
 
+    // The contributions of subgrids depend on the sizes of the tracks that they inherit in the other axis
+    compute_subgrid_contributions(tree, axis, other_axis_tracks, items, available_space, &get_track_size_estimate);
+
     // Pre-computations for 11.5 Resolve Intrinsic Track Sizes
 
     // The track sizing algorithm requires us to iterate through the items in ascendeding order of the number of
@@ -232,6 +293,70 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutTree>(
     stretch_auto_tracks(axis, axis_tracks, container_style, available_space, available_grid_space);
 }
 
+/// Compute the baseline shim of each baseline-aligned item (items with an `align-self` of `baseline`).
+///
+/// Items which start in the same row form a baseline-sharing group. Each item in a group is shimmed so
+/// that its baseline lines up with the baseline of the item in the group whose baseline is furthest from
+/// the start of the row. Groups containing a single baseline-aligned item do not need shimming.
+///
+/// Note: baseline alignment of columns (`justify-self: baseline`) falls back to start alignment as taffy
+/// does not support vertical writing modes.
+fn resolve_item_baselines<Tree: LayoutTree>(
+    tree: &mut Tree,
+    items: &mut [GridItem<Tree::NodeId>],
+    container_style: &Style,
+    column_tracks: &[GridTrack],
+    available_space: Size<AvailableSpace>,
+    get_track_size_estimate: impl Fn(&GridTrack, AvailableSpace) -> Option<f32>,
+) {
+    items.iter_mut().for_each(|item| item.baseline_shim = 0.0);
+
+    // Sort the baseline-aligned items' indexes (rather than the items themselves, whose order belongs to the caller)
+    // by the items' row-start line so that the items in the same baseline-sharing group are adjacent
+    let mut baseline_items: Vec<(i16, usize)> = items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.is_baseline_aligned(tree, container_style.align_items))
+        .map(|(index, item)| (item.row.start, index))
+        .collect();
+    baseline_items.sort_by_key(|&(row_start, _)| row_start);
+
+    for group in baseline_items.chunk_by(|(a, _), (b, _)| a == b) {
+        if group.len() < 2 {
+            continue;
+        }
+
+        // Compute the baseline of each item (measured from the top of its margin box), temporarily storing it in the
+        // item's baseline_shim field
+        for &(_, index) in group {
+            let item = &mut items[index];
+            let item_width = column_tracks[item.track_range_excluding_lines(AbstractAxis::Inline)]
+                .iter()
+                .map(|track| {
+                    get_track_size_estimate(track, available_space.width)
+                        .map(|size| size + track.content_alignment_adjustment)
+                })
+                .sum::<Option<f32>>();
+            let measured_size_and_baseline = compute_node_layout(
+                tree,
+                item.node,
+                Size { width: item_width, height: None },
+                Size::MAX_CONTENT,
+                RunMode::ComputeSize,
+                SizingMode::InherentSize,
+            );
+            let margin_top = item.margin.top.resolve_or_zero(available_space.width.into_option());
+            item.baseline_shim = margin_top + measured_size_and_baseline.first_baseline_or_height();
+        }
+
+        // Convert the baselines into shims
+        let max_baseline = group.iter().map(|&(_, index)| items[index].baseline_shim).fold(0.0, f32_max);
+        for &(_, index) in group {
+            items[index].baseline_shim = max_baseline - items[index].baseline_shim;
+        }
+    }
+}
+
 /// Whether it is a minimum or maximum size's space being distributed
 /// This controls behaviour of the space distribution algorithm when distributing beyond limits
 /// See "distributing space beyond limits" at https://www.w3.org/TR/css-grid-1/#extra-space
@@ -309,17 +434,18 @@ fn initialize_track_sizes(axis_tracks: &mut [GridTrack], axis_available_space: A
 }
 
 /// 11.5 Resolve Intrinsic Track Sizes
-fn resolve_intrinsic_track_sizes(
-    tree: &mut impl LayoutTree,
+fn resolve_intrinsic_track_sizes<Tree: LayoutTree>(
+    tree: &mut Tree,
     axis: AbstractAxis,
     axis_tracks: &mut [GridTrack],
     other_axis_tracks: &mut [GridTrack],
-    items: &mut [GridItem],
+    items: &mut [GridItem<Tree::NodeId>],
     available_space: Size<AvailableSpace>,
     get_track_size_estimate: impl Fn(&GridTrack, AvailableSpace) -> Option<f32>,
 ) {
     // Step 1. Shim baseline-aligned items so their intrinsic size contributions reflect their baseline alignment.
-    // TODO: we do not yet support baseline alignment for CSS Grid
+    // The shims themselves are computed by `resolve_item_baselines`. Here we simply add them to the item's contributions
+    // (see `compute_item_sizes` below).
 
     // Step 2. We skip Step 2 as it is noted that:
     //
@@ -339,7 +465,7 @@ fn resolve_intrinsic_track_sizes(
     // to the min-content contribution—but can differ in some cases, see §6.6 Automatic Minimum Size of Grid Items.
     // Also, minimum contribution <= min-content contribution <= max-content contribution.
     // TODO: be smarter about only computing these when they are required
-    let mut compute_item_sizes = |item: &mut GridItem, axis_tracks: &[GridTrack]| {
+    let mut compute_item_sizes = |item: &mut GridItem<Tree::NodeId>, axis_tracks: &[GridTrack]| {
         let known_dimensions = item.known_dimensions_cached(
             axis,
             other_axis_tracks,
@@ -349,13 +475,16 @@ fn resolve_intrinsic_track_sizes(
 
         let margin = item.margin.map(|m| m.resolve_or_zero(available_space.width.into_option())).sum_axes();
 
-        let min_content_size = item.min_content_contribution_cached(tree, known_dimensions) + margin;
-        let max_content_size = item.max_content_contribution_cached(tree, known_dimensions) + margin;
+        // Baseline shims act as additional margin on the start side of the item
+        let baseline_shim = if axis == AbstractAxis::Block { item.baseline_shim } else { 0.0 };
+
+        let min_content_size = item.min_content_contribution_cached(tree, known_dimensions).get(axis);
+        let max_content_size = item.max_content_contribution_cached(tree, known_dimensions).get(axis);
         let axis_minimum_size =
-            item.minimum_contribution_cached(tree, axis, axis_tracks, available_space, known_dimensions)
-                + margin.get(axis);
+            item.minimum_contribution_cached(tree, axis, axis_tracks, available_space, known_dimensions);
 
-        (axis_minimum_size, min_content_size.get(axis), max_content_size.get(axis))
+        let axis_margin = margin.get(axis) + item.subgrid_margin.sum_axes().get(axis) + baseline_shim;
+        (axis_minimum_size + axis_margin, min_content_size + axis_margin, max_content_size + axis_margin)
     };
 
     let axis_available_space = available_space.get(axis);
@@ -668,11 +797,11 @@ fn maximise_tracks(axis: AbstractAxis, axis_tracks: &mut [GridTrack], available_
 
 /// 11.7. Expand Flexible Tracks
 /// This step sizes flexible tracks using the largest value it can assign to an fr without exceeding the available space.
-fn expand_flexible_tracks(
-    tree: &mut impl LayoutTree,
+fn expand_flexible_tracks<Tree: LayoutTree>(
+    tree: &mut Tree,
     axis: AbstractAxis,
     axis_tracks: &mut [GridTrack],
-    items: &mut [GridItem],
+    items: &mut [GridItem<Tree::NodeId>],
     axis_min_size: Option<f32>,
     axis_max_size: Option<f32>,
     available_grid_space: Size<AvailableSpace>,
@@ -4,19 +4,21 @@ use crate::axis::AbstractAxis;
 use crate::compute::compute_node_layout;
 use crate::geometry::{Line, Rect, Size};
 use crate::layout::{RunMode, SizingMode};
-use crate::node::Node;
 use crate::prelude::LayoutTree;
 use crate::resolve::MaybeResolve;
-use crate::style::{AvailableSpace, LengthPercentageAuto, MaxTrackSizingFunction, MinTrackSizingFunction, Style};
+use crate::style::{
+    AlignItems, AlignSelf, AvailableSpace, Direction, LengthPercentageAuto, MaxTrackSizingFunction,
+    MinTrackSizingFunction, Style, Visibility,
+};
 use crate::style_helpers::*;
 use core::cmp::max;
 use core::ops::Range;
 
 /// Represents a single grid item
 #[derive(Debug)]
-pub(in super::super) struct GridItem {
-    /// The id of the Node that this item represents
-    pub node: Node,
+pub(in super::super) struct GridItem<NodeId> {
+    /// The id of the node that this item represents
+    pub node: NodeId,
 
     /// The order of the item in the children array
     ///
@@ -33,6 +35,10 @@ pub(in super::super) struct GridItem {
 
     /// The item's margin style
     pub margin: Rect<LengthPercentageAuto>,
+    /// The item's resolved direction (inherited from the grid container if the item doesn't set one)
+    pub direction: Direction,
+    /// The item's resolved visibility (inherited from the grid container if the item doesn't set one)
+    pub visibility: Visibility,
 
     /// The item's definite row-start and row-end (same as `row` field, except in a different coordinate system)
     /// (as indexes into the Vec<GridTrack> stored in a grid's AbstractAxisTracks)
@@ -46,6 +52,29 @@ pub(in super::super) struct GridItem {
     /// Whether the item crosses a flexible column
     pub crosses_flexible_column: bool,
 
+    /// The extra space added above the item so that its baseline lines up with the other items in its
+    /// baseline-sharing group. Zero for items that are not baseline-aligned.
+    pub baseline_shim: f32,
+
+    /// Whether the item is a child of a subgrid (rather than of the grid container itself). Such items only take part
+    /// in sizing the tracks that their subgrid inherits from the grid container.
+    pub is_subgrid_item: bool,
+    /// Whether the item is a subgrid that inherits the grid container's rows
+    pub inherits_rows: bool,
+    /// Whether the item is a subgrid that inherits the grid container's columns
+    pub inherits_columns: bool,
+    /// Whether the item's size contributes to the sizes of the rows. False for subgrids that inherit the rows, and for
+    /// subgrid items in the axes in which their subgrid does not inherit the grid container's tracks.
+    pub contributes_to_rows: bool,
+    /// Whether the item's size contributes to the sizes of the columns. See `contributes_to_rows`.
+    pub contributes_to_columns: bool,
+    /// The space between the edges of the item's grid area and the content boxes of any subgrids that the item is
+    /// placed at the edges of (the margin, border and padding of those subgrids). Zero if the item is not in a subgrid.
+    pub subgrid_margin: Rect<f32>,
+    /// The start and end of the item's margin box in the masonry axis of a masonry container, relative to the start of
+    /// the single track that holds all of the items in that axis. Zero for items of other grid containers.
+    pub masonry_extent: Line<f32>,
+
     // Caches for intrinsic size computation. These caches are only valid for a single run of the track-sizing algorithm.
     /// Cache for the known_dimensions input to intrinsic sizing computation
     pub known_dimensions_cache: Option<Size<Option<f32>>>,
@@ -57,10 +86,10 @@ pub(in super::super) struct GridItem {
     pub max_content_contribution_cache: Option<Size<f32>>,
 }
 
-impl GridItem {
+impl<NodeId: Copy> GridItem<NodeId> {
     /// Create a new item given a concrete placement in both axes
     pub fn new_with_placement_style_and_order(
-        node: Node,
+        node: NodeId,
         col_span: Line<i16>,
         row_span: Line<i16>,
         style: &Style,
@@ -72,10 +101,20 @@ impl GridItem {
             row: row_span,
             column: col_span,
             margin: style.margin,
-            row_indexes: Line { start: 0, end: 0 }, // Properly initialised later
+            direction: Direction::Ltr,                 // Properly initialised later
+            visibility: Visibility::Visible,           // Properly initialised later
+            row_indexes: Line { start: 0, end: 0 },    // Properly initialised later
             column_indexes: Line { start: 0, end: 0 }, // Properly initialised later
-            crosses_flexible_row: false,            // Properly initialised later
-            crosses_flexible_column: false,         // Properly initialised later
+            crosses_flexible_row: false,               // Properly initialised later
+            crosses_flexible_column: false,            // Properly initialised later
+            baseline_shim: 0.0,                        // Properly initialised later
+            is_subgrid_item: false,
+            inherits_rows: false,
+            inherits_columns: false,
+            contributes_to_rows: true,
+            contributes_to_columns: true,
+            subgrid_margin: Rect::ZERO,
+            masonry_extent: Line { start: 0.0, end: 0.0 },
             known_dimensions_cache: None,
             min_content_contribution_cache: None,
             max_content_contribution_cache: None,
@@ -83,6 +122,21 @@ impl GridItem {
         }
     }
 
+    /// Whether the item is baseline-aligned in the block axis (has an `align-self` of `baseline`, either directly or
+    /// through the container's `align-items`)
+    pub fn is_baseline_aligned(
+        &self,
+        tree: &impl LayoutTree<NodeId = NodeId>,
+        container_align_items: Option<AlignItems>,
+    ) -> bool {
+        tree.style(self.node).align_self.or(container_align_items) == Some(AlignSelf::Baseline)
+    }
+
+    /// Whether the item has `visibility: collapse` (either directly or by inheriting it)
+    pub fn is_collapsed(&self) -> bool {
+        self.visibility == Visibility::Collapse
+    }
+
     /// This item's placement in the specified axis in OriginZero coordinates
     pub fn placement(&self, axis: AbstractAxis) -> Line<i16> {
         match axis {
@@ -115,6 +169,22 @@ impl GridItem {
         }
     }
 
+    /// Whether the item is a subgrid that inherits the grid container's tracks in the specified axis
+    pub fn inherits_tracks(&self, axis: AbstractAxis) -> bool {
+        match axis {
+            AbstractAxis::Inline => self.inherits_columns,
+            AbstractAxis::Block => self.inherits_rows,
+        }
+    }
+
+    /// Whether the item's size contributes to the sizes of the tracks in the specified axis
+    pub fn contributes_to_tracks(&self, axis: AbstractAxis) -> bool {
+        match axis {
+            AbstractAxis::Inline => self.contributes_to_columns,
+            AbstractAxis::Block => self.contributes_to_rows,
+        }
+    }
+
     /// Returns the pre-computed value indicating whether the grid item crosses a flexible track in
     /// the specified axis
     pub fn crosses_flexible_track(&self, axis: AbstractAxis) -> bool {
@@ -144,6 +214,7 @@ impl GridItem {
                             .map(|size| size + track.content_alignment_adjustment)
                     })
                     .sum::<Option<f32>>()
+                    .map(|size| size - self.subgrid_margin.sum_axes().get(axis.other()))
             };
             let known_dimensions = {
                 let mut size = Size::NONE;
@@ -183,7 +254,7 @@ impl GridItem {
     /// Retrieve the item's min content contribution from the cache or compute it using the provided parameters
     pub fn min_content_contribution_cached(
         &mut self,
-        tree: &mut impl LayoutTree,
+        tree: &mut impl LayoutTree<NodeId = NodeId>,
         known_dimensions: Size<Option<f32>>,
     ) -> Size<f32> {
         self.min_content_contribution_cache.unwrap_or_else(|| {
@@ -194,7 +265,8 @@ impl GridItem {
                 Size::MIN_CONTENT,
                 RunMode::ComputeSize,
                 SizingMode::InherentSize,
-            );
+            )
+            .size;
             self.min_content_contribution_cache = Some(size);
             size
         })
@@ -203,7 +275,7 @@ impl GridItem {
     /// Retrieve the item's max content contribution from the cache or compute it using the provided parameters
     pub fn max_content_contribution_cached(
         &mut self,
-        tree: &mut impl LayoutTree,
+        tree: &mut impl LayoutTree<NodeId = NodeId>,
         known_dimensions: Size<Option<f32>>,
     ) -> Size<f32> {
         self.max_content_contribution_cache.unwrap_or_else(|| {
@@ -214,7 +286,8 @@ impl GridItem {
                 Size::MAX_CONTENT,
                 RunMode::ComputeSize,
                 SizingMode::InherentSize,
-            );
+            )
+            .size;
             self.max_content_contribution_cache = Some(size);
             size
         })
@@ -228,7 +301,7 @@ impl GridItem {
     /// Because the minimum contribution often depends on the size of the item’s content, it is considered a type of intrinsic size contribution.
     pub fn minimum_contribution_cached(
         &mut self,
-        tree: &mut impl LayoutTree,
+        tree: &mut impl LayoutTree<NodeId = NodeId>,
         axis: AbstractAxis,
         axis_tracks: &[GridTrack],
         available_space: Size<AvailableSpace>,
@@ -237,6 +310,7 @@ impl GridItem {
         self.minimum_contribution_cache.unwrap_or_else(|| {
             let style = tree.style(self.node);
             let axis_available_space = available_space.get(axis).into_option();
+            let overflow = style.overflow.get(axis);
             style
                 .size
                 .get(axis)
@@ -250,7 +324,7 @@ impl GridItem {
                     let item_axis_tracks = &axis_tracks[self.track_range_excluding_lines(axis)];
 
                     // it is not a scroll container
-                    // TODO: support overflow propety
+                    let is_scroll_container = overflow.is_scroll_container();
 
                     // it spans at least one track in that axis whose min track sizing function is auto
                     let spans_auto_min_track = axis_tracks
@@ -264,8 +338,9 @@ impl GridItem {
                         .iter()
                         .any(|track| matches!(track.max_track_sizing_function, MaxTrackSizingFunction::Flex(_)));
 
-                    let use_content_based_minimum =
-                        spans_auto_min_track && (only_span_one_track || !spans_a_flexible_track);
+                    let use_content_based_minimum = !is_scroll_container
+                        && spans_auto_min_track
+                        && (only_span_one_track || !spans_a_flexible_track);
 
                     // Otherwise, the automatic minimum size is zero, as usual.
                     if use_content_based_minimum {
@@ -118,6 +118,9 @@ impl GridTrack {
             MaxTrackSizingFunction::FitContent(LengthPercentage::Points(limit)) => limit,
             // TODO: properly support percentage fit-content values
             MaxTrackSizingFunction::FitContent(LengthPercentage::Percent(_)) => f32::INFINITY,
+            MaxTrackSizingFunction::FitContent(LengthPercentage::Calc(calc)) => {
+                calc.maybe_resolve(None).unwrap_or(f32::INFINITY)
+            }
             _ => f32::INFINITY,
         }
     }
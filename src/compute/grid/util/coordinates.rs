@@ -24,13 +24,12 @@ pub(crate) fn css_grid_line_into_origin_zero_coords(grid_line: i16, explicit_tra
     }
 }
 
-#[allow(dead_code)]
 /// Convert from OriginZero coordinates to CSS Grid Line coordinates
 pub(crate) fn origin_zero_coords_into_css_grid_line(origin_zero_line: i16, explicit_track_count: u16) -> i16 {
     let explicit_line_count = explicit_track_count + 1;
     if origin_zero_line >= 0 {
         origin_zero_line + 1
     } else {
-        -(origin_zero_line + explicit_line_count as i16)
+        origin_zero_line - explicit_line_count as i16
     }
 }
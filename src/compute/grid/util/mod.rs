@@ -2,7 +2,6 @@
 pub(super) mod coordinates;
 
 pub(super) use coordinates::css_grid_line_into_origin_zero_coords;
-#[allow(unused_imports)]
 pub(super) use coordinates::origin_zero_coords_into_css_grid_line;
 
 #[cfg(test)]
@@ -1,9 +1,8 @@
 //! Computes size using styles and measure functions
 
 use crate::geometry::Size;
-use crate::layout::{RunMode, SizingMode};
+use crate::layout::{RunMode, SizeAndBaseline, SizingMode};
 use crate::math::MaybeMath;
-use crate::node::Node;
 use crate::resolve::{MaybeResolve, ResolveOrZero};
 use crate::style::AvailableSpace;
 use crate::tree::LayoutTree;
@@ -12,14 +11,14 @@ use crate::tree::LayoutTree;
 use crate::debug::NODE_LOGGER;
 
 /// Compute the size of a leaf node (node with no children)
-pub(crate) fn compute(
-    tree: &mut impl LayoutTree,
-    node: Node,
+pub fn compute<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
     known_dimensions: Size<Option<f32>>,
     available_space: Size<AvailableSpace>,
     _run_mode: RunMode,
     sizing_mode: SizingMode,
-) -> Size<f32> {
+) -> SizeAndBaseline {
     let style = tree.style(node);
 
     // Resolve node's preferred/min/max sizes (width/heights) against the available space (percentages resolve to pixel values)
@@ -49,9 +48,14 @@ pub(crate) fn compute(
     #[cfg(feature = "debug")]
     NODE_LOGGER.labelled_debug_log("max_size ", node_max_size);
 
-    // Return early if both width and height are known
+    // Return early if both width and height are known, unless the node's measure function can report a baseline
+    // Note: leaf nodes without a measure function have no content, so their content size is zero
     if let Size { width: Some(width), height: Some(height) } = node_size {
-        return Size { width, height }.maybe_clamp(node_min_size, node_max_size);
+        let needs_baseline = tree.needs_measure(node) && tree.can_measure_baseline(node);
+        if !needs_baseline {
+            let size = Size { width, height }.maybe_clamp(node_min_size, node_max_size);
+            return SizeAndBaseline { size, first_baseline: None, content_size: Size::ZERO };
+        }
     };
 
     if tree.needs_measure(node) {
@@ -70,26 +74,37 @@ pub(crate) fn compute(
         };
 
         // Measure node
-        let measured_size = tree.measure_node(node, known_dimensions, available_space);
+        let measured = tree.measure_node(node, known_dimensions, available_space);
 
-        return node_size.unwrap_or(measured_size).maybe_clamp(node_min_size, node_max_size);
+        return SizeAndBaseline {
+            size: node_size.unwrap_or(measured.size).maybe_clamp(node_min_size, node_max_size),
+            first_baseline: measured.first_baseline,
+            content_size: measured.content_size,
+        };
     }
 
     // Note: both horizontal and vertical percentage padding/borders are resolved against the container's inline size (i.e. width).
     // This is not a bug, but is how CSS is specified (see: https://developer.mozilla.org/en-US/docs/Web/CSS/padding#values)
     let padding = style.padding.resolve_or_zero(available_space.width.into_option());
     let border = style.border.resolve_or_zero(available_space.width.into_option());
+    // Space reserved for scrollbars is treated as additional padding
+    let scrollbar_gutter = style.scrollbar_gutter();
+    let padding_border_size = Size {
+        width: padding.horizontal_axis_sum() + border.horizontal_axis_sum() + scrollbar_gutter.width,
+        height: padding.vertical_axis_sum() + border.vertical_axis_sum() + scrollbar_gutter.height,
+    };
 
-    Size {
+    let size = Size {
         width: node_size
             .width
-            // .unwrap_or(0.0) + padding.horizontal_axis_sum() + border.horizontal_axis_sum(), // content-box
-            .unwrap_or(0.0 + padding.horizontal_axis_sum() + border.horizontal_axis_sum()) // border-box
+            // .unwrap_or(0.0) + padding_border_size.width, // content-box
+            .unwrap_or(0.0 + padding_border_size.width) // border-box
             .maybe_clamp(node_min_size.width, node_max_size.width),
         height: node_size
             .height
-            // .unwrap_or(0.0) + padding.vertical_axis_sum() + border.vertical_axis_sum(), // content-box
-            .unwrap_or(0.0 + padding.vertical_axis_sum() + border.vertical_axis_sum()) // border-box
+            // .unwrap_or(0.0) + padding_border_size.height, // content-box
+            .unwrap_or(0.0 + padding_border_size.height) // border-box
             .maybe_clamp(node_min_size.height, node_max_size.height),
-    }
+    };
+    SizeAndBaseline { size, first_baseline: None, content_size: Size::ZERO }
 }
@@ -1,5 +1,7 @@
 //! The layout algorithms themselves
 
+#[cfg(feature = "block_layout")]
+pub(crate) mod block;
 pub(crate) mod common;
 pub(crate) mod flexbox;
 pub(crate) mod leaf;
@@ -7,58 +9,91 @@ pub(crate) mod leaf;
 #[cfg(feature = "grid")]
 pub(crate) mod grid;
 
-use crate::data::CACHE_SIZE;
+use crate::compute::common::content_size::compute_content_contribution;
+use crate::compute::common::order::compute_painting_order;
+use crate::compute::common::relative_position::compute_relative_offset;
 use crate::error::TaffyError;
-use crate::geometry::{Point, Size};
-use crate::layout::{Cache, Layout, RunMode, SizingMode};
-use crate::node::Node;
-use crate::style::{AvailableSpace, Display};
-use crate::sys::round;
+use crate::geometry::{Point, Rect, Size};
+use crate::layout::{Cache, Layout, RunMode, SizeAndBaseline, SizingMode, CACHE_SIZE};
+use crate::math::MaybeMath;
+use crate::resolve::{MaybeResolve, ResolveOrZero};
+use crate::style::{AvailableSpace, Direction, Display, Position, Visibility};
+use crate::sys::{round, Vec};
 use crate::tree::LayoutTree;
 
 #[cfg(feature = "debug")]
 use crate::debug::NODE_LOGGER;
 
 /// Updates the stored layout of the provided `node` and its children
-pub fn compute_layout(
-    tree: &mut impl LayoutTree,
-    root: Node,
+pub fn compute_layout<Tree: LayoutTree>(
+    tree: &mut Tree,
+    root: Tree::NodeId,
     available_space: Size<AvailableSpace>,
 ) -> Result<(), TaffyError> {
     // Recursively compute node layout
-    let size =
+    let SizeAndBaseline { size, first_baseline, mut content_size } =
         compute_node_layout(tree, root, Size::NONE, available_space, RunMode::PeformLayout, SizingMode::InherentSize);
 
-    let layout = Layout { order: 0, size, location: Point::ZERO };
+    // Note: percentage padding, border and margin resolve against the width of the available space (if it is definite)
+    let style = tree.style(root);
+    let parent_width = available_space.width.into_option();
+    let padding = style.padding.resolve_or_zero(parent_width);
+    let border = style.border.resolve_or_zero(parent_width);
+    let margin = style.margin.resolve_or_zero(parent_width);
+
+    // The root node is the containing block of fixed position descendants, and of absolutely positioned descendants
+    // that have no positioned ancestor
+    let mut hoisted = Vec::new();
+    if !style.position.is_positioned() {
+        collect_hoisted_descendants(tree, root, true, &mut hoisted);
+    }
+    tree.fixed_descendants(root, &mut hoisted);
+    if !hoisted.is_empty() {
+        let hoisted_content_size = perform_hoisted_layout(tree, root, &hoisted, size, border, padding);
+        content_size.width = content_size.width.max(hoisted_content_size.width);
+        content_size.height = content_size.height.max(hoisted_content_size.height);
+    }
+
+    // A relatively positioned root node is offset relative to the available space (which acts as its containing block)
+    let location = compute_relative_offset(tree.style(root), tree.direction(root), available_space.into_options());
+
+    let visibility = tree.visibility(root);
+    let layout = Layout { order: 0, size, location, padding, border, margin, content_size, first_baseline, visibility };
     *tree.layout_mut(root) = layout;
 
-    // Recursively round the layout's of this node and all children
-    round_layout(tree, root, 0.0, 0.0);
+    // Recursively round the layouts of this node and all children that have been laid out
+    round_layout(tree, root, Point::ZERO);
 
     Ok(())
 }
 
-/// Updates the stored layout of the provided `node` and its children
-fn compute_node_layout(
-    tree: &mut impl LayoutTree,
-    node: Node,
+/// Computes the size of the provided `node` (and, if `run_mode` is [`RunMode::PeformLayout`], updates the stored layout
+/// of its children), dispatching to the layout algorithm selected by its [`Display`] style.
+///
+/// Results are cached, so this is the function that custom layout algorithms should use to measure and lay out their
+/// children. Note that this doesn't set the layout of `node` itself: that is the responsibility of its parent.
+pub fn compute_node_layout<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
     known_dimensions: Size<Option<f32>>,
     available_space: Size<AvailableSpace>,
     run_mode: RunMode,
     sizing_mode: SizingMode,
-) -> Size<f32> {
+) -> SizeAndBaseline {
     #[cfg(feature = "debug")]
     NODE_LOGGER.push_node(node);
     #[cfg(feature = "debug")]
     println!();
 
     // First we check if we have a cached result for the given input
-    let cache_run_mode = if tree.is_childless(node) { RunMode::PeformLayout } else { run_mode };
-    if let Some(cached_size) =
-        compute_from_cache(tree, node, known_dimensions, available_space, cache_run_mode, sizing_mode)
+    // Note: performing layout of a container can only be skipped if its children were laid out with the same inputs,
+    // which is only known to be the case if the inputs match those of its cached final layout
+    let uses_final_layout_cache = run_mode == RunMode::PeformLayout && !tree.is_childless(node);
+    if let Some(cached_size_and_baseline) =
+        compute_from_cache(tree, node, known_dimensions, available_space, uses_final_layout_cache, sizing_mode)
     {
         #[cfg(feature = "debug")]
-        NODE_LOGGER.labelled_debug_log("CACHE", cached_size);
+        NODE_LOGGER.labelled_debug_log("CACHE", cached_size_and_baseline.size);
         #[cfg(feature = "debug")]
         NODE_LOGGER.labelled_debug_log("run_mode", run_mode);
         #[cfg(feature = "debug")]
@@ -69,7 +104,7 @@ fn compute_node_layout(
         NODE_LOGGER.labelled_debug_log("available_space", available_space);
         #[cfg(feature = "debug")]
         NODE_LOGGER.pop_node();
-        return cached_size;
+        return cached_size_and_baseline;
     }
 
     #[cfg(feature = "debug")]
@@ -108,20 +143,31 @@ fn compute_node_layout(
     // }
 
     // If this is a leaf node we can skip a lot of this function in some cases
-    let computed_size = if tree.is_childless(node) {
+    let computed_size_and_baseline = if tree.is_childless(node) {
         #[cfg(feature = "debug")]
         NODE_LOGGER.log("Algo: leaf");
         self::leaf::compute(tree, node, known_dimensions, available_space, run_mode, sizing_mode)
     } else {
         // println!("match {:?}", tree.style(node).display);
         match tree.style(node).display {
+            #[cfg(feature = "block_layout")]
+            Display::Block => {
+                #[cfg(feature = "debug")]
+                NODE_LOGGER.log("Algo: block");
+                self::block::compute(tree, node, known_dimensions, available_space, run_mode)
+            }
             Display::Flex => {
                 #[cfg(feature = "debug")]
                 NODE_LOGGER.log("Algo: flexbox");
                 self::flexbox::compute(tree, node, known_dimensions, available_space, run_mode)
             }
             #[cfg(feature = "grid")]
-            Display::Grid => self::grid::compute(tree, node, available_space),
+            Display::Grid => self::grid::compute(tree, node, known_dimensions, available_space, run_mode),
+            Display::Custom => {
+                #[cfg(feature = "debug")]
+                NODE_LOGGER.log("Algo: custom");
+                tree.compute_custom_layout(node, known_dimensions, available_space, run_mode, sizing_mode)
+            }
             Display::None => {
                 #[cfg(feature = "debug")]
                 NODE_LOGGER.log("Algo: none");
@@ -130,19 +176,91 @@ fn compute_node_layout(
         }
     };
 
-    // Cache result
-    let cache_slot = compute_cache_slot(known_dimensions, available_space);
-    *tree.cache_mut(node, cache_slot) =
-        Some(Cache { known_dimensions, available_space, run_mode: cache_run_mode, cached_size: computed_size });
+    let computed_size_and_baseline =
+        finish_node_layout(tree, node, known_dimensions, available_space, run_mode, computed_size_and_baseline);
 
     #[cfg(feature = "debug")]
-    NODE_LOGGER.labelled_debug_log("RESULT", computed_size);
+    NODE_LOGGER.labelled_debug_log("RESULT", computed_size_and_baseline.size);
     #[cfg(feature = "debug")]
     NODE_LOGGER.pop_node();
 
-    computed_size
+    computed_size_and_baseline
+}
+
+/// Lays out `node` using the provided layout algorithm rather than the one selected by its [`Display`] style, and caches
+/// the result as its final layout so that performing its layout with the same inputs later reuses it.
+///
+/// This is used by layout algorithms whose children depend on more than their inputs (such as subgrids, which depend on
+/// the tracks of their parent grid).
+#[cfg(feature = "grid")]
+pub(crate) fn compute_node_layout_with<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    algorithm: impl FnOnce(&mut Tree) -> SizeAndBaseline,
+) -> SizeAndBaseline {
+    let computed_size_and_baseline = algorithm(tree);
+    finish_node_layout(tree, node, known_dimensions, available_space, RunMode::PeformLayout, computed_size_and_baseline)
+}
+
+/// Lays out the absolutely positioned descendants that `node` is the containing block of (if its layout was performed),
+/// and caches the result of computing its layout
+fn finish_node_layout<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+    mut computed_size_and_baseline: SizeAndBaseline,
+) -> SizeAndBaseline {
+    // Absolutely positioned descendants that are not laid out by their parent (because their parent is static) are laid
+    // out by their containing block once it has been sized
+    if run_mode == RunMode::PeformLayout && !tree.is_childless(node) {
+        let style = tree.style(node);
+        if style.position.is_positioned() && style.display != Display::None {
+            let parent_width = available_space.width.into_option();
+            let padding = style.padding.resolve_or_zero(parent_width);
+            let border = style.border.resolve_or_zero(parent_width);
+
+            let mut hoisted = Vec::new();
+            collect_hoisted_descendants(tree, node, false, &mut hoisted);
+            if !hoisted.is_empty() {
+                let size = computed_size_and_baseline.size;
+                let hoisted_content_size = perform_hoisted_layout(tree, node, &hoisted, size, border, padding);
+                let content_size = &mut computed_size_and_baseline.content_size;
+                content_size.width = content_size.width.max(hoisted_content_size.width);
+                content_size.height = content_size.height.max(hoisted_content_size.height);
+            }
+        }
+    }
+
+    // Cache result
+    let cache_entry = Cache {
+        known_dimensions,
+        available_space,
+        rounded_position: None,
+        cached_size: computed_size_and_baseline.size,
+        cached_baseline: computed_size_and_baseline.first_baseline,
+        cached_content_size: computed_size_and_baseline.content_size,
+        #[cfg(feature = "block_layout")]
+        cached_collapsible_margins: None,
+    };
+    let cache_slot = compute_cache_slot(known_dimensions, available_space);
+    *tree.cache_mut(node, cache_slot) = Some(cache_entry);
+    if run_mode == RunMode::PeformLayout && !tree.is_childless(node) {
+        *tree.cache_mut(node, FINAL_LAYOUT_CACHE_SLOT) = Some(Cache { rounded_position: None, ..cache_entry });
+    }
+
+    computed_size_and_baseline
 }
 
+/// The cache slot used to cache the collapsible margins of a node that is an in-flow child of a block container
+pub(crate) const COLLAPSIBLE_MARGINS_CACHE_SLOT: usize = CACHE_SIZE - 2;
+
+/// The cache slot used to cache the final layout of a node
+const FINAL_LAYOUT_CACHE_SLOT: usize = CACHE_SIZE - 1;
+
 /// Return the cache slot to cache the current computed result in
 ///
 /// ## Caching Strategy
@@ -165,6 +283,11 @@ fn compute_node_layout(
 /// - Slot 2: 1 of 2 known_dimensions were set and the other dimension was a MinContent constraint
 /// - Slot 3: Neither known_dimensions were set and we are sizing under a MaxContent or Definite available space constraint
 /// - Slot 4: Neither known_dimensions were set and we are sizing under a MinContent constraint
+///
+/// The penultimate slot ([`COLLAPSIBLE_MARGINS_CACHE_SLOT`]) is reserved for the collapsible margins of the node, and
+/// the final slot ([`FINAL_LAYOUT_CACHE_SLOT`]) is reserved for the inputs and result of the most recent
+/// [`RunMode::PeformLayout`] run of a node with children. A node's layout is clean (and performing its layout again
+/// with the same inputs can skip its entire subtree) as long as this slot is filled.
 #[inline]
 fn compute_cache_slot(known_dimensions: Size<Option<f32>>, available_space: Size<AvailableSpace>) -> usize {
     let has_known_width = known_dimensions.width.is_some();
@@ -188,25 +311,25 @@ fn compute_cache_slot(known_dimensions: Size<Option<f32>>, available_space: Size
 }
 
 /// Try to get the computation result from the cache.
+///
+/// If `uses_final_layout_cache` is true then only the cached final layout of the node is considered, otherwise only the
+/// cached sizes of the node are considered.
 #[inline]
-fn compute_from_cache(
-    tree: &mut impl LayoutTree,
-    node: Node,
+pub(crate) fn compute_from_cache<Tree: LayoutTree>(
+    tree: &mut Tree,
+    node: Tree::NodeId,
     known_dimensions: Size<Option<f32>>,
     available_space: Size<AvailableSpace>,
-    run_mode: RunMode,
+    uses_final_layout_cache: bool,
     sizing_mode: SizingMode,
-) -> Option<Size<f32>> {
-    for idx in 0..CACHE_SIZE {
+) -> Option<SizeAndBaseline> {
+    let slots =
+        if uses_final_layout_cache { FINAL_LAYOUT_CACHE_SLOT..CACHE_SIZE } else { 0..COLLAPSIBLE_MARGINS_CACHE_SLOT };
+    for idx in slots {
         let entry = tree.cache_mut(node, idx);
         #[cfg(feature = "debug")]
         NODE_LOGGER.labelled_debug_log("cache_entry", &entry);
         if let Some(entry) = entry {
-            // Cached ComputeSize results are not valid if we are running in PerformLayout mode
-            if entry.run_mode == RunMode::ComputeSize && run_mode == RunMode::PeformLayout {
-                return None;
-            }
-
             if (known_dimensions.width == entry.known_dimensions.width
                 || known_dimensions.width == Some(entry.cached_size.width))
                 && (known_dimensions.height == entry.known_dimensions.height
@@ -222,7 +345,11 @@ fn compute_from_cache(
                         && available_space.height.is_definite()
                         && available_space.height.unwrap() >= entry.cached_size.height))
             {
-                return Some(entry.cached_size);
+                return Some(SizeAndBaseline {
+                    size: entry.cached_size,
+                    first_baseline: entry.cached_baseline,
+                    content_size: entry.cached_content_size,
+                });
             }
         }
     }
@@ -232,38 +359,278 @@ fn compute_from_cache(
 
 /// Creates a layout for this node and its children, recursively.
 /// Each hidden node has zero size and is placed at the origin
-fn perform_hidden_layout(tree: &mut impl LayoutTree, node: Node) -> Size<f32> {
+pub(crate) fn perform_hidden_layout<Tree: LayoutTree>(tree: &mut Tree, node: Tree::NodeId) -> SizeAndBaseline {
     /// Recursive function to apply hidden layout to all descendents
-    fn perform_hidden_layout_inner(tree: &mut impl LayoutTree, node: Node, order: u32) {
-        *tree.layout_mut(node) = Layout::with_order(order);
+    fn perform_hidden_layout_inner<Tree: LayoutTree>(
+        tree: &mut Tree,
+        node: Tree::NodeId,
+        order: u32,
+        parent_visibility: Visibility,
+    ) {
+        let visibility = tree.style(node).visibility.unwrap_or(parent_visibility);
+        *tree.layout_mut(node) = Layout { visibility, ..Layout::with_order(order) };
+        // The layouts of the node's children no longer match its cached final layout
+        *tree.cache_mut(node, FINAL_LAYOUT_CACHE_SLOT) = None;
         for order in 0..tree.child_count(node) {
-            perform_hidden_layout_inner(tree, tree.child(node, order), order as _);
+            perform_hidden_layout_inner(tree, tree.child(node, order), order as _, visibility);
         }
     }
 
+    // The layouts of the node's children no longer match its cached final layout (if it was previously displayed)
+    *tree.cache_mut(node, FINAL_LAYOUT_CACHE_SLOT) = None;
+    let visibility = tree.visibility(node);
     for order in 0..tree.child_count(node) {
-        perform_hidden_layout_inner(tree, tree.child(node, order), order as _);
+        perform_hidden_layout_inner(tree, tree.child(node, order), order as _, visibility);
     }
 
-    Size::ZERO
+    SizeAndBaseline::ZERO
+}
+
+/// Collects the absolutely positioned descendants of `node` whose containing block is `node` but which are not laid out
+/// by the layout algorithm of their parent. These are the absolutely positioned children of static descendants of `node`
+/// (that have no positioned ancestor closer than `node`).
+///
+/// If `include_children` is true then the absolutely positioned children of `node` itself are also collected. This is
+/// used for static nodes (whose absolutely positioned children are not laid out by their layout algorithm).
+fn collect_hoisted_descendants<Tree: LayoutTree>(
+    tree: &Tree,
+    node: Tree::NodeId,
+    include_children: bool,
+    hoisted: &mut Vec<Tree::NodeId>,
+) {
+    for child in tree.children(node) {
+        let style = tree.style(child);
+        match (style.display, style.position) {
+            (Display::None, _) => {}
+            (_, Position::Absolute) if include_children => hoisted.push(child),
+            (_, Position::Static) => collect_hoisted_descendants(tree, child, true, hoisted),
+            _ => {}
+        }
+    }
 }
 
-/// Rounds the calculated [`NodeData`] according to the spec
-fn round_layout(tree: &mut impl LayoutTree, root: Node, abs_x: f32, abs_y: f32) {
-    let layout = tree.layout_mut(root);
-    let abs_x = abs_x + layout.location.x;
-    let abs_y = abs_y + layout.location.y;
+/// Collects the fixed position descendants of `node` in tree order (so that ancestors come before their descendants)
+pub(crate) fn collect_fixed_descendants<Tree: LayoutTree>(
+    tree: &Tree,
+    node: Tree::NodeId,
+    fixed: &mut Vec<Tree::NodeId>,
+) {
+    for child in tree.children(node) {
+        let style = tree.style(child);
+        if style.display == Display::None {
+            continue;
+        }
+        if style.position == Position::Fixed {
+            fixed.push(child);
+        }
+        collect_fixed_descendants(tree, child, fixed);
+    }
+}
 
-    layout.location.x = round(layout.location.x);
-    layout.location.y = round(layout.location.y);
+/// Lays out out-of-flow descendants of `containing_block` (collected by [`collect_hoisted_descendants`] or
+/// [`LayoutTree::fixed_descendants`]) relative to its padding box. The containing block must already have been sized, and
+/// `size`, `border` and `padding` are its size, border and padding. The ancestors of each descendant (up to the containing
+/// block) must already have been laid out.
+///
+/// Descendants without an inset in an axis are placed at their static position: the start of the content box of their
+/// parent (in the direction of the containing block). Returns the far edges of the area covered by the descendants, relative to the containing block's border box.
+fn perform_hoisted_layout<Tree: LayoutTree>(
+    tree: &mut Tree,
+    containing_block: Tree::NodeId,
+    hoisted: &[Tree::NodeId],
+    size: Size<f32>,
+    border: Rect<f32>,
+    padding: Rect<f32>,
+) -> Size<f32> {
+    let area_size =
+        Size { width: size.width - border.horizontal_axis_sum(), height: size.height - border.vertical_axis_sum() };
+
+    let direction = tree.direction(containing_block);
+    let containing_block_visibility = tree.visibility(containing_block);
+
+    // The painting order of the children of each parent of the descendants, computed once per parent
+    let mut painting_orders: Vec<(Tree::NodeId, Vec<u32>)> = Vec::new();
+
+    let mut content_size = Size::ZERO;
+    for &node in hoisted {
+        let parent = match tree.parent(node) {
+            Some(parent) => parent,
+            None => continue,
+        };
+
+        // Determine the offset of the parent's border box from the containing block's border box
+        let mut parent_offset = Point::ZERO;
+        let mut ancestor = parent;
+        while ancestor != containing_block {
+            let location = tree.layout(ancestor).location;
+            parent_offset.x += location.x;
+            parent_offset.y += location.y;
+            ancestor = match tree.parent(ancestor) {
+                Some(ancestor) => ancestor,
+                None => break,
+            };
+        }
 
-    layout.size.width = round(layout.size.width);
-    layout.size.height = round(layout.size.height);
+        // Note: the layout of the containing block itself has not been written yet
+        let (parent_size, parent_padding, parent_border, parent_visibility) = if parent == containing_block {
+            (size, padding, border, containing_block_visibility)
+        } else {
+            let layout = tree.layout(parent);
+            (layout.size, layout.padding, layout.border, layout.visibility)
+        };
+
+        let style = tree.style(node);
+        let inset = style.inset.zip_size(area_size, |p, s| p.maybe_resolve(s));
+        let margin = style.margin.resolve_or_zero(Some(area_size.width));
+        let min_size = style.min_size.maybe_resolve(area_size);
+        let max_size = style.max_size.maybe_resolve(area_size);
+        let mut known_dimensions = style.size.maybe_resolve(area_size).maybe_clamp(min_size, max_size);
+
+        // Fill in width from left/right and height from top/bottom if appropriate
+        if let (None, Some(left), Some(right)) = (known_dimensions.width, inset.left, inset.right) {
+            known_dimensions.width = Some(area_size.width - left - right - margin.horizontal_axis_sum());
+        }
+        if let (None, Some(top), Some(bottom)) = (known_dimensions.height, inset.top, inset.bottom) {
+            known_dimensions.height = Some(area_size.height - top - bottom - margin.vertical_axis_sum());
+        }
+
+        let SizeAndBaseline { size: node_size, first_baseline, content_size: node_content_size } = compute_node_layout(
+            tree,
+            node,
+            known_dimensions,
+            area_size.map(AvailableSpace::Definite),
+            RunMode::PeformLayout,
+            SizingMode::ContentSize,
+        );
+        let node_size = node_size.maybe_clamp(min_size, max_size);
+
+        // If both insets in an axis are set then the inset on the start edge wins
+        let from_left = inset.left.map(|left| border.left + left + margin.left);
+        let from_right = inset.right.map(|right| size.width - border.right - right - margin.right - node_size.width);
+        let x = match direction {
+            Direction::Ltr => from_left
+                .or(from_right)
+                .unwrap_or(parent_offset.x + parent_border.left + parent_padding.left + margin.left),
+            Direction::Rtl => from_right.or(from_left).unwrap_or(
+                parent_offset.x + parent_size.width
+                    - parent_border.right
+                    - parent_padding.right
+                    - margin.right
+                    - node_size.width,
+            ),
+        };
+        let from_top = inset.top.map(|top| border.top + top + margin.top);
+        let from_bottom =
+            inset.bottom.map(|bottom| size.height - border.bottom - bottom - margin.bottom - node_size.height);
+        let y =
+            from_top.or(from_bottom).unwrap_or(parent_offset.y + parent_border.top + parent_padding.top + margin.top);
+
+        let index = tree.children(parent).position(|child| child == node).unwrap_or(0);
+        let painting_order = match painting_orders.iter().position(|(other_parent, _)| *other_parent == parent) {
+            Some(position) => &painting_orders[position].1,
+            None => {
+                painting_orders.push((parent, compute_painting_order(tree, parent)));
+                &painting_orders[painting_orders.len() - 1].1
+            }
+        };
+        let order = painting_order[index];
+        let style = tree.style(node);
+        let visibility = style.visibility.unwrap_or(parent_visibility);
+        *tree.layout_mut(node) = Layout {
+            order,
+            size: node_size,
+            location: Point { x: x - parent_offset.x, y: y - parent_offset.y },
+            padding: style.padding.resolve_or_zero(Some(area_size.width)),
+            border: style.border.resolve_or_zero(Some(area_size.width)),
+            margin,
+            content_size: node_content_size,
+            first_baseline,
+            visibility,
+        };
+
+        // The ancestors of the node up to the containing block (whose layouts may have been skipped by this layout)
+        // must visit their children when rounding
+        let mut ancestor = Some(parent);
+        while let Some(current) = ancestor {
+            if let Some(entry) = tree.cache_mut(current, FINAL_LAYOUT_CACHE_SLOT) {
+                entry.rounded_position = None;
+            }
+            ancestor = if current == containing_block { None } else { tree.parent(current) };
+        }
+
+        let contribution = compute_content_contribution(tree, node, Point { x, y });
+        content_size.width = content_size.width.max(contribution.width);
+        content_size.height = content_size.height.max(contribution.height);
+    }
+
+    content_size
+}
+
+/// Rounds the layout of `node` to produce its final layout, notifying the tree if its final location, size, order or
+/// visibility has changed
+///
+/// The padding, border and margin of the node are rounded cumulatively: each is the distance between the rounded
+/// absolute positions of its edges, so that adjacent edges neither overlap nor leave gaps between them.
+/// `parent_position` is the unrounded absolute position of the node's parent.
+///
+/// The children of the node are only visited if they have been laid out or the node has moved since the node was last
+/// rounded, as otherwise neither their layouts nor the layouts of their descendants can have changed.
+fn round_layout<Tree: LayoutTree>(tree: &mut Tree, node: Tree::NodeId, parent_position: Point<f32>) {
+    let layout = tree.layout(node);
+    let position = Point { x: parent_position.x + layout.location.x, y: parent_position.y + layout.location.y };
+    let far_corner = Point { x: position.x + layout.size.width, y: position.y + layout.size.height };
+    let (padding, border, margin) = (layout.padding, layout.border, layout.margin);
+
+    // The rounded distance between two absolute positions
+    let round_between = |start: f32, end: f32| round(end) - round(start);
+
+    let rounded_layout = Layout {
+        order: layout.order,
+        size: Size { width: round(layout.size.width), height: round(layout.size.height) },
+        location: Point { x: round(layout.location.x), y: round(layout.location.y) },
+        padding: Rect {
+            left: round_between(position.x + border.left, position.x + border.left + padding.left),
+            right: round_between(far_corner.x - border.right - padding.right, far_corner.x - border.right),
+            top: round_between(position.y + border.top, position.y + border.top + padding.top),
+            bottom: round_between(far_corner.y - border.bottom - padding.bottom, far_corner.y - border.bottom),
+        },
+        border: Rect {
+            left: round_between(position.x, position.x + border.left),
+            right: round_between(far_corner.x - border.right, far_corner.x),
+            top: round_between(position.y, position.y + border.top),
+            bottom: round_between(far_corner.y - border.bottom, far_corner.y),
+        },
+        margin: Rect {
+            left: round_between(position.x - margin.left, position.x),
+            right: round_between(far_corner.x, far_corner.x + margin.right),
+            top: round_between(position.y - margin.top, position.y),
+            bottom: round_between(far_corner.y, far_corner.y + margin.bottom),
+        },
+        content_size: Size { width: round(layout.content_size.width), height: round(layout.content_size.height) },
+        first_baseline: layout.first_baseline.map(round),
+        visibility: layout.visibility,
+    };
+    let final_layout = tree.final_layout_mut(node);
+    let has_changed = final_layout.location != rounded_layout.location
+        || final_layout.size != rounded_layout.size
+        || final_layout.order != rounded_layout.order
+        || final_layout.visibility != rounded_layout.visibility;
+    *final_layout = rounded_layout;
+    if has_changed {
+        tree.notify_layout_changed(node);
+    }
+
+    if let Some(entry) = tree.cache_mut(node, FINAL_LAYOUT_CACHE_SLOT) {
+        if entry.rounded_position == Some(position) {
+            return;
+        }
+        entry.rounded_position = Some(position);
+    }
 
     // Satisfy the borrow checker here by re-indexing to shorten the lifetime to the loop scope
-    for x in 0..tree.child_count(root) {
-        let child = tree.child(root, x);
-        round_layout(tree, child, abs_x, abs_y);
+    for x in 0..tree.child_count(node) {
+        let child = tree.child(node, x);
+        round_layout(tree, child, position);
     }
 }
 
@@ -298,11 +665,9 @@ mod tests {
 
         // Whatever size and display-mode the nodes had previously,
         // all layouts should resolve to ZERO due to the root's DISPLAY::NONE
-        for (node, _) in taffy.nodes.iter().filter(|(node, _)| *node != root) {
-            if let Ok(layout) = taffy.layout(node) {
-                assert_eq!(layout.size, Size::zero());
-                assert_eq!(layout.location, Point::zero());
-            }
+        for (_, data) in taffy.layouts.iter().filter(|(node, _)| *node != root) {
+            assert_eq!(data.unrounded_layout.size, Size::zero());
+            assert_eq!(data.unrounded_layout.location, Point::zero());
         }
     }
 }
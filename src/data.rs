@@ -2,33 +2,58 @@
 //!
 //! Used to compute layout for Taffy trees
 //!
-use crate::layout::{Cache, Layout};
+use crate::layout::{Cache, Layout, CACHE_SIZE};
+#[cfg(feature = "parallel")]
+use crate::parallel::SubtreeSlot;
 use crate::style::Style;
 
-/// The number of cache entries for each node in the tree
-pub(crate) const CACHE_SIZE: usize = 5;
-
-/// Layout information for a given [`Node`](crate::node::Node)
+/// Styling information for a given [`Node`](crate::node::Node)
 ///
 /// Stored in a [`Taffy`].
 pub(crate) struct NodeData {
     /// The layout strategy used by this node
     pub(crate) style: Style,
-    /// The results of the layout computation
-    pub(crate) layout: Layout,
 
     /// Should we try and measure this node?
     pub(crate) needs_measure: bool,
 
-    /// The primary cached results of the layout computation
-    pub(crate) size_cache: [Option<Cache>; CACHE_SIZE],
+    /// The slot of the node's [`LayoutData`] in the view of the subtree that is laying it out concurrently, if any
+    #[cfg(feature = "parallel")]
+    pub(crate) subtree_slot: Option<SubtreeSlot>,
 }
 
 impl NodeData {
     /// Create the data for a new node
     #[must_use]
     pub const fn new(style: Style) -> Self {
-        Self { style, size_cache: [None; CACHE_SIZE], layout: Layout::new(), needs_measure: false }
+        Self {
+            style,
+            needs_measure: false,
+            #[cfg(feature = "parallel")]
+            subtree_slot: None,
+        }
+    }
+}
+
+/// Layout information for a given [`Node`](crate::node::Node)
+///
+/// Stored in a [`Taffy`], separately from the [`NodeData`] of the node, as this is the only data that is written to
+/// during layout.
+pub(crate) struct LayoutData {
+    /// The final (rounded) results of the layout computation
+    pub(crate) layout: Layout,
+    /// The unrounded results of the layout computation
+    pub(crate) unrounded_layout: Layout,
+
+    /// The primary cached results of the layout computation
+    pub(crate) size_cache: [Option<Cache>; CACHE_SIZE],
+}
+
+impl LayoutData {
+    /// Create the layout data for a new node
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { size_cache: [None; CACHE_SIZE], layout: Layout::new(), unrounded_layout: Layout::new() }
     }
 
     /// Marks a node and all of its parents (recursively) as dirty
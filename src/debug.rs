@@ -1,18 +1,32 @@
 use core::fmt::{Debug, Display, Write};
-use slotmap::Key;
 use std::sync::Mutex;
 
-use crate::node::Node;
+use crate::node::{Node, Taffy};
 use crate::style;
 use crate::tree::LayoutTree;
 
 /// Prints a debug representation of the computed layout for a tree of nodes, starting with the passed root node.
-pub fn print_tree(tree: &impl LayoutTree, root: Node) {
+///
+/// The context of each node that has one is printed after its id.
+pub fn print_tree<T: Debug>(taffy: &Taffy<T>, root: Node) {
     println!("TREE");
-    print_node(tree, root, false, String::new());
+    print_node(taffy, root, false, String::new(), &|node| taffy.get_node_context(node).map(|context| context as _));
 }
 
-fn print_node(tree: &impl LayoutTree, node: Node, has_sibling: bool, lines_string: String) {
+/// Prints a debug representation of the computed layout for any [`LayoutTree`], starting with the passed root node.
+pub fn print_layout_tree<Tree: LayoutTree>(tree: &Tree, root: Tree::NodeId) {
+    println!("TREE");
+    print_node(tree, root, false, String::new(), &|_| None);
+}
+
+/// Prints a node and its descendants, labelling each with its context as returned by `node_context`
+fn print_node<'t, Tree: LayoutTree>(
+    tree: &Tree,
+    node: Tree::NodeId,
+    has_sibling: bool,
+    lines_string: String,
+    node_context: &dyn Fn(Tree::NodeId) -> Option<&'t dyn Debug>,
+) {
     let layout = tree.layout(node);
     let style = tree.style(node);
 
@@ -21,14 +35,21 @@ fn print_node(tree: &impl LayoutTree, node: Node, has_sibling: bool, lines_strin
     let display = match (num_children, style.display) {
         (_, style::Display::None) => "NONE",
         (0, _) => "LEAF",
+        #[cfg(feature = "block_layout")]
+        (_, style::Display::Block) => "BLOCK",
         (_, style::Display::Flex) => "FLEX",
         #[cfg(feature = "grid")]
         (_, style::Display::Grid) => "GRID",
+        (_, style::Display::Custom) => "CUSTOM",
     };
 
     let fork_string = if has_sibling { "├── " } else { "└── " };
+    let context = match node_context(node) {
+        Some(context) => format!(" {context:?}"),
+        None => String::new(),
+    };
     println!(
-        "{lines}{fork} {display} [x: {x:<4} y: {y:<4} width: {width:<4} height: {height:<4}] ({key:?})",
+        "{lines}{fork} {display} [x: {x:<4} y: {y:<4} width: {width:<4} height: {height:<4}] ({key:?}){context}",
         lines = lines_string,
         fork = fork_string,
         display = display,
@@ -36,7 +57,7 @@ fn print_node(tree: &impl LayoutTree, node: Node, has_sibling: bool, lines_strin
         y = layout.location.y,
         width = layout.size.width,
         height = layout.size.height,
-        key = node.data(),
+        key = node,
     );
     let bar = if has_sibling { "│   " } else { "    " };
     let new_string = lines_string + bar;
@@ -44,7 +65,7 @@ fn print_node(tree: &impl LayoutTree, node: Node, has_sibling: bool, lines_strin
     // Recurse into children
     for (index, child) in tree.children(node).enumerate() {
         let has_sibling = index < num_children - 1;
-        print_node(tree, *child, has_sibling, new_string.clone());
+        print_node(tree, child, has_sibling, new_string.clone(), node_context);
     }
 }
 
@@ -59,10 +80,10 @@ impl DebugLogger {
         Self { stack: Mutex::new(Vec::new()) }
     }
 
-    pub fn push_node(&self, new_key: impl Key) {
+    pub fn push_node(&self, new_key: impl Debug) {
         let mut stack = self.stack.lock().unwrap();
         let mut key_string = String::new();
-        write!(&mut key_string, "{:?}", new_key.data()).unwrap();
+        write!(&mut key_string, "{:?}", new_key).unwrap();
         stack.push(key_string);
     }
 
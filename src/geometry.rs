@@ -1,6 +1,6 @@
 //! Geometric primitives useful for layout
 
-use crate::style::{Dimension, FlexDirection};
+use crate::style::{Dimension, Direction, FlexDirection};
 use core::ops::Add;
 
 #[cfg(feature = "grid")]
@@ -80,6 +80,17 @@ impl<T> Rect<T> {
     pub fn vertical_components(self) -> Line<T> {
         Line { start: self.top, end: self.bottom }
     }
+
+    /// Swaps the left and right properties of the Rect if the direction is right-to-left
+    ///
+    /// Right-to-left content is laid out as if it were left-to-right content with its horizontal edges swapped, and is then
+    /// mirrored. This converts physical edges into that mirrored space (and back again).
+    pub(crate) fn for_direction(self, direction: Direction) -> Rect<T> {
+        match direction {
+            Direction::Ltr => self,
+            Direction::Rtl => Rect { left: self.right, right: self.left, top: self.top, bottom: self.bottom },
+        }
+    }
 }
 
 impl<T, U> Rect<T>
@@ -397,6 +408,7 @@ impl Size<Dimension> {
 ///
 /// When used in association with a [`Rect`], represents the bottom-left corner.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Point<T> {
     /// The x-coordinate
     pub x: T,
@@ -409,6 +421,40 @@ impl Point<f32> {
     pub const ZERO: Point<f32> = Self { x: 0.0, y: 0.0 };
 }
 
+impl<T> Point<T> {
+    /// Gets the component in the main layout axis
+    ///
+    /// Whether this is the x or y component depends on the `direction` provided
+    pub(crate) fn main(self, direction: FlexDirection) -> T {
+        if direction.is_row() {
+            self.x
+        } else {
+            self.y
+        }
+    }
+
+    /// Gets the component in the cross layout axis
+    ///
+    /// Whether this is the x or y component depends on the `direction` provided
+    pub(crate) fn cross(self, direction: FlexDirection) -> T {
+        if direction.is_row() {
+            self.y
+        } else {
+            self.x
+        }
+    }
+
+    /// Gets the component in the specified layout axis
+    /// Whether this is the x or y component depends on the `GridAxis` provided
+    #[cfg(feature = "grid")]
+    pub(crate) fn get(self, axis: AbstractAxis) -> T {
+        match axis {
+            AbstractAxis::Inline => self.x,
+            AbstractAxis::Block => self.y,
+        }
+    }
+}
+
 /// Generic struct which holds a "min" value and a "max" value
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct MinMax<Min, Max> {
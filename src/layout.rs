@@ -1,7 +1,9 @@
 //! Final and cached data structures that represent the high-level UI layout
 
-use crate::geometry::{Point, Size};
-use crate::style::AvailableSpace;
+#[cfg(feature = "block_layout")]
+use crate::compute::block::CollapsibleMargins;
+use crate::geometry::{Point, Rect, Size};
+use crate::style::{AvailableSpace, Visibility};
 
 /// Whether we are performing a full layout, or we merely need to size the node
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -10,6 +12,9 @@ pub enum RunMode {
     PeformLayout,
     /// The layout algorithm should be executed such that an accurate container size for the node can be determined.
     /// Layout steps that aren't necessary for determining the container size of the current node can be skipped.
+    ///
+    /// The first baseline of the node is also determined (without laying out its children), unless the node is a block
+    /// or grid container whose size is known up front, in which case its children are not sized at all.
     ComputeSize,
 }
 
@@ -22,8 +27,23 @@ pub enum SizingMode {
     InherentSize,
 }
 
+/// The inputs with which a layout algorithm is about to perform the layout of one of its children
+///
+/// See [`LayoutTree::prepare_child_layouts`](crate::tree::LayoutTree::prepare_child_layouts)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LayoutInput<NodeId> {
+    /// The child node
+    pub node: NodeId,
+    /// The sizes of the child that are already known
+    pub known_dimensions: Size<Option<f32>>,
+    /// The space available to the child
+    pub available_space: Size<AvailableSpace>,
+    /// Whether the inherent size styles of the child are taken into account
+    pub sizing_mode: SizingMode,
+}
+
 /// The final result of a layout algorithm for a single [`Node`](crate::node::Node).
-#[derive(Copy, Debug, Clone)]
+#[derive(Copy, Debug, Clone, PartialEq)]
 pub struct Layout {
     /// The relative ordering of the node
     ///
@@ -34,29 +54,111 @@ pub struct Layout {
     pub size: Size<f32>,
     /// The bottom-left corner of the node
     pub location: Point<f32>,
+    /// The resolved padding of the node
+    pub padding: Rect<f32>,
+    /// The resolved border widths of the node
+    pub border: Rect<f32>,
+    /// The resolved margin of the node (with any `auto` margins resolved to the space they took up)
+    ///
+    /// Note: these are the node's own margins. Margins that were collapsed with adjoining margins are not adjusted.
+    pub margin: Rect<f32>,
+    /// The size of the node's scrollable content
+    ///
+    /// This is the union of the margin boxes of the node's children (including any content that overflows children
+    /// with `Overflow::Visible`), measured from the top-left corner of the node's border box. For leaf nodes this is
+    /// the size reported by the node's measure function (if any). It may be larger than `size` if the content overflows.
+    pub content_size: Size<f32>,
+    /// The distance from the top edge of the node's border box to its first baseline
+    ///
+    /// This is `None` if the node has no baseline (for example because it is a leaf node without a measure function, or
+    /// a container without any children). Parent layout algorithms fall back to the bottom edge of the border box in that case.
+    pub first_baseline: Option<f32>,
+    /// The resolved visibility of the node (see [`LayoutTree::visibility`](crate::tree::LayoutTree::visibility))
+    ///
+    /// Nodes that are not [`Visibility::Visible`] should not be painted. Their children may still be visible.
+    pub visibility: Visibility,
 }
 
 impl Layout {
     /// Creates a new zero-[`Layout`].
     ///
-    /// The Zero-layout has size and location set to ZERO.
+    /// The Zero-layout has size, location, padding, border, margin and content size set to ZERO, and is visible.
     /// The `order` value of this layout is set to the minimum value of 0.
     /// This means it should be rendered below all other [`Layout`]s.
     #[must_use]
     pub const fn new() -> Self {
-        Self { order: 0, size: Size::zero(), location: Point::ZERO }
+        Self::with_order(0)
     }
 
     /// Creates a new zero-[`Layout`] with the supplied `order` value.
     ///
     /// Nodes with a higher order should be rendered on top of those with a lower order.
-    /// The Zero-layout has size and location set to ZERO.
+    /// The Zero-layout has size, location, padding, border, margin and content size set to ZERO, and is visible.
     #[must_use]
     pub const fn with_order(order: u32) -> Self {
-        Self { order, size: Size::zero(), location: Point::ZERO }
+        Self {
+            order,
+            size: Size::zero(),
+            location: Point::ZERO,
+            padding: Rect::ZERO,
+            border: Rect::ZERO,
+            margin: Rect::ZERO,
+            content_size: Size::ZERO,
+            first_baseline: None,
+            visibility: Visibility::Visible,
+        }
+    }
+}
+
+/// The size of a node along with the position of its first baseline and the size of its content
+///
+/// This is both the output of a [`MeasureFunc`](crate::node::MeasureFunc) that is able to report baselines,
+/// and the output of each of the layout algorithms.
+#[derive(Copy, Debug, Clone, PartialEq)]
+pub struct SizeAndBaseline {
+    /// The width and height of the node
+    pub size: Size<f32>,
+    /// The distance from the top edge of the node's border box to its first baseline (if it has one)
+    pub first_baseline: Option<f32>,
+    /// The size of the node's content, which may be larger than `size` if the content overflows the node
+    ///
+    /// See [`Layout::content_size`]
+    pub content_size: Size<f32>,
+}
+
+impl SizeAndBaseline {
+    /// A `SizeAndBaseline` with zero size, no baseline and no content
+    pub const ZERO: Self = Self { size: Size::ZERO, first_baseline: None, content_size: Size::ZERO };
+
+    /// Creates a new `SizeAndBaseline` with the supplied size and no baseline
+    ///
+    /// The content size is zero, as it is for leaf nodes without a measure function
+    #[must_use]
+    pub const fn from_size(size: Size<f32>) -> Self {
+        Self { size, first_baseline: None, content_size: Size::ZERO }
+    }
+
+    /// Returns the node's first baseline, or the height of the node if it does not have a baseline
+    ///
+    /// This is the baseline that a parent should use when aligning the node, as a box without a baseline
+    /// has one synthesized from the bottom edge of its border box.
+    #[must_use]
+    pub fn first_baseline_or_height(&self) -> f32 {
+        self.first_baseline.unwrap_or(self.size.height)
+    }
+}
+
+impl From<Size<f32>> for SizeAndBaseline {
+    fn from(size: Size<f32>) -> Self {
+        Self::from_size(size)
     }
 }
 
+/// The number of cache entries for each node in the tree
+///
+/// See [`LayoutTree::cache_mut`](crate::tree::LayoutTree::cache_mut)
+pub const CACHE_SIZE: usize = 7;
+
 /// Cached intermediate layout results
 #[derive(Debug, Clone, Copy)]
 pub struct Cache {
@@ -64,9 +166,23 @@ pub struct Cache {
     pub(crate) known_dimensions: Size<Option<f32>>,
     /// The initial cached size of the parent's node
     pub(crate) available_space: Size<AvailableSpace>,
-    /// Whether or not layout should be recomputed
-    pub(crate) run_mode: RunMode,
+    /// The unrounded absolute position of the node when its layout was last rounded, or `None` if its children have
+    /// been laid out since then
+    ///
+    /// Layouts are rounded cumulatively, so the children of the node must be rounded again if either has changed. This
+    /// is only used by the entry that caches the node's final layout.
+    pub(crate) rounded_position: Option<Point<f32>>,
 
     /// The cached size of the item
     pub(crate) cached_size: Size<f32>,
+    /// The cached first baseline of the item
+    pub(crate) cached_baseline: Option<f32>,
+    /// The cached content size of the item
+    pub(crate) cached_content_size: Size<f32>,
+    /// The cached collapsible margins of the item, when it is an in-flow child of a block container
+    ///
+    /// This is only used by the entry that caches them, whose `available_space` width is the content box width of the
+    /// block container.
+    #[cfg(feature = "block_layout")]
+    pub(crate) cached_collapsible_margins: Option<CollapsibleMargins>,
 }
@@ -29,6 +29,8 @@ pub mod node;
 pub mod prelude;
 pub mod style;
 pub mod style_helpers;
+#[cfg(feature = "text")]
+pub mod text;
 pub mod tree;
 
 #[cfg(feature = "random")]
@@ -36,8 +38,10 @@ pub mod randomizable;
 
 mod compute;
 mod data;
+#[cfg(feature = "parallel")]
+mod parallel;
 mod resolve;
 mod sys;
 
-pub use crate::compute::compute_layout;
+pub use crate::compute::{compute_layout, compute_node_layout};
 pub use crate::node::Taffy;
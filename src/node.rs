@@ -1,20 +1,25 @@
 //! UI [`Node`] types and related data structures.
 //!
 //! Layouts are composed of multiple nodes, which live in a tree-like data structure.
-use slotmap::{DefaultKey, SlotMap, SparseSecondaryMap};
+use slotmap::{SlotMap, SparseSecondaryMap};
 
 /// A node in a layout.
 pub type Node = slotmap::DefaultKey;
 
 use crate::error::{TaffyError, TaffyResult};
 use crate::geometry::Size;
-use crate::layout::{Cache, Layout};
+#[cfg(feature = "parallel")]
+use crate::layout::LayoutInput;
+use crate::layout::{Cache, Layout, SizeAndBaseline};
 use crate::prelude::LayoutTree;
-use crate::style::{AvailableSpace, Style};
+use crate::style::{AvailableSpace, Display, Position, ResolutionContext, Style};
 #[cfg(any(feature = "std", feature = "alloc"))]
 use crate::sys::Box;
 use crate::sys::{new_vec_with_capacity, ChildrenVec, Vec};
-use crate::{data::NodeData, error};
+use crate::{
+    data::{LayoutData, NodeData},
+    error,
+};
 
 /// A function type that can be used in a [`MeasureFunc`]
 ///
@@ -22,6 +27,12 @@ use crate::{data::NodeData, error};
 pub trait Measurable: Send + Sync + Fn(Size<Option<f32>>, Size<AvailableSpace>) -> Size<f32> {}
 impl<F: Send + Sync + Fn(Size<Option<f32>>, Size<AvailableSpace>) -> Size<f32>> Measurable for F {}
 
+/// A function type that can be used in a [`MeasureFunc`] that also reports the first baseline of the measured node
+///
+/// This trait is automatically implemented for all types (including closures) that define a function with the appropriate type signature.
+pub trait MeasurableWithBaseline: Send + Sync + Fn(Size<Option<f32>>, Size<AvailableSpace>) -> SizeAndBaseline {}
+impl<F: Send + Sync + Fn(Size<Option<f32>>, Size<AvailableSpace>) -> SizeAndBaseline> MeasurableWithBaseline for F {}
+
 /// A function that can be used to compute the intrinsic size of a node
 pub enum MeasureFunc {
     /// Stores an unboxed function
@@ -30,16 +41,71 @@ pub enum MeasureFunc {
     /// Stores a boxed function
     #[cfg(any(feature = "std", feature = "alloc"))]
     Boxed(Box<dyn Measurable>),
+
+    /// Stores an unboxed function that also reports the first baseline of the node (e.g. the baseline of the first line of text)
+    RawWithBaseline(fn(Size<Option<f32>>, Size<AvailableSpace>) -> SizeAndBaseline),
+
+    /// Stores a boxed function that also reports the first baseline of the node (e.g. the baseline of the first line of text)
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    BoxedWithBaseline(Box<dyn MeasurableWithBaseline>),
+}
+
+impl MeasureFunc {
+    /// Whether this function reports the first baseline of the node
+    pub(crate) fn reports_baseline(&self) -> bool {
+        match self {
+            MeasureFunc::Raw(_) => false,
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            MeasureFunc::Boxed(_) => false,
+            MeasureFunc::RawWithBaseline(_) => true,
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            MeasureFunc::BoxedWithBaseline(_) => true,
+        }
+    }
+
+    /// Measure a node using this function
+    pub(crate) fn measure(
+        &self,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+    ) -> SizeAndBaseline {
+        match self {
+            MeasureFunc::Raw(measure) => measure(known_dimensions, available_space).into(),
+
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            MeasureFunc::Boxed(measure) => {
+                (measure as &dyn Fn(_, _) -> Size<f32>)(known_dimensions, available_space).into()
+            }
+
+            MeasureFunc::RawWithBaseline(measure) => measure(known_dimensions, available_space),
+
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            MeasureFunc::BoxedWithBaseline(measure) => {
+                (measure as &dyn Fn(_, _) -> _)(known_dimensions, available_space)
+            }
+        }
+    }
 }
 
 /// A tree of UI [`Nodes`](`Node`), suitable for UI layout
-pub struct Taffy {
+///
+/// Each node may carry a context of type `T` (such as the widget or text that the node belongs to), which is passed to
+/// the measure function of [`Taffy::compute_layout_with_measure`].
+pub struct Taffy<T = ()> {
     /// The [`NodeData`] for each node stored in this tree
     pub(crate) nodes: SlotMap<Node, NodeData>,
 
+    /// The [`LayoutData`] for each node stored in this tree
+    ///
+    /// The indexes in the outer vector correspond to the position of the [`NodeData`] of the node
+    pub(crate) layouts: SlotMap<Node, LayoutData>,
+
     /// The mapping from the Size<AvailableSpace> (in real units) to Size<f32> (in points) for this node
     pub(crate) measure_funcs: SparseSecondaryMap<Node, MeasureFunc>,
 
+    /// The context of each node that has one
+    pub(crate) node_contexts: SparseSecondaryMap<Node, T>,
+
     /// The children of each node
     ///
     /// The indexes in the outer vector correspond to the position of the parent [`NodeData`]
@@ -49,19 +115,46 @@ pub struct Taffy {
     ///
     /// The indexes in the outer vector correspond to the position of the child [`NodeData`]
     pub(crate) parents: SlotMap<Node, Option<Node>>,
+
+    /// The styles of nodes that use viewport-relative or font-relative lengths, with those lengths converted to points
+    ///
+    /// These are what the layout algorithms see. They are updated at the start of each layout.
+    pub(crate) resolved_styles: SparseSecondaryMap<Node, Style>,
+
+    /// The nodes whose styles use viewport-relative or font-relative lengths
+    pub(crate) relative_unit_nodes: SparseSecondaryMap<Node, ()>,
+
+    /// The nodes whose styles are fixed position, which are laid out relative to the root node
+    pub(crate) fixed_nodes: SparseSecondaryMap<Node, ()>,
+
+    /// The context that the `resolved_styles` were resolved against
+    ///
+    /// This is `None` if the tree has changed in a way that may affect the resolved styles since the previous layout.
+    pub(crate) resolution_context: Option<ResolutionContext>,
+
+    /// Whether the nodes whose layout changes during layout are recorded
+    pub(crate) track_layout_changes: bool,
+
+    /// The nodes whose layout has changed during the current layout
+    pub(crate) changed_layouts: Vec<Node>,
 }
 
-impl Default for Taffy {
+impl<T> Default for Taffy<T> {
     fn default() -> Self {
-        Taffy::new()
+        Taffy::with_context_capacity(16)
     }
 }
 
-impl LayoutTree for Taffy {
-    type ChildIter<'a> = std::slice::Iter<'a, DefaultKey>;
+impl<T> LayoutTree for Taffy<T> {
+    type NodeId = Node;
+
+    type ChildIter<'a>
+        = core::iter::Copied<core::slice::Iter<'a, Node>>
+    where
+        Self: 'a;
 
     fn children(&self, node: Node) -> Self::ChildIter<'_> {
-        self.children[node].iter()
+        self.children[node].iter().copied()
     }
 
     fn child_count(&self, node: Node) -> usize {
@@ -77,15 +170,29 @@ impl LayoutTree for Taffy {
     }
 
     fn style(&self, node: Node) -> &Style {
-        &self.nodes[node].style
+        self.resolved_styles.get(node).unwrap_or(&self.nodes[node].style)
+    }
+
+    fn fixed_descendants(&self, node: Node, fixed: &mut Vec<Node>) {
+        collect_fixed_nodes(self, &self.fixed_nodes, node, fixed)
     }
 
     fn layout(&self, node: Node) -> &Layout {
-        &self.nodes[node].layout
+        &self.layouts[node].unrounded_layout
     }
 
     fn layout_mut(&mut self, node: Node) -> &mut Layout {
-        &mut self.nodes[node].layout
+        &mut self.layouts[node].unrounded_layout
+    }
+
+    fn final_layout_mut(&mut self, node: Node) -> &mut Layout {
+        &mut self.layouts[node].layout
+    }
+
+    fn notify_layout_changed(&mut self, node: Node) {
+        if self.track_layout_changes {
+            self.changed_layouts.push(node);
+        }
     }
 
     #[inline(always)]
@@ -94,25 +201,30 @@ impl LayoutTree for Taffy {
     }
 
     fn measure_node(
-        &self,
+        &mut self,
         node: Node,
         known_dimensions: Size<Option<f32>>,
         available_space: Size<AvailableSpace>,
-    ) -> Size<f32> {
-        match &self.measure_funcs[node] {
-            MeasureFunc::Raw(measure) => measure(known_dimensions, available_space),
-
-            #[cfg(any(feature = "std", feature = "alloc"))]
-            MeasureFunc::Boxed(measure) => (measure as &dyn Fn(_, _) -> _)(known_dimensions, available_space),
-        }
+    ) -> SizeAndBaseline {
+        self.measure_funcs[node].measure(known_dimensions, available_space)
     }
 
     fn needs_measure(&self, node: Node) -> bool {
         self.nodes[node].needs_measure && self.measure_funcs.get(node).is_some()
     }
 
+    fn can_measure_baseline(&self, node: Node) -> bool {
+        self.measure_funcs.get(node).is_some_and(MeasureFunc::reports_baseline)
+    }
+
     fn cache_mut(&mut self, node: Node, index: usize) -> &mut Option<Cache> {
-        &mut self.nodes[node].size_cache[index]
+        &mut self.layouts[node].size_cache[index]
+    }
+
+    /// Lays out the subtrees of the children concurrently if the `parallel` feature is enabled
+    #[cfg(feature = "parallel")]
+    fn prepare_child_layouts(&mut self, inputs: &[LayoutInput<Node>]) {
+        crate::parallel::prepare_child_layouts(self, inputs)
     }
 
     fn child(&self, node: Node, id: usize) -> Node {
@@ -120,11 +232,11 @@ impl LayoutTree for Taffy {
     }
 }
 
-#[allow(clippy::iter_cloned_collect)] // due to no-std support, we need to use `iter_cloned` instead of `collect`
 impl Taffy {
     /// Creates a new [`Taffy`]
     ///
-    /// The default capacity of a [`Taffy`] is 16 nodes.
+    /// The default capacity of a [`Taffy`] is 16 nodes. Use [`Taffy::default`] to create a tree whose nodes carry
+    /// contexts of another type.
     #[must_use]
     pub fn new() -> Self {
         Self::with_capacity(16)
@@ -133,21 +245,41 @@ impl Taffy {
     /// Creates a new [`Taffy`] that can store `capacity` nodes before reallocation
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_context_capacity(capacity)
+    }
+}
+
+#[allow(clippy::iter_cloned_collect)] // due to no-std support, we need to use `iter_cloned` instead of `collect`
+impl<T> Taffy<T> {
+    /// Creates a new [`Taffy`] whose nodes carry contexts of type `T`, that can store `capacity` nodes before
+    /// reallocation
+    #[must_use]
+    pub fn with_context_capacity(capacity: usize) -> Self {
         Self {
             // TODO: make this method const upstream,
             // so constructors here can be const
             nodes: SlotMap::with_capacity(capacity),
+            layouts: SlotMap::with_capacity(capacity),
             children: SlotMap::with_capacity(capacity),
             parents: SlotMap::with_capacity(capacity),
             measure_funcs: SparseSecondaryMap::with_capacity(capacity),
+            node_contexts: SparseSecondaryMap::new(),
+            resolved_styles: SparseSecondaryMap::new(),
+            relative_unit_nodes: SparseSecondaryMap::new(),
+            fixed_nodes: SparseSecondaryMap::new(),
+            resolution_context: None,
+            track_layout_changes: false,
+            changed_layouts: Vec::new(),
         }
     }
 
     /// Creates and adds a new unattached leaf node to the tree, and returns the [`NodeId`] of the new node
     pub fn new_leaf(&mut self, layout: Style) -> TaffyResult<Node> {
         let id = self.nodes.insert(NodeData::new(layout));
+        let _ = self.layouts.insert(LayoutData::new());
         let _ = self.children.insert(new_vec_with_capacity(0));
         let _ = self.parents.insert(None);
+        self.track_style(id);
 
         Ok(id)
     }
@@ -160,10 +292,23 @@ impl Taffy {
         data.needs_measure = true;
 
         let id = self.nodes.insert(data);
+        let _ = self.layouts.insert(LayoutData::new());
         self.measure_funcs.insert(id, measure);
 
         let _ = self.children.insert(new_vec_with_capacity(0));
         let _ = self.parents.insert(None);
+        self.track_style(id);
+
+        Ok(id)
+    }
+
+    /// Creates and adds a new leaf node with the supplied context
+    ///
+    /// The node is measured by the measure function passed to [`Taffy::compute_layout_with_measure`], which receives
+    /// the context.
+    pub fn new_leaf_with_context(&mut self, layout: Style, context: T) -> TaffyResult<Node> {
+        let id = self.new_leaf(layout)?;
+        self.node_contexts.insert(id, context);
 
         Ok(id)
     }
@@ -171,6 +316,7 @@ impl Taffy {
     /// Creates and adds a new node, which may have any number of `children`
     pub fn new_with_children(&mut self, layout: Style, children: &[Node]) -> TaffyResult<Node> {
         let id = self.nodes.insert(NodeData::new(layout));
+        let _ = self.layouts.insert(LayoutData::new());
 
         for child in children {
             self.parents[*child] = Some(id);
@@ -178,6 +324,7 @@ impl Taffy {
 
         let _ = self.children.insert(children.iter().copied().collect::<_>());
         let _ = self.parents.insert(None);
+        self.track_style(id);
 
         Ok(id)
     }
@@ -187,8 +334,15 @@ impl Taffy {
     /// All associated [`Id`] will be rendered invalid.
     pub fn clear(&mut self) {
         self.nodes.clear();
+        self.layouts.clear();
         self.children.clear();
         self.parents.clear();
+        self.node_contexts.clear();
+        self.resolved_styles.clear();
+        self.relative_unit_nodes.clear();
+        self.fixed_nodes.clear();
+        self.resolution_context = None;
+        self.changed_layouts.clear();
     }
 
     /// Remove a specific [`Node`] from the tree
@@ -204,6 +358,13 @@ impl Taffy {
         let _ = self.children.remove(node);
         let _ = self.parents.remove(node);
         let _ = self.nodes.remove(node);
+        let _ = self.layouts.remove(node);
+        let _ = self.node_contexts.remove(node);
+        let _ = self.resolved_styles.remove(node);
+        let _ = self.relative_unit_nodes.remove(node);
+        let _ = self.fixed_nodes.remove(node);
+        // The font size inherited by the former children of the node may have changed
+        self.resolution_context = None;
 
         Ok(node)
     }
@@ -223,10 +384,37 @@ impl Taffy {
         Ok(())
     }
 
+    /// Sets the context of the associated node, or removes it if `None` is passed
+    pub fn set_node_context(&mut self, node: Node, context: Option<T>) -> TaffyResult<()> {
+        if let Some(context) = context {
+            self.node_contexts.insert(node, context);
+        } else {
+            self.node_contexts.remove(node);
+        }
+
+        self.mark_dirty_internal(node)?;
+
+        Ok(())
+    }
+
+    /// Gets the context of the associated node, if it has one
+    pub fn get_node_context(&self, node: Node) -> Option<&T> {
+        self.node_contexts.get(node)
+    }
+
+    /// Gets a mutable reference to the context of the associated node, if it has one
+    ///
+    /// Note that the node isn't marked as dirty: use [`LayoutTree::mark_dirty`] if the change affects its measured size.
+    pub fn get_node_context_mut(&mut self, node: Node) -> Option<&mut T> {
+        self.node_contexts.get_mut(node)
+    }
+
     /// Adds a `child` [`Node`] under the supplied `parent`
     pub fn add_child(&mut self, parent: Node, child: Node) -> TaffyResult<()> {
+        self.mark_reparented_child_dirty(child, self.parents[child], Some(parent));
         self.parents[child] = Some(parent);
         self.children[parent].push(child);
+        self.resolution_context = None;
         self.mark_dirty_internal(parent)?;
 
         Ok(())
@@ -234,6 +422,17 @@ impl Taffy {
 
     /// Directly sets the `children` of the supplied `parent`
     pub fn set_children(&mut self, parent: Node, children: &[Node]) -> TaffyResult<()> {
+        // Children that are added or removed may inherit a different direction or visibility
+        for index in 0..self.children[parent].len() {
+            let child = self.children[parent][index];
+            if !children.contains(&child) {
+                self.mark_reparented_child_dirty(child, Some(parent), None);
+            }
+        }
+        for child in children {
+            self.mark_reparented_child_dirty(*child, self.parents[*child], Some(parent));
+        }
+
         // Remove node as parent from all its current children.
         for child in &self.children[parent] {
             self.parents[*child] = None;
@@ -245,6 +444,7 @@ impl Taffy {
         }
 
         self.children[parent] = children.iter().copied().collect::<_>();
+        self.resolution_context = None;
 
         self.mark_dirty_internal(parent)?;
 
@@ -269,7 +469,9 @@ impl Taffy {
         }
 
         let child = self.children[parent].remove(child_index);
+        self.mark_reparented_child_dirty(child, Some(parent), None);
         self.parents[child] = None;
+        self.resolution_context = None;
 
         self.mark_dirty_internal(parent)?;
 
@@ -285,9 +487,12 @@ impl Taffy {
             return Err(error::TaffyError::ChildIndexOutOfBounds { parent, child_index, child_count });
         }
 
+        self.mark_reparented_child_dirty(new_child, self.parents[new_child], Some(parent));
         self.parents[new_child] = Some(parent);
         let old_child = core::mem::replace(&mut self.children[parent][child_index], new_child);
+        self.mark_reparented_child_dirty(old_child, Some(parent), None);
         self.parents[old_child] = None;
+        self.resolution_context = None;
 
         self.mark_dirty_internal(parent)?;
 
@@ -316,7 +521,20 @@ impl Taffy {
 
     /// Sets the [`Style`] of the provided `node`
     pub fn set_style(&mut self, node: Node, style: Style) -> TaffyResult<()> {
+        // Descendants that don't set a direction or visibility inherit this node's, so their cached layouts are
+        // invalidated too
+        let old_style = &self.nodes[node].style;
+        let inherited_styles_changed =
+            old_style.direction != style.direction || old_style.visibility != style.visibility;
+        // The relative lengths of this node and of its descendants that inherit its font size need to be resolved again
+        if old_style.font_size != style.font_size || old_style.uses_relative_units() {
+            self.resolution_context = None;
+        }
+        if inherited_styles_changed {
+            self.mark_descendants_dirty(node);
+        }
         self.nodes[node].style = style;
+        self.track_style(node);
         self.mark_dirty_internal(node)?;
         Ok(())
     }
@@ -328,7 +546,7 @@ impl Taffy {
 
     /// Return this node layout relative to its parent
     pub fn layout(&self, node: Node) -> TaffyResult<&Layout> {
-        Ok(&self.nodes[node].layout)
+        Ok(&self.layouts[node].layout)
     }
 
     /// Marks the layout computation of this node and its children as outdated
@@ -339,31 +557,310 @@ impl Taffy {
     fn mark_dirty_internal(&mut self, node: Node) -> TaffyResult<()> {
         /// WARNING: this will stack-overflow if the tree contains a cycle
         fn mark_dirty_recursive(
-            nodes: &mut SlotMap<Node, NodeData>,
+            layouts: &mut SlotMap<Node, LayoutData>,
             parents: &SlotMap<Node, Option<Node>>,
             node_id: Node,
         ) {
-            nodes[node_id].mark_dirty();
+            layouts[node_id].mark_dirty();
 
             if let Some(Some(node)) = parents.get(node_id) {
-                mark_dirty_recursive(nodes, parents, *node);
+                mark_dirty_recursive(layouts, parents, *node);
             }
         }
 
-        mark_dirty_recursive(&mut self.nodes, &self.parents, node);
+        mark_dirty_recursive(&mut self.layouts, &self.parents, node);
 
         Ok(())
     }
 
+    /// Marks the layout computation of `child` and its descendants as outdated if moving it from `old_parent` to
+    /// `new_parent` changes the direction or visibility that it inherits
+    fn mark_reparented_child_dirty(&mut self, child: Node, old_parent: Option<Node>, new_parent: Option<Node>) {
+        let inherited_styles = |parent: Option<Node>| match parent {
+            Some(parent) => (self.direction(parent), self.visibility(parent)),
+            None => Default::default(),
+        };
+        if inherited_styles(old_parent) != inherited_styles(new_parent) {
+            self.layouts[child].mark_dirty();
+            self.mark_descendants_dirty(child);
+        }
+    }
+
+    /// Marks the layout computation of all descendants of this node as outdated
+    fn mark_descendants_dirty(&mut self, node: Node) {
+        for index in 0..self.children[node].len() {
+            let child = self.children[node][index];
+            self.layouts[child].mark_dirty();
+            self.mark_descendants_dirty(child);
+        }
+    }
+
+    /// Sets whether layouts record the nodes whose layout has changed, which are returned by [`Taffy::changed_layouts`]
+    ///
+    /// This is disabled by default, in which case no nodes are recorded.
+    pub fn set_layout_change_tracking(&mut self, enabled: bool) {
+        self.track_layout_changes = enabled;
+    }
+
+    /// Returns the nodes whose location, size, order (relative to their parent) or visibility changed during the most
+    /// recent layout
+    ///
+    /// Nodes are only recorded if layout change tracking has been enabled with [`Taffy::set_layout_change_tracking`].
+    /// Nodes in subtrees that didn't need to be laid out again are never included, so only these nodes need to be
+    /// repainted.
+    pub fn changed_layouts(&self) -> impl Iterator<Item = Node> + '_ {
+        self.changed_layouts.iter().copied()
+    }
+
     /// Indicates whether the layout of this node (and its children) need to be recomputed
     pub fn dirty(&self, node: Node) -> TaffyResult<bool> {
-        Ok(self.nodes[node].size_cache.iter().all(|entry| entry.is_none()))
+        Ok(self.layouts[node].size_cache.iter().all(|entry| entry.is_none()))
     }
 
     /// Updates the stored layout of the provided `node` and its children
+    ///
+    /// Viewport-relative lengths are resolved against the available space (or zero if it is indefinite) and font-relative
+    /// lengths against a root font size of 16 points. Use [`Taffy::compute_layout_with_context`] to specify these.
     pub fn compute_layout(&mut self, node: Node, available_space: Size<AvailableSpace>) -> Result<(), TaffyError> {
+        self.compute_layout_with_context(node, available_space, default_resolution_context(available_space))
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, resolving viewport-relative (`vw`, `vh`) and
+    /// font-relative (`em`, `rem`) lengths using the passed [`ResolutionContext`]
+    ///
+    /// Only nodes whose resolved lengths have changed since the previous layout are recomputed, so changing the viewport
+    /// size or the root font size doesn't require the styles of the tree to be updated.
+    pub fn compute_layout_with_context(
+        &mut self,
+        node: Node,
+        available_space: Size<AvailableSpace>,
+        context: ResolutionContext,
+    ) -> Result<(), TaffyError> {
+        self.prepare_layout(&context)?;
         crate::compute::compute_layout(self, node, available_space)
     }
+
+    /// Updates the stored layout of the provided `node` and its children, measuring the nodes that have a context
+    /// (see [`Taffy::new_leaf_with_context`]) with the passed `measure` function
+    ///
+    /// The function is called with the caller-supplied `context` (such as a font system or per-frame state), the node
+    /// being measured and its context, followed by the known dimensions and available space in the same way as a
+    /// [`MeasureFunc`]. It may return either a [`Size`] or a [`SizeAndBaseline`]. Nodes that have a [`MeasureFunc`] are
+    /// measured with it instead.
+    ///
+    /// Relative lengths are resolved in the same way as [`Taffy::compute_layout`] (use
+    /// [`Taffy::compute_layout_with_context_and_measure`] to specify how). Subtrees are always laid out sequentially.
+    pub fn compute_layout_with_measure<Ctx, R: Into<SizeAndBaseline>>(
+        &mut self,
+        node: Node,
+        available_space: Size<AvailableSpace>,
+        context: &mut Ctx,
+        measure: impl FnMut(&mut Ctx, Node, Option<&mut T>, Size<Option<f32>>, Size<AvailableSpace>) -> R,
+    ) -> Result<(), TaffyError> {
+        let resolution_context = default_resolution_context(available_space);
+        self.compute_layout_with_context_and_measure(node, available_space, resolution_context, context, measure)
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, resolving relative lengths using the passed
+    /// [`ResolutionContext`] (as [`Taffy::compute_layout_with_context`] does) and measuring the nodes that have a context
+    /// with the passed `measure` function (as [`Taffy::compute_layout_with_measure`] does)
+    pub fn compute_layout_with_context_and_measure<Ctx, R: Into<SizeAndBaseline>>(
+        &mut self,
+        node: Node,
+        available_space: Size<AvailableSpace>,
+        resolution_context: ResolutionContext,
+        context: &mut Ctx,
+        measure: impl FnMut(&mut Ctx, Node, Option<&mut T>, Size<Option<f32>>, Size<AvailableSpace>) -> R,
+    ) -> Result<(), TaffyError> {
+        self.prepare_layout(&resolution_context)?;
+        crate::compute::compute_layout(&mut MeasureView { taffy: self, context, measure }, node, available_space)
+    }
+
+    /// Records whether the style of `node` uses relative lengths (which are resolved at the start of each layout) and
+    /// whether it is fixed position
+    fn track_style(&mut self, node: Node) {
+        let style = &self.nodes[node].style;
+        if style.position == Position::Fixed {
+            self.fixed_nodes.insert(node, ());
+        } else {
+            let _ = self.fixed_nodes.remove(node);
+        }
+
+        if style.uses_relative_units() {
+            self.relative_unit_nodes.insert(node, ());
+            self.resolution_context = None;
+        } else {
+            let _ = self.relative_unit_nodes.remove(node);
+            let _ = self.resolved_styles.remove(node);
+        }
+    }
+
+    /// Prepares the tree for a layout, resolving the relative lengths in the styles of the nodes that use them
+    ///
+    /// Nothing is resolved if neither the context nor the tree have changed in a way that affects them since the
+    /// previous layout.
+    fn prepare_layout(&mut self, context: &ResolutionContext) -> TaffyResult<()> {
+        self.changed_layouts.clear();
+        if self.resolution_context.as_ref() == Some(context) {
+            return Ok(());
+        }
+        self.resolution_context = Some(*context);
+
+        let nodes: Vec<Node> = self.relative_unit_nodes.keys().collect();
+        for node in nodes {
+            let font_size = self.font_size(node).unwrap_or(context.root_font_size);
+            let resolved_style = self.nodes[node].style.resolve_units(context, font_size);
+            if self.resolved_styles.get(node) != Some(&resolved_style) {
+                self.resolved_styles.insert(node, resolved_style);
+                self.mark_dirty_internal(node)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Collects the fixed position descendants of `node` that are displayed, ordered by depth, given the `fixed_nodes` of
+/// the tree
+///
+/// Only the fixed position nodes and their ancestors are visited, rather than the whole subtree of `node`.
+pub(crate) fn collect_fixed_nodes(
+    tree: &impl LayoutTree<NodeId = Node>,
+    fixed_nodes: &SparseSecondaryMap<Node, ()>,
+    node: Node,
+    fixed: &mut Vec<Node>,
+) {
+    let mut descendants: Vec<(usize, Node)> = Vec::new();
+    'fixed_nodes: for fixed_node in fixed_nodes.keys() {
+        let mut depth = 0;
+        let mut current = fixed_node;
+        while current != node {
+            if tree.style(current).display == Display::None {
+                continue 'fixed_nodes;
+            }
+            current = match tree.parent(current) {
+                Some(parent) => parent,
+                None => continue 'fixed_nodes,
+            };
+            depth += 1;
+        }
+        if depth > 0 {
+            descendants.push((depth, fixed_node));
+        }
+    }
+
+    descendants.sort_by_key(|(depth, _)| *depth);
+    fixed.extend(descendants.into_iter().map(|(_, node)| node));
+}
+
+/// The [`ResolutionContext`] used by [`Taffy::compute_layout`], which resolves viewport-relative lengths against the
+/// available space (or zero if it is indefinite)
+fn default_resolution_context(available_space: Size<AvailableSpace>) -> ResolutionContext {
+    ResolutionContext {
+        viewport_size: Size {
+            width: available_space.width.unwrap_or(0.0),
+            height: available_space.height.unwrap_or(0.0),
+        },
+        ..Default::default()
+    }
+}
+
+/// A view of a [`Taffy`] that measures the nodes that have a context with the measure function passed to
+/// [`Taffy::compute_layout_with_measure`]
+struct MeasureView<'t, T, Ctx, F> {
+    /// The tree being laid out
+    taffy: &'t mut Taffy<T>,
+    /// The context passed to each call of the measure function
+    context: &'t mut Ctx,
+    /// The measure function
+    measure: F,
+}
+
+impl<T, Ctx, F, R> LayoutTree for MeasureView<'_, T, Ctx, F>
+where
+    F: FnMut(&mut Ctx, Node, Option<&mut T>, Size<Option<f32>>, Size<AvailableSpace>) -> R,
+    R: Into<SizeAndBaseline>,
+{
+    type NodeId = Node;
+
+    type ChildIter<'a>
+        = <Taffy<T> as LayoutTree>::ChildIter<'a>
+    where
+        Self: 'a;
+
+    fn children(&self, node: Node) -> Self::ChildIter<'_> {
+        LayoutTree::children(self.taffy, node)
+    }
+
+    fn child_count(&self, node: Node) -> usize {
+        LayoutTree::child_count(self.taffy, node)
+    }
+
+    fn is_childless(&self, node: Node) -> bool {
+        LayoutTree::is_childless(self.taffy, node)
+    }
+
+    fn child(&self, node: Node, index: usize) -> Node {
+        LayoutTree::child(self.taffy, node, index)
+    }
+
+    fn parent(&self, node: Node) -> Option<Node> {
+        LayoutTree::parent(self.taffy, node)
+    }
+
+    fn style(&self, node: Node) -> &Style {
+        LayoutTree::style(self.taffy, node)
+    }
+
+    fn layout(&self, node: Node) -> &Layout {
+        LayoutTree::layout(self.taffy, node)
+    }
+
+    fn layout_mut(&mut self, node: Node) -> &mut Layout {
+        LayoutTree::layout_mut(self.taffy, node)
+    }
+
+    fn final_layout_mut(&mut self, node: Node) -> &mut Layout {
+        LayoutTree::final_layout_mut(self.taffy, node)
+    }
+
+    fn fixed_descendants(&self, node: Node, fixed: &mut Vec<Node>) {
+        collect_fixed_nodes(self, &self.taffy.fixed_nodes, node, fixed)
+    }
+
+    fn notify_layout_changed(&mut self, node: Node) {
+        LayoutTree::notify_layout_changed(self.taffy, node)
+    }
+
+    fn mark_dirty(&mut self, node: Node) -> TaffyResult<()> {
+        LayoutTree::mark_dirty(self.taffy, node)
+    }
+
+    fn measure_node(
+        &mut self,
+        node: Node,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+    ) -> SizeAndBaseline {
+        if let Some(measure) = self.taffy.measure_funcs.get(node) {
+            return measure.measure(known_dimensions, available_space);
+        }
+        let node_context = self.taffy.node_contexts.get_mut(node);
+        (self.measure)(self.context, node, node_context, known_dimensions, available_space).into()
+    }
+
+    fn needs_measure(&self, node: Node) -> bool {
+        LayoutTree::needs_measure(self.taffy, node) || self.taffy.node_contexts.contains_key(node)
+    }
+
+    /// Nodes with a context are measured by the passed measure function, which may return a baseline
+    fn can_measure_baseline(&self, node: Node) -> bool {
+        self.taffy.measure_funcs.get(node).is_none_or(MeasureFunc::reports_baseline)
+    }
+
+    fn cache_mut(&mut self, node: Node, index: usize) -> &mut Option<Cache> {
+        LayoutTree::cache_mut(self.taffy, node, index)
+    }
 }
 
 #[cfg(test)]
@@ -501,6 +998,27 @@ mod tests {
         assert_eq!(taffy.layout(node).unwrap().size.width, 100.0);
     }
 
+    #[test]
+    fn node_context() {
+        let mut taffy: Taffy<u32> = Taffy::default();
+        let node = taffy.new_leaf_with_context(Style::default(), 1).unwrap();
+        let other_node = taffy.new_leaf(Style::default()).unwrap();
+        assert_eq!(taffy.get_node_context(node), Some(&1));
+        assert_eq!(taffy.get_node_context(other_node), None);
+
+        *taffy.get_node_context_mut(node).unwrap() += 1;
+        assert_eq!(taffy.get_node_context(node), Some(&2));
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        taffy.set_node_context(node, None).unwrap();
+        assert_eq!(taffy.get_node_context(node), None);
+        assert!(taffy.dirty(node).unwrap());
+
+        taffy.set_node_context(other_node, Some(3)).unwrap();
+        taffy.remove(other_node).unwrap();
+        assert!(taffy.node_contexts.is_empty());
+    }
+
     /// Test that adding `add_child()` works
     #[test]
     fn add_child() {
@@ -0,0 +1,288 @@
+//! Concurrent layout of the independent subtrees of a [`Taffy`] tree
+//!
+//! A [`Taffy`] stores the [`LayoutData`] of its nodes (the only data written to during layout) separately from the rest
+//! of their data. This lets the layout data be split between the subtrees being laid out: each subtree is laid out in
+//! place on the `rayon` thread pool by a view of the tree that shares the rest of the tree but has exclusive access to
+//! the layout data of the subtree's nodes. The nodes whose layout changed, and the nodes outside of the subtree that were
+//! marked dirty, are recorded by each view and passed on to the tree once every subtree has been laid out.
+//!
+//! Subtrees are only distributed at a single level: the subtrees within them are laid out on the thread of their view.
+use rayon::prelude::*;
+use slotmap::{SlotMap, SparseSecondaryMap};
+
+use crate::compute::{compute_from_cache, compute_node_layout};
+use crate::data::{LayoutData, NodeData};
+use crate::error::TaffyResult;
+use crate::geometry::Size;
+use crate::layout::{Cache, Layout, LayoutInput, RunMode, SizeAndBaseline};
+use crate::node::{collect_fixed_nodes, MeasureFunc, Node, Taffy};
+use crate::style::{AvailableSpace, Style};
+use crate::sys::{ChildrenVec, Vec};
+use crate::tree::LayoutTree;
+
+/// The minimum number of nodes that the pending subtrees must contain in total for them to be laid out concurrently
+///
+/// Smaller subtrees are laid out faster on the current thread than they can be distributed to other threads.
+const PARALLEL_LAYOUT_THRESHOLD: usize = 256;
+
+/// The position of the [`LayoutData`] of a node in the [`SubtreeView`] that is laying it out
+#[derive(Copy, Clone)]
+pub(crate) struct SubtreeSlot {
+    /// The index of the subtree that the node belongs to
+    subtree: usize,
+    /// The index of the node's layout data in the view of the subtree
+    index: usize,
+}
+
+/// Lay out the subtrees of the children described by `inputs` concurrently, storing the results in their caches
+///
+/// Childless children, children whose layout is already cached, and all of the children if their subtrees are too small
+/// to benefit (see [`PARALLEL_LAYOUT_THRESHOLD`]) or the `rayon` thread pool has a single thread, are left to be laid
+/// out by the caller.
+pub(crate) fn prepare_child_layouts<T>(taffy: &mut Taffy<T>, inputs: &[LayoutInput<Node>]) {
+    if rayon::current_num_threads() < 2 {
+        return;
+    }
+    let pending: Vec<LayoutInput<Node>> = inputs
+        .iter()
+        .copied()
+        .filter(|input| {
+            !LayoutTree::is_childless(taffy, input.node)
+                && compute_from_cache(
+                    taffy,
+                    input.node,
+                    input.known_dimensions,
+                    input.available_space,
+                    true,
+                    input.sizing_mode,
+                )
+                .is_none()
+        })
+        .collect();
+    if pending.len() < 2 || !contains_at_least(taffy, &pending, PARALLEL_LAYOUT_THRESHOLD) {
+        return;
+    }
+
+    // Split the layout data of the tree between the subtrees, in the order of the nodes in the tree's storage
+    for (subtree, input) in pending.iter().enumerate() {
+        set_subtree_slots(&mut taffy.nodes, &taffy.children, input.node, Some(SubtreeSlot { subtree, index: 0 }));
+    }
+    let mut subtree_layouts: Vec<Vec<&mut LayoutData>> = pending.iter().map(|_| Vec::new()).collect();
+    for (node, layout_data) in taffy.layouts.iter_mut() {
+        if let Some(slot) = &mut taffy.nodes[node].subtree_slot {
+            slot.index = subtree_layouts[slot.subtree].len();
+            subtree_layouts[slot.subtree].push(layout_data);
+        }
+    }
+
+    let tree = SharedTree {
+        nodes: &taffy.nodes,
+        children: &taffy.children,
+        parents: &taffy.parents,
+        measure_funcs: &taffy.measure_funcs,
+        resolved_styles: &taffy.resolved_styles,
+        fixed_nodes: &taffy.fixed_nodes,
+        track_layout_changes: taffy.track_layout_changes,
+    };
+    let views: Vec<SubtreeView> = pending
+        .par_iter()
+        .zip(subtree_layouts)
+        .enumerate()
+        .map(|(subtree, (input, layouts))| {
+            let mut view =
+                SubtreeView { tree: &tree, subtree, layouts, changed_layouts: Vec::new(), dirty_ancestors: Vec::new() };
+            compute_node_layout(
+                &mut view,
+                input.node,
+                input.known_dimensions,
+                input.available_space,
+                RunMode::PeformLayout,
+                input.sizing_mode,
+            );
+            view
+        })
+        .collect();
+
+    let mut dirty_ancestors = Vec::new();
+    for view in views {
+        taffy.changed_layouts.extend(view.changed_layouts);
+        dirty_ancestors.extend(view.dirty_ancestors);
+    }
+    for input in &pending {
+        set_subtree_slots(&mut taffy.nodes, &taffy.children, input.node, None);
+    }
+    for node in dirty_ancestors {
+        let _ = LayoutTree::mark_dirty(taffy, node);
+    }
+}
+
+/// Whether the subtrees of the given nodes contain at least `threshold` nodes in total
+fn contains_at_least<T>(taffy: &Taffy<T>, inputs: &[LayoutInput<Node>], threshold: usize) -> bool {
+    let mut stack: Vec<Node> = inputs.iter().map(|input| input.node).collect();
+    let mut count = 0;
+    while let Some(node) = stack.pop() {
+        count += 1;
+        if count >= threshold {
+            return true;
+        }
+        stack.extend(LayoutTree::children(taffy, node));
+    }
+    false
+}
+
+/// Set the [`SubtreeSlot`] of every node in the subtree of `root`
+fn set_subtree_slots(
+    nodes: &mut SlotMap<Node, NodeData>,
+    children: &SlotMap<Node, ChildrenVec<Node>>,
+    root: Node,
+    slot: Option<SubtreeSlot>,
+) {
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        nodes[node].subtree_slot = slot;
+        stack.extend(children[node].iter().copied());
+    }
+}
+
+/// The parts of a [`Taffy`] that are shared by the views of its subtrees
+///
+/// Unlike the tree itself (whose node contexts may not be `Sync`), these can be shared between threads.
+struct SharedTree<'t> {
+    /// The data of each node
+    nodes: &'t SlotMap<Node, NodeData>,
+    /// The children of each node
+    children: &'t SlotMap<Node, ChildrenVec<Node>>,
+    /// The parent of each node
+    parents: &'t SlotMap<Node, Option<Node>>,
+    /// The measure function of each node that has one
+    measure_funcs: &'t SparseSecondaryMap<Node, MeasureFunc>,
+    /// The resolved style of each node that uses relative lengths
+    resolved_styles: &'t SparseSecondaryMap<Node, Style>,
+    /// The nodes whose styles are fixed position
+    fixed_nodes: &'t SparseSecondaryMap<Node, ()>,
+    /// Whether the nodes whose layout changes are recorded
+    track_layout_changes: bool,
+}
+
+/// A view of a [`Taffy`] that lays out one of its subtrees, with exclusive access to the layout data of the subtree
+struct SubtreeView<'t> {
+    /// The tree being laid out
+    tree: &'t SharedTree<'t>,
+    /// The index of the subtree, which identifies the [`SubtreeSlot`]s of its nodes
+    subtree: usize,
+    /// The layout data of the nodes of the subtree, indexed by [`SubtreeSlot::index`]
+    layouts: Vec<&'t mut LayoutData>,
+    /// The nodes whose layout has changed
+    changed_layouts: Vec<Node>,
+    /// The nodes outside of the subtree that have been marked dirty
+    dirty_ancestors: Vec<Node>,
+}
+
+impl SubtreeView<'_> {
+    /// The index of the layout data of the node in `layouts`, or `None` if it is outside of the subtree
+    fn index(&self, node: Node) -> Option<usize> {
+        self.tree.nodes[node].subtree_slot.filter(|slot| slot.subtree == self.subtree).map(|slot| slot.index)
+    }
+
+    /// The layout data of a node of the subtree
+    fn layout_data(&self, node: Node) -> &LayoutData {
+        let index = self.index(node).expect("only the nodes of a subtree are laid out by its view");
+        self.layouts[index]
+    }
+
+    /// The mutable layout data of a node of the subtree
+    fn layout_data_mut(&mut self, node: Node) -> &mut LayoutData {
+        let index = self.index(node).expect("only the nodes of a subtree are laid out by its view");
+        self.layouts[index]
+    }
+}
+
+impl LayoutTree for SubtreeView<'_> {
+    type NodeId = Node;
+
+    type ChildIter<'a>
+        = <Taffy as LayoutTree>::ChildIter<'a>
+    where
+        Self: 'a;
+
+    fn children(&self, node: Node) -> Self::ChildIter<'_> {
+        self.tree.children[node].iter().copied()
+    }
+
+    fn child_count(&self, node: Node) -> usize {
+        self.tree.children[node].len()
+    }
+
+    fn is_childless(&self, node: Node) -> bool {
+        self.tree.children[node].is_empty()
+    }
+
+    fn child(&self, node: Node, index: usize) -> Node {
+        self.tree.children[node][index]
+    }
+
+    fn parent(&self, node: Node) -> Option<Node> {
+        self.tree.parents.get(node).copied().flatten()
+    }
+
+    fn style(&self, node: Node) -> &Style {
+        self.tree.resolved_styles.get(node).unwrap_or(&self.tree.nodes[node].style)
+    }
+
+    fn fixed_descendants(&self, node: Node, fixed: &mut Vec<Node>) {
+        collect_fixed_nodes(self, self.tree.fixed_nodes, node, fixed)
+    }
+
+    fn layout(&self, node: Node) -> &Layout {
+        &self.layout_data(node).unrounded_layout
+    }
+
+    fn layout_mut(&mut self, node: Node) -> &mut Layout {
+        &mut self.layout_data_mut(node).unrounded_layout
+    }
+
+    fn final_layout_mut(&mut self, node: Node) -> &mut Layout {
+        &mut self.layout_data_mut(node).layout
+    }
+
+    fn notify_layout_changed(&mut self, node: Node) {
+        if self.tree.track_layout_changes {
+            self.changed_layouts.push(node);
+        }
+    }
+
+    /// Nodes outside of the subtree are marked dirty by the tree once the subtree has been laid out
+    fn mark_dirty(&mut self, node: Node) -> TaffyResult<()> {
+        let mut current = Some(node);
+        while let Some(node) = current {
+            if self.index(node).is_none() {
+                self.dirty_ancestors.push(node);
+                break;
+            }
+            self.layout_data_mut(node).mark_dirty();
+            current = self.parent(node);
+        }
+        Ok(())
+    }
+
+    fn measure_node(
+        &mut self,
+        node: Node,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+    ) -> SizeAndBaseline {
+        self.tree.measure_funcs[node].measure(known_dimensions, available_space)
+    }
+
+    fn needs_measure(&self, node: Node) -> bool {
+        self.tree.nodes[node].needs_measure && self.tree.measure_funcs.contains_key(node)
+    }
+
+    fn can_measure_baseline(&self, node: Node) -> bool {
+        self.tree.measure_funcs.get(node).is_some_and(MeasureFunc::reports_baseline)
+    }
+
+    fn cache_mut(&mut self, node: Node, index: usize) -> &mut Option<Cache> {
+        &mut self.layout_data_mut(node).size_cache[index]
+    }
+}
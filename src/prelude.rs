@@ -1,25 +1,34 @@
 //! Commonly used types
 
 pub use crate::{
-    compute::flexbox::compute as layout_flexbox,
-    geometry::{Line, Rect, Size},
-    layout::Layout,
+    compute::{compute_node_layout, flexbox::compute as layout_flexbox, leaf::compute as layout_leaf},
+    geometry::{Line, Point, Rect, Size},
+    layout::{Layout, RunMode, SizingMode},
     node::{Node, Taffy},
     style::{
-        AlignContent, AlignItems, AlignSelf, AvailableSpace, Dimension, Display, FlexDirection, FlexWrap,
-        JustifyContent, JustifyItems, JustifySelf, LengthPercentage, LengthPercentageAuto, Position, Style,
+        AlignContent, AlignItems, AlignSelf, AvailableSpace, CalcLength, Dimension, Direction, Display, FlexDirection,
+        FlexWrap, JustifyContent, JustifyItems, JustifySelf, LengthPercentage, LengthPercentageAuto, Overflow,
+        Position, ResolutionContext, Style, Visibility,
     },
     style_helpers::{
-        auto, fit_content, flex, max_content, min_content, minmax, percent, points, zero, FromFlex, FromPercent,
+        auto, calc, fit_content, flex, max_content, min_content, minmax, percent, points, zero, FromFlex, FromPercent,
         FromPoints, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
     },
     tree::LayoutTree,
 };
 
+#[cfg(feature = "block_layout")]
+pub use crate::compute::block::compute as layout_block;
+
+#[cfg(feature = "grid")]
+pub use crate::compute::grid::compute as layout_grid;
 #[cfg(feature = "grid")]
 pub use crate::style::{
-    GridAutoFlow, GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
-    NonRepeatedTrackSizingFunction, TrackSizingFunction,
+    GridAutoFlow, GridLineName, GridPlacement, GridTemplateArea, GridTrackRepetition, MaxTrackSizingFunction,
+    MinTrackSizingFunction, NonRepeatedTrackSizingFunction, TrackSizingFunction,
 };
 #[cfg(feature = "grid")]
-pub use crate::style_helpers::{line, repeat, span, TaffyGridLine, TaffyGridSpan};
+pub use crate::style_helpers::{
+    line, line_names, masonry, named_area, named_line, named_span, repeat, span, subgrid, TaffyGridLine,
+    TaffyGridNamedArea, TaffyGridNamedLine, TaffyGridNamedSpan, TaffyGridSpan,
+};
@@ -1,7 +1,14 @@
 //! Helper trait to calculate dimensions during layout resolution
 
 use crate::prelude::{Dimension, LengthPercentage, LengthPercentageAuto, Rect, Size};
+use crate::style::ResolutionContext;
 use crate::style_helpers::TaffyZero;
+#[cfg(feature = "grid")]
+use crate::{
+    geometry::MinMax,
+    style::{MaxTrackSizingFunction, MinTrackSizingFunction, TrackSizingFunction},
+    sys::GridTrackVec,
+};
 
 /// Trait to encapsulate behaviour where we need to resolve from a
 /// potentially context-dependent size or dimension into
@@ -23,6 +30,16 @@ pub(crate) trait ResolveOrZero<TContext, TOutput: TaffyZero> {
     fn resolve_or_zero(self, context: TContext) -> TOutput;
 }
 
+/// Trait to encapsulate behaviour where we need to resolve viewport-relative and
+/// font-relative lengths into points before layout.
+pub(crate) trait ResolveUnits: Sized {
+    /// Whether the value contains any viewport-relative or font-relative lengths
+    fn uses_relative_units(&self) -> bool;
+    /// Converts any viewport-relative and font-relative lengths into points, using the
+    /// passed context and the font size of the node the value belongs to
+    fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Self;
+}
+
 impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentage {
     /// Converts the given [`LengthPercentage`] into a concrete value of points
     /// Can return `None`
@@ -30,6 +47,11 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentage {
         match self {
             LengthPercentage::Points(points) => Some(points),
             LengthPercentage::Percent(percent) => context.map(|dim| dim * percent),
+            LengthPercentage::Calc(calc) => calc.maybe_resolve(context),
+            // Viewport-relative and font-relative lengths are resolved to points before layout
+            LengthPercentage::Vw(_) | LengthPercentage::Vh(_) | LengthPercentage::Em(_) | LengthPercentage::Rem(_) => {
+                None
+            }
         }
     }
 }
@@ -41,6 +63,12 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentageAuto {
         match self {
             LengthPercentageAuto::Points(points) => Some(points),
             LengthPercentageAuto::Percent(percent) => context.map(|dim| dim * percent),
+            LengthPercentageAuto::Calc(calc) => calc.maybe_resolve(context),
+            // Viewport-relative and font-relative lengths are resolved to points before layout
+            LengthPercentageAuto::Vw(_)
+            | LengthPercentageAuto::Vh(_)
+            | LengthPercentageAuto::Em(_)
+            | LengthPercentageAuto::Rem(_) => None,
             LengthPercentageAuto::Auto => None,
         }
     }
@@ -54,6 +82,9 @@ impl MaybeResolve<Option<f32>, Option<f32>> for Dimension {
         match self {
             Dimension::Points(points) => Some(points),
             Dimension::Percent(percent) => context.map(|dim| dim * percent),
+            Dimension::Calc(calc) => calc.maybe_resolve(context),
+            // Viewport-relative and font-relative lengths are resolved to points before layout
+            Dimension::Vw(_) | Dimension::Vh(_) | Dimension::Em(_) | Dimension::Rem(_) => None,
             Dimension::Auto => None,
         }
     }
@@ -132,12 +163,188 @@ impl<Out: TaffyZero, T: ResolveOrZero<Option<f32>, Out>> ResolveOrZero<Option<f3
     }
 }
 
+impl ResolveUnits for LengthPercentage {
+    fn uses_relative_units(&self) -> bool {
+        matches!(
+            self,
+            LengthPercentage::Vw(_) | LengthPercentage::Vh(_) | LengthPercentage::Em(_) | LengthPercentage::Rem(_)
+        )
+    }
+
+    fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Self {
+        match *self {
+            LengthPercentage::Vw(value) => LengthPercentage::Points(value * context.viewport_size.width),
+            LengthPercentage::Vh(value) => LengthPercentage::Points(value * context.viewport_size.height),
+            LengthPercentage::Em(value) => LengthPercentage::Points(value * font_size),
+            LengthPercentage::Rem(value) => LengthPercentage::Points(value * context.root_font_size),
+            other => other,
+        }
+    }
+}
+
+impl ResolveUnits for LengthPercentageAuto {
+    fn uses_relative_units(&self) -> bool {
+        matches!(
+            self,
+            LengthPercentageAuto::Vw(_)
+                | LengthPercentageAuto::Vh(_)
+                | LengthPercentageAuto::Em(_)
+                | LengthPercentageAuto::Rem(_)
+        )
+    }
+
+    fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Self {
+        match *self {
+            LengthPercentageAuto::Vw(value) => LengthPercentageAuto::Points(value * context.viewport_size.width),
+            LengthPercentageAuto::Vh(value) => LengthPercentageAuto::Points(value * context.viewport_size.height),
+            LengthPercentageAuto::Em(value) => LengthPercentageAuto::Points(value * font_size),
+            LengthPercentageAuto::Rem(value) => LengthPercentageAuto::Points(value * context.root_font_size),
+            other => other,
+        }
+    }
+}
+
+impl ResolveUnits for Dimension {
+    fn uses_relative_units(&self) -> bool {
+        matches!(self, Dimension::Vw(_) | Dimension::Vh(_) | Dimension::Em(_) | Dimension::Rem(_))
+    }
+
+    fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Self {
+        match *self {
+            Dimension::Vw(value) => Dimension::Points(value * context.viewport_size.width),
+            Dimension::Vh(value) => Dimension::Points(value * context.viewport_size.height),
+            Dimension::Em(value) => Dimension::Points(value * font_size),
+            Dimension::Rem(value) => Dimension::Points(value * context.root_font_size),
+            other => other,
+        }
+    }
+}
+
+// Generic ResolveUnits for Size
+impl<T: ResolveUnits> ResolveUnits for Size<T> {
+    fn uses_relative_units(&self) -> bool {
+        self.width.uses_relative_units() || self.height.uses_relative_units()
+    }
+
+    fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Self {
+        Size {
+            width: self.width.resolve_units(context, font_size),
+            height: self.height.resolve_units(context, font_size),
+        }
+    }
+}
+
+// Generic ResolveUnits for Rect
+impl<T: ResolveUnits> ResolveUnits for Rect<T> {
+    fn uses_relative_units(&self) -> bool {
+        self.left.uses_relative_units()
+            || self.right.uses_relative_units()
+            || self.top.uses_relative_units()
+            || self.bottom.uses_relative_units()
+    }
+
+    fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Self {
+        Rect {
+            left: self.left.resolve_units(context, font_size),
+            right: self.right.resolve_units(context, font_size),
+            top: self.top.resolve_units(context, font_size),
+            bottom: self.bottom.resolve_units(context, font_size),
+        }
+    }
+}
+
+#[cfg(feature = "grid")]
+impl ResolveUnits for MinTrackSizingFunction {
+    fn uses_relative_units(&self) -> bool {
+        matches!(self, MinTrackSizingFunction::Fixed(length) if length.uses_relative_units())
+    }
+
+    fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Self {
+        match self {
+            MinTrackSizingFunction::Fixed(length) => {
+                MinTrackSizingFunction::Fixed(length.resolve_units(context, font_size))
+            }
+            other => *other,
+        }
+    }
+}
+
+#[cfg(feature = "grid")]
+impl ResolveUnits for MaxTrackSizingFunction {
+    fn uses_relative_units(&self) -> bool {
+        match self {
+            MaxTrackSizingFunction::Fixed(length) | MaxTrackSizingFunction::FitContent(length) => {
+                length.uses_relative_units()
+            }
+            _ => false,
+        }
+    }
+
+    fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Self {
+        match self {
+            MaxTrackSizingFunction::Fixed(length) => {
+                MaxTrackSizingFunction::Fixed(length.resolve_units(context, font_size))
+            }
+            MaxTrackSizingFunction::FitContent(length) => {
+                MaxTrackSizingFunction::FitContent(length.resolve_units(context, font_size))
+            }
+            other => *other,
+        }
+    }
+}
+
+#[cfg(feature = "grid")]
+impl<Min: ResolveUnits, Max: ResolveUnits> ResolveUnits for MinMax<Min, Max> {
+    fn uses_relative_units(&self) -> bool {
+        self.min.uses_relative_units() || self.max.uses_relative_units()
+    }
+
+    fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Self {
+        MinMax { min: self.min.resolve_units(context, font_size), max: self.max.resolve_units(context, font_size) }
+    }
+}
+
+#[cfg(feature = "grid")]
+impl ResolveUnits for TrackSizingFunction {
+    fn uses_relative_units(&self) -> bool {
+        match self {
+            TrackSizingFunction::Single(track) => track.uses_relative_units(),
+            TrackSizingFunction::AutoRepeat(_, tracks) => tracks.uses_relative_units(),
+            TrackSizingFunction::LineNames(_) | TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry => false,
+        }
+    }
+
+    fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Self {
+        match self {
+            TrackSizingFunction::Single(track) => TrackSizingFunction::Single(track.resolve_units(context, font_size)),
+            TrackSizingFunction::AutoRepeat(repetition, tracks) => {
+                TrackSizingFunction::AutoRepeat(*repetition, tracks.resolve_units(context, font_size))
+            }
+            TrackSizingFunction::LineNames(names) => TrackSizingFunction::LineNames(names.clone()),
+            TrackSizingFunction::Subgrid => TrackSizingFunction::Subgrid,
+            TrackSizingFunction::Masonry => TrackSizingFunction::Masonry,
+        }
+    }
+}
+
+// Generic ResolveUnits for lists of grid tracks
+#[cfg(feature = "grid")]
+impl<T: ResolveUnits> ResolveUnits for GridTrackVec<T> {
+    fn uses_relative_units(&self) -> bool {
+        self.iter().any(|track| track.uses_relative_units())
+    }
+
+    fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Self {
+        self.iter().map(|track| track.resolve_units(context, font_size)).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod maybe_resolve_dimension {
 
         use crate::resolve::MaybeResolve;
-        use crate::style::Dimension;
+        use crate::style::{CalcLength, Dimension};
         use rstest::rstest;
 
         /// `Dimension::Auto` should always return `None`
@@ -178,6 +385,44 @@ mod tests {
         fn resolve_percent(#[case] input: Dimension, #[case] context: Option<f32>, #[case] expected: Option<f32>) {
             assert_eq!(input.maybe_resolve(context), expected);
         }
+
+        /// `Dimension::Calc` should return `None` if it has a percentage component and context is `None`.
+        /// Otherwise it should return `Some(f32)` where the f32 value is the points value plus
+        /// the percent * context value, clamped between the min and max bounds.
+        ///
+        /// The parent / context __should__ affect the outcome.
+        #[rstest]
+        #[case(Dimension::from(CalcLength::new(-10.0, 1.0)), None, None)]
+        #[case(Dimension::from(CalcLength::new(-10.0, 1.0)), Some(50.0), Some(40.0))]
+        #[case(Dimension::from(CalcLength::new(10.0, 0.0)), None, Some(10.0))]
+        #[case(Dimension::from(CalcLength::new(0.0, 0.5).at_most(20.0)), Some(50.0), Some(20.0))]
+        #[case(Dimension::from(CalcLength::new(0.0, 0.5).at_least(30.0)), Some(50.0), Some(30.0))]
+        #[case(Dimension::from(CalcLength::new(0.0, 0.5).clamp(30.0, 20.0)), Some(50.0), Some(30.0))]
+        fn resolve_calc(#[case] input: Dimension, #[case] context: Option<f32>, #[case] expected: Option<f32>) {
+            assert_eq!(input.maybe_resolve(context), expected);
+        }
+    }
+
+    mod resolve_units_dimension {
+        use crate::geometry::Size;
+        use crate::resolve::ResolveUnits;
+        use crate::style::{Dimension, ResolutionContext};
+        use rstest::rstest;
+
+        /// Viewport-relative and font-relative dimensions should resolve to `Dimension::Points`.
+        /// Other dimensions should be left unchanged.
+        #[rstest]
+        #[case(Dimension::Vw(0.5), Dimension::Points(100.0))]
+        #[case(Dimension::Vh(0.5), Dimension::Points(50.0))]
+        #[case(Dimension::Em(2.0), Dimension::Points(20.0))]
+        #[case(Dimension::Rem(2.0), Dimension::Points(32.0))]
+        #[case(Dimension::Percent(0.5), Dimension::Percent(0.5))]
+        #[case(Dimension::Auto, Dimension::Auto)]
+        fn resolve_units(#[case] input: Dimension, #[case] expected: Dimension) {
+            let context =
+                ResolutionContext { viewport_size: Size { width: 200.0, height: 100.0 }, root_font_size: 16.0 };
+            assert_eq!(input.resolve_units(&context, 10.0), expected);
+        }
     }
 
     mod maybe_resolve_size_dimension {
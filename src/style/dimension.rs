@@ -4,6 +4,70 @@ use crate::geometry::{Rect, Size};
 use crate::style_helpers::{FromPercent, FromPoints, TaffyAuto, TaffyMaxContent, TaffyMinContent, TaffyZero};
 use crate::sys::abs;
 
+/// A `calc()` length: the sum of a length in points and a percentage of the containing block, optionally
+/// clamped between a minimum and maximum number of points.
+///
+/// This covers the common CSS forms `calc(100% - 48px)`, `min(100%, 600px)`, `max(10%, 100px)` and
+/// `clamp(200px, 50%, 800px)`. The bounds of `min()`, `max()` and `clamp()` must be absolute lengths.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CalcLength {
+    /// The length in points that is added to the percentage
+    pub points: f32,
+    /// The percentage (as a fraction: 0.5 is 50%) of the value that this length is resolved against
+    pub percent: f32,
+    /// The value in points that this length will never resolve below
+    pub min: f32,
+    /// The value in points that this length will never resolve above
+    pub max: f32,
+}
+
+impl CalcLength {
+    /// Creates an unbounded `calc(percent + points)` length
+    #[must_use]
+    pub const fn new(points: f32, percent: f32) -> Self {
+        Self { points, percent, min: f32::NEG_INFINITY, max: f32::INFINITY }
+    }
+
+    /// Prevents the length from resolving below `min` points. Equivalent to CSS `max(self, min)`.
+    #[must_use]
+    pub fn at_least(self, min: f32) -> Self {
+        Self { min: self.min.max(min), ..self }
+    }
+
+    /// Prevents the length from resolving above `max` points. Equivalent to CSS `min(self, max)`.
+    #[must_use]
+    pub fn at_most(self, max: f32) -> Self {
+        Self { max: self.max.min(max), ..self }
+    }
+
+    /// Clamps the length between `min` and `max` points. Equivalent to CSS `clamp(min, self, max)`.
+    #[must_use]
+    pub fn clamp(self, min: f32, max: f32) -> Self {
+        self.at_least(min).at_most(max)
+    }
+
+    /// Whether resolving this length requires a value for percentages to be resolved against
+    pub(crate) fn uses_percentage(self) -> bool {
+        self.percent != 0.0
+    }
+
+    /// Resolve the length against the value that percentages are relative to.
+    /// As in CSS, the minimum wins if it is larger than the maximum.
+    pub(crate) fn resolve(self, context: f32) -> f32 {
+        (self.points + self.percent * context).min(self.max).max(self.min)
+    }
+
+    /// Resolve the length, returning `None` if it uses a percentage and `context` is `None`
+    pub(crate) fn maybe_resolve(self, context: Option<f32>) -> Option<f32> {
+        match context {
+            Some(context) => Some(self.resolve(context)),
+            None if !self.uses_percentage() => Some(self.resolve(0.0)),
+            None => None,
+        }
+    }
+}
+
 /// A unit of linear measurement
 ///
 /// This is commonly combined with [`Rect`], [`Point`](crate::geometry::Point) and [`Size<T>`].
@@ -15,6 +79,20 @@ pub enum LengthPercentage {
     Points(f32),
     /// The dimension is stored in percentage relative to the parent item.
     Percent(f32),
+    /// The dimension is a [`CalcLength`] combining points and a percentage relative to the parent item.
+    Calc(CalcLength),
+    /// The dimension is stored as a fraction of the width of the viewport (`0.5` is `50vw`)
+    ///
+    /// Resolved to points before layout using the [`ResolutionContext`] passed to [`Taffy::compute_layout_with_context`](crate::node::Taffy::compute_layout_with_context)
+    Vw(f32),
+    /// The dimension is stored as a fraction of the height of the viewport (`0.5` is `50vh`)
+    ///
+    /// Resolved to points before layout using the [`ResolutionContext`] passed to [`Taffy::compute_layout_with_context`](crate::node::Taffy::compute_layout_with_context)
+    Vh(f32),
+    /// The dimension is stored as a multiple of the font size of the node (see [`Style::font_size`](crate::style::Style::font_size))
+    Em(f32),
+    /// The dimension is stored as a multiple of the root font size of the [`ResolutionContext`]
+    Rem(f32),
 }
 impl TaffyZero for LengthPercentage {
     const ZERO: Self = Self::Points(0.0);
@@ -24,6 +102,11 @@ impl FromPoints for LengthPercentage {
         Self::Points(points.into())
     }
 }
+impl From<CalcLength> for LengthPercentage {
+    fn from(calc: CalcLength) -> Self {
+        Self::Calc(calc)
+    }
+}
 impl FromPercent for LengthPercentage {
     fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
         Self::Points(percent.into())
@@ -41,6 +124,20 @@ pub enum LengthPercentageAuto {
     Points(f32),
     /// The dimension is stored in percentage relative to the parent item.
     Percent(f32),
+    /// The dimension is a [`CalcLength`] combining points and a percentage relative to the parent item.
+    Calc(CalcLength),
+    /// The dimension is stored as a fraction of the width of the viewport (`0.5` is `50vw`)
+    ///
+    /// Resolved to points before layout using the [`ResolutionContext`] passed to [`Taffy::compute_layout_with_context`](crate::node::Taffy::compute_layout_with_context)
+    Vw(f32),
+    /// The dimension is stored as a fraction of the height of the viewport (`0.5` is `50vh`)
+    ///
+    /// Resolved to points before layout using the [`ResolutionContext`] passed to [`Taffy::compute_layout_with_context`](crate::node::Taffy::compute_layout_with_context)
+    Vh(f32),
+    /// The dimension is stored as a multiple of the font size of the node (see [`Style::font_size`](crate::style::Style::font_size))
+    Em(f32),
+    /// The dimension is stored as a multiple of the root font size of the [`ResolutionContext`]
+    Rem(f32),
     /// The dimension should be automatically computed
     Auto,
 }
@@ -55,6 +152,11 @@ impl FromPoints for LengthPercentageAuto {
         Self::Points(points.into())
     }
 }
+impl From<CalcLength> for LengthPercentageAuto {
+    fn from(calc: CalcLength) -> Self {
+        Self::Calc(calc)
+    }
+}
 impl FromPercent for LengthPercentageAuto {
     fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
         Self::Points(percent.into())
@@ -66,6 +168,11 @@ impl From<LengthPercentage> for LengthPercentageAuto {
         match input {
             LengthPercentage::Points(value) => Self::Points(value),
             LengthPercentage::Percent(value) => Self::Percent(value),
+            LengthPercentage::Calc(calc) => Self::Calc(calc),
+            LengthPercentage::Vw(value) => Self::Vw(value),
+            LengthPercentage::Vh(value) => Self::Vh(value),
+            LengthPercentage::Em(value) => Self::Em(value),
+            LengthPercentage::Rem(value) => Self::Rem(value),
         }
     }
 }
@@ -73,13 +180,14 @@ impl From<LengthPercentage> for LengthPercentageAuto {
 impl LengthPercentageAuto {
     /// Returns:
     ///   - Some(points) for Points variants
-    ///   - Some(resolved) using the provided context for Percent variants
-    ///   - None for Auto variants
+    ///   - Some(resolved) using the provided context for Percent and Calc variants
+    ///   - None for Auto variants (and for viewport-relative and font-relative variants, which are resolved before layout)
     pub fn resolve_to_option(self, context: f32) -> Option<f32> {
         match self {
             Self::Points(points) => Some(points),
             Self::Percent(percent) => Some(context * percent),
-            Self::Auto => None,
+            Self::Calc(calc) => Some(calc.resolve(context)),
+            Self::Vw(_) | Self::Vh(_) | Self::Em(_) | Self::Rem(_) | Self::Auto => None,
         }
     }
 }
@@ -95,6 +203,20 @@ pub enum Dimension {
     Points(f32),
     /// The dimension is stored in percentage relative to the parent item.
     Percent(f32),
+    /// The dimension is a [`CalcLength`] combining points and a percentage relative to the parent item.
+    Calc(CalcLength),
+    /// The dimension is stored as a fraction of the width of the viewport (`0.5` is `50vw`)
+    ///
+    /// Resolved to points before layout using the [`ResolutionContext`] passed to [`Taffy::compute_layout_with_context`](crate::node::Taffy::compute_layout_with_context)
+    Vw(f32),
+    /// The dimension is stored as a fraction of the height of the viewport (`0.5` is `50vh`)
+    ///
+    /// Resolved to points before layout using the [`ResolutionContext`] passed to [`Taffy::compute_layout_with_context`](crate::node::Taffy::compute_layout_with_context)
+    Vh(f32),
+    /// The dimension is stored as a multiple of the font size of the node (see [`Style::font_size`](crate::style::Style::font_size))
+    Em(f32),
+    /// The dimension is stored as a multiple of the root font size of the [`ResolutionContext`]
+    Rem(f32),
     /// The dimension should be automatically computed
     Auto,
 }
@@ -109,6 +231,11 @@ impl FromPoints for Dimension {
         Self::Points(points.into())
     }
 }
+impl From<CalcLength> for Dimension {
+    fn from(calc: CalcLength) -> Self {
+        Self::Calc(calc)
+    }
+}
 impl FromPercent for Dimension {
     fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
         Self::Points(percent.into())
@@ -120,6 +247,11 @@ impl From<LengthPercentage> for Dimension {
         match input {
             LengthPercentage::Points(value) => Self::Points(value),
             LengthPercentage::Percent(value) => Self::Percent(value),
+            LengthPercentage::Calc(calc) => Self::Calc(calc),
+            LengthPercentage::Vw(value) => Self::Vw(value),
+            LengthPercentage::Vh(value) => Self::Vh(value),
+            LengthPercentage::Em(value) => Self::Em(value),
+            LengthPercentage::Rem(value) => Self::Rem(value),
         }
     }
 }
@@ -129,6 +261,11 @@ impl From<LengthPercentageAuto> for Dimension {
         match input {
             LengthPercentageAuto::Points(value) => Self::Points(value),
             LengthPercentageAuto::Percent(value) => Self::Percent(value),
+            LengthPercentageAuto::Calc(calc) => Self::Calc(calc),
+            LengthPercentageAuto::Vw(value) => Self::Vw(value),
+            LengthPercentageAuto::Vh(value) => Self::Vh(value),
+            LengthPercentageAuto::Em(value) => Self::Em(value),
+            LengthPercentageAuto::Rem(value) => Self::Rem(value),
             LengthPercentageAuto::Auto => Self::Auto,
         }
     }
@@ -137,7 +274,7 @@ impl From<LengthPercentageAuto> for Dimension {
 impl Dimension {
     /// Is this value defined?
     pub(crate) fn is_defined(self) -> bool {
-        matches!(self, Dimension::Points(_) | Dimension::Percent(_))
+        !matches!(self, Dimension::Auto)
     }
 
     /// Get Points value if value is Points variant
@@ -174,6 +311,28 @@ impl Rect<Dimension> {
     }
 }
 
+/// The values that viewport-relative (`vw`, `vh`) and font-relative (`em`, `rem`) lengths are resolved against
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ResolutionContext {
+    /// The size of the viewport (in points) that `Vw` and `Vh` lengths are relative to
+    pub viewport_size: Size<f32>,
+    /// The font size (in points) that `Rem` lengths are relative to. Also used as the font size of the root node if it
+    /// doesn't set a [`Style::font_size`](crate::style::Style::font_size).
+    pub root_font_size: f32,
+}
+
+impl ResolutionContext {
+    /// The font size used by browsers when no font size is specified
+    pub const DEFAULT_FONT_SIZE: f32 = 16.0;
+}
+
+impl Default for ResolutionContext {
+    fn default() -> Self {
+        Self { viewport_size: Size::ZERO, root_font_size: Self::DEFAULT_FONT_SIZE }
+    }
+}
+
 /// The amount of space available to a node in a given axis
 /// https://www.w3.org/TR/css-sizing-3/#available
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -1,5 +1,5 @@
 //! Style types for CSS Grid layout
-use super::{AlignContent, LengthPercentage, Style};
+use super::{AlignContent, CalcLength, LengthPercentage, Style};
 use crate::axis::{AbsoluteAxis, AbstractAxis};
 use crate::geometry::{Line, MinMax};
 use crate::style::AvailableSpace;
@@ -53,9 +53,12 @@ impl GridAutoFlow {
     }
 }
 
-/// A grid line placement specification. Used for grid-[row/column]-[start/end]. Named tracks are not implemented.
+/// A grid line placement specification. Used for grid-[row/column]-[start/end].
 ///
-/// Defaults to [`GridLine::Auto`]
+/// Named lines and areas are referred to by a [`GridLineName`]. Names are chosen by the user: the same name must be used
+/// wherever it appears in the grid container's line names (see [`TrackSizingFunction::LineNames`]) and `grid_template_areas`.
+///
+/// Defaults to [`GridPlacement::Auto`]
 ///
 /// [Specification](https://www.w3.org/TR/css3-grid-layout/#typedef-grid-row-start-grid-line)
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -67,6 +70,15 @@ pub enum GridPlacement {
     Line(i16),
     /// Item should span specified number of tracks (columns or rows)
     Span(u16),
+    /// Place item at the nth line (column or row) with the specified name. Negative indexes count backwards from the end of the grid.
+    /// If there are fewer than n lines with the name then all implicit lines are assumed to have that name.
+    NamedLine(GridLineName, i16),
+    /// Item should span until the nth line (column or row) with the specified name in the direction of the span
+    NamedSpan(GridLineName, u16),
+    /// Place item at the edge of the named area: the first `<name>-start` line when used as a start position, and the first
+    /// `<name>-end` line when used as an end position. These lines are named implicitly by a named area, or explicitly (in which
+    /// case they define an implicit named area). If there is no line with the name then this is equivalent to `NamedLine(name, 1)`.
+    NamedArea(GridLineName),
 }
 impl TaffyAuto for GridPlacement {
     const AUTO: Self = Self::Auto;
@@ -95,6 +107,37 @@ impl TaffyGridSpan for Line<GridPlacement> {
     }
 }
 
+impl TaffyGridNamedLine for GridPlacement {
+    fn from_named_line(name: GridLineName, index: i16) -> Self {
+        GridPlacement::NamedLine(name, index)
+    }
+}
+impl TaffyGridNamedLine for Line<GridPlacement> {
+    fn from_named_line(name: GridLineName, index: i16) -> Self {
+        Line { start: GridPlacement::from_named_line(name, index), end: GridPlacement::Auto }
+    }
+}
+impl TaffyGridNamedSpan for GridPlacement {
+    fn from_named_span(name: GridLineName, span: u16) -> Self {
+        GridPlacement::NamedSpan(name, span)
+    }
+}
+impl TaffyGridNamedSpan for Line<GridPlacement> {
+    fn from_named_span(name: GridLineName, span: u16) -> Self {
+        Line { start: GridPlacement::from_named_span(name, span), end: GridPlacement::Auto }
+    }
+}
+impl TaffyGridNamedArea for GridPlacement {
+    fn from_named_area(name: GridLineName) -> Self {
+        GridPlacement::NamedArea(name)
+    }
+}
+impl TaffyGridNamedArea for Line<GridPlacement> {
+    /// Places the item within the named area (equivalent to CSS `grid-row: name` / `grid-column: name`)
+    fn from_named_area(name: GridLineName) -> Self {
+        Line { start: GridPlacement::NamedArea(name), end: GridPlacement::NamedArea(name) }
+    }
+}
 impl Default for GridPlacement {
     fn default() -> Self {
         Self::Auto
@@ -106,9 +149,18 @@ impl GridPlacement {
     pub fn map_track(&self, map_fn: impl FnOnce(i16) -> i16) -> Self {
         use GridPlacement::*;
         match *self {
-            Auto => Auto,
-            Span(span) => Span(span),
             Line(track) => Line(map_fn(track)),
+            placement => placement,
+        }
+    }
+
+    /// Whether the placement refers to a specific grid line (either by index or by name)
+    #[inline]
+    pub fn is_line(&self) -> bool {
+        match *self {
+            Self::Line(_) | Self::NamedArea(_) => true,
+            Self::NamedLine(_, index) => index != 0,
+            Self::Auto | Self::Span(_) | Self::NamedSpan(_, _) => false,
         }
     }
 }
@@ -118,16 +170,17 @@ impl Line<GridPlacement> {
     /// Whether the track position is definite in this axis (or the item will need auto placement)
     /// The track position is definite if least one of the start and end positions is a track index
     pub fn is_definite(&self) -> bool {
-        use GridPlacement::*;
-        matches!((self.start, self.end), (Line(_), _) | (_, Line(_)))
+        self.start.is_line() || self.end.is_line()
     }
 
     /// If at least one of the of the start and end positions is a track index then the other end can be resolved
     /// into a track index purely based on the information contained with the placement specification
+    ///
+    /// The placement must be in origin-zero coordinates (in which 0 is a valid line index)
     pub fn resolve_definite_grid_tracks(&self) -> Line<i16> {
         use GridPlacement as GP;
         match (self.start, self.end) {
-            (GP::Line(track1), GP::Line(track2)) if track1 != 0 && track2 != 0 => {
+            (GP::Line(track1), GP::Line(track2)) => {
                 if track1 == track2 {
                     Line { start: track1, end: track1 + 1 }
                 } else {
@@ -135,9 +188,9 @@ impl Line<GridPlacement> {
                 }
             }
             (GP::Line(track), GP::Span(span)) => Line { start: track, end: track + span as i16 },
-            (GP::Line(track), GP::Auto | GP::Line(0)) => Line { start: track, end: track + 1_i16 },
+            (GP::Line(track), GP::Auto) => Line { start: track, end: track + 1_i16 },
             (GP::Span(span), GP::Line(track)) => Line { start: track - span as i16, end: track },
-            (GP::Auto | GP::Line(0), GP::Line(track)) => Line { start: track - 1_i16, end: track },
+            (GP::Auto, GP::Line(track)) => Line { start: track - 1_i16, end: track },
             _ => panic!("resolve_definite_grid_tracks should only be called on definite grid tracks"),
         }
     }
@@ -196,6 +249,7 @@ impl Line<GridPlacement> {
             (GP::Auto, GP::Span(span)) => span,
             (GP::Span(span), GP::Span(_)) => span,
             (GP::Line(_), GP::Line(_)) => panic!("indefinite_span should only be called on indefinite grid tracks"),
+            _ => panic!("indefinite_span should only be called once named lines have been resolved"),
         }
     }
 }
@@ -251,6 +305,11 @@ impl FromPoints for MaxTrackSizingFunction {
         Self::Fixed(LengthPercentage::from_points(points))
     }
 }
+impl From<CalcLength> for MaxTrackSizingFunction {
+    fn from(calc: CalcLength) -> Self {
+        Self::Fixed(LengthPercentage::Calc(calc))
+    }
+}
 impl FromPercent for MaxTrackSizingFunction {
     fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
         Self::Fixed(LengthPercentage::from_percent(percent))
@@ -295,6 +354,10 @@ impl MaxTrackSizingFunction {
                 AvailableSpace::Definite(available_size) => Some(fraction * available_size),
                 _ => None,
             },
+            Fixed(LengthPercentage::Calc(calc)) => calc.maybe_resolve(available_space.into_option()),
+            Fixed(
+                LengthPercentage::Vw(_) | LengthPercentage::Vh(_) | LengthPercentage::Em(_) | LengthPercentage::Rem(_),
+            ) => None,
             MinContent | MaxContent | FitContent(_) | Auto | Flex(_) => None,
         }
     }
@@ -333,6 +396,11 @@ impl FromPoints for MinTrackSizingFunction {
         Self::Fixed(LengthPercentage::from_points(points))
     }
 }
+impl From<CalcLength> for MinTrackSizingFunction {
+    fn from(calc: CalcLength) -> Self {
+        Self::Fixed(LengthPercentage::Calc(calc))
+    }
+}
 impl FromPercent for MinTrackSizingFunction {
     fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
         Self::Fixed(LengthPercentage::from_percent(percent))
@@ -352,6 +420,10 @@ impl MinTrackSizingFunction {
                 AvailableSpace::Definite(available_size) => Some(fraction * available_size),
                 _ => None,
             },
+            Fixed(LengthPercentage::Calc(calc)) => calc.maybe_resolve(available_space.into_option()),
+            Fixed(
+                LengthPercentage::Vw(_) | LengthPercentage::Vh(_) | LengthPercentage::Em(_) | LengthPercentage::Rem(_),
+            ) => None,
             MinContent | MaxContent | Auto => None,
         }
     }
@@ -399,6 +471,11 @@ impl FromPoints for NonRepeatedTrackSizingFunction {
         Self { min: MinTrackSizingFunction::from_points(points), max: MaxTrackSizingFunction::from_points(points) }
     }
 }
+impl From<CalcLength> for NonRepeatedTrackSizingFunction {
+    fn from(calc: CalcLength) -> Self {
+        Self { min: MinTrackSizingFunction::from(calc), max: MaxTrackSizingFunction::from(calc) }
+    }
+}
 impl FromPercent for NonRepeatedTrackSizingFunction {
     fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
         Self { min: MinTrackSizingFunction::from_percent(percent), max: MaxTrackSizingFunction::from_percent(percent) }
@@ -410,7 +487,8 @@ impl FromFlex for NonRepeatedTrackSizingFunction {
     }
 }
 
-/// The first argument to a repeated track definition. This type represents the type of automatic repetition to perform.
+/// The first argument to a repeated track definition. This type represents the number of repetitions to generate: either
+/// a fixed count, or the type of automatic repetition to perform.
 ///
 /// See https://www.w3.org/TR/css-grid-1/#auto-repeat for an explanation of how auto-repeated track definitions work
 /// and the difference between AutoFit and AutoFill.
@@ -422,6 +500,14 @@ pub enum GridTrackRepetition {
     /// Auto-repeating track should be generated to fit the container
     /// See: https://developer.mozilla.org/en-US/docs/Web/CSS/repeat#auto-fit
     AutoFit,
+    /// The specified tracks should be repeated exactly N times. A count of zero generates no tracks.
+    /// See: <https://developer.mozilla.org/en-US/docs/Web/CSS/repeat#integer>
+    Count(u16),
+}
+impl From<u16> for GridTrackRepetition {
+    fn from(count: u16) -> Self {
+        Self::Count(count)
+    }
 }
 
 /// The sizing function for a grid track (row/column)
@@ -430,14 +516,50 @@ pub enum GridTrackRepetition {
 pub enum TrackSizingFunction {
     /// A single non-repeated track
     Single(NonRepeatedTrackSizingFunction),
-    /// Automatically generate grid tracks to fit the available space using the specified definite track lengths
-    /// Only valid if every track in template (not just the repitition) has a fixed size.
+    /// Repeat the specified tracks either a fixed number of times (`GridTrackRepetition::Count`), or automatically to fit
+    /// the available space using their definite track lengths (`AutoFill` / `AutoFit`). Automatic repetition is only
+    /// valid if every track in template (not just the repitition) has a fixed size, and if the template contains no
+    /// other automatic repetition.
     AutoRepeat(GridTrackRepetition, GridTrackVec<NonRepeatedTrackSizingFunction>),
+    /// Names for the grid line at this position in the track list (between the tracks before and after it).
+    /// Does not generate a track. Equivalent to the CSS `[name1 name2]` syntax.
+    LineNames(GridTrackVec<GridLineName>),
+    /// Use the tracks that the grid spans in its parent grid, and lay its children out in them. The parent grid sizes
+    /// those tracks taking the contributions of the subgrid's children into account. Equivalent to the CSS `subgrid`
+    /// keyword, and only valid as the first entry of a template (any other entries are ignored).
+    ///
+    /// A grid whose parent is not a grid, or which is absolutely positioned, is not a subgrid: it has no explicit
+    /// tracks in an axis with this template.
+    Subgrid,
+    /// Make this axis the masonry axis of the grid (equivalent to the CSS `masonry` keyword). Instead of being placed
+    /// in tracks, the items are stacked along this axis in the tracks of the other axis (the grid axis): each item is
+    /// placed in the tracks in which it is closest to the start of the container. Only valid as the first entry of a
+    /// template (any other entries are ignored).
+    ///
+    /// If both templates are `masonry` then the rows are the masonry axis and the columns have no explicit tracks.
+    /// Subgrids are never masonry containers: they have no explicit tracks in an axis with this template.
+    Masonry,
 }
 impl TrackSizingFunction {
-    /// Whether the track definition is a auto-repeated fragment
+    /// Whether the track definition is a auto-repeated fragment (`auto-fill` or `auto-fit`, but not a fixed count of
+    /// repetitions)
     pub fn is_auto_repetition(&self) -> bool {
-        matches!(self, Self::AutoRepeat(_, _))
+        matches!(self, Self::AutoRepeat(GridTrackRepetition::AutoFill | GridTrackRepetition::AutoFit, _))
+    }
+
+    /// Whether the track definition is a list of line names (and therefore does not generate a track)
+    pub fn is_line_names(&self) -> bool {
+        matches!(self, Self::LineNames(_))
+    }
+
+    /// Whether the track definition is the `subgrid` keyword
+    pub fn is_subgrid(&self) -> bool {
+        matches!(self, Self::Subgrid)
+    }
+
+    /// Whether the track definition is the `masonry` keyword
+    pub fn is_masonry(&self) -> bool {
+        matches!(self, Self::Masonry)
     }
 }
 impl TaffyAuto for TrackSizingFunction {
@@ -462,6 +584,11 @@ impl FromPoints for TrackSizingFunction {
         Self::Single(NonRepeatedTrackSizingFunction::from_points(points))
     }
 }
+impl From<CalcLength> for TrackSizingFunction {
+    fn from(calc: CalcLength) -> Self {
+        Self::Single(NonRepeatedTrackSizingFunction::from(calc))
+    }
+}
 impl FromPercent for TrackSizingFunction {
     fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
         Self::Single(NonRepeatedTrackSizingFunction::from_percent(percent))
@@ -478,6 +605,79 @@ impl From<MinMax<MinTrackSizingFunction, MaxTrackSizingFunction>> for TrackSizin
     }
 }
 
+/// The name of a grid line or a grid area, identified by a user-chosen id
+///
+/// Ids must be less than `0x4000` (see [`GridLineName::new`]), as the names of the `<name>-start` and `<name>-end` lines
+/// of each name are derived from it (see [`GridTemplateArea::start_line_name`] and [`GridTemplateArea::end_line_name`]).
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridLineName(u16);
+
+impl GridLineName {
+    /// The largest id that can be used for a name
+    pub const MAX_ID: u16 = START_LINE_NAME_FLAG - 1;
+
+    /// Create the name with the specified id, or `None` if the id is greater than [`GridLineName::MAX_ID`]
+    pub const fn new(id: u16) -> Option<Self> {
+        match id <= Self::MAX_ID {
+            true => Some(Self(id)),
+            false => None,
+        }
+    }
+
+    /// The id of the name. The ids of `<name>-start` and `<name>-end` line names have a flag set above [`GridLineName::MAX_ID`].
+    pub const fn id(self) -> u16 {
+        self.0
+    }
+}
+
+/// A named grid area. The area covers the tracks between the specified start and end lines (1-based) in each axis.
+///
+/// The area implicitly names its start and end lines `<name>-start` and `<name>-end`. These can be referred to using
+/// [`GridPlacement::NamedArea`], or as named lines using the names returned by [`GridTemplateArea::start_line_name`] and
+/// [`GridTemplateArea::end_line_name`]. Conversely, explicitly naming lines with these ids defines an implicit named area.
+///
+/// See https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-areas
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridTemplateArea {
+    /// The name of the area
+    pub name: GridLineName,
+    /// The line at which the area starts in the block (row) axis
+    pub row_start: u16,
+    /// The line at which the area ends in the block (row) axis
+    pub row_end: u16,
+    /// The line at which the area starts in the inline (column) axis
+    pub column_start: u16,
+    /// The line at which the area ends in the inline (column) axis
+    pub column_end: u16,
+}
+
+/// The bit set in the id of the `<name>-start` line name of a name
+const START_LINE_NAME_FLAG: u16 = 0x4000;
+/// The bit set in the id of the `<name>-end` line name of a name
+const END_LINE_NAME_FLAG: u16 = 0x8000;
+
+impl GridTemplateArea {
+    /// The `<name>-start` line name for the name `name`
+    pub const fn start_line_name(name: GridLineName) -> GridLineName {
+        GridLineName(name.0 | START_LINE_NAME_FLAG)
+    }
+
+    /// The `<name>-end` line name for the name `name`
+    pub const fn end_line_name(name: GridLineName) -> GridLineName {
+        GridLineName(name.0 | END_LINE_NAME_FLAG)
+    }
+
+    /// Get the start and end lines of the area in the axis passed
+    pub(crate) fn lines(&self, axis: AbsoluteAxis) -> Line<u16> {
+        match axis {
+            AbsoluteAxis::Horizontal => Line { start: self.column_start, end: self.column_end },
+            AbsoluteAxis::Vertical => Line { start: self.row_start, end: self.row_end },
+        }
+    }
+}
+
 // Grid extensions to the Style struct
 impl Style {
     /// Get a grid item's row or column placement depending on the axis passed
@@ -488,6 +688,20 @@ impl Style {
         }
     }
 
+    /// Whether the grid template in the axis passed is `subgrid`
+    ///
+    /// Note: this doesn't check whether the node is a grid item (and can therefore actually be a subgrid)
+    pub(crate) fn is_subgrid(&self, axis: AbsoluteAxis) -> bool {
+        self.grid_template_tracks(axis).first().is_some_and(TrackSizingFunction::is_subgrid)
+    }
+
+    /// The masonry axis of a grid container: the axis whose grid template is `masonry` (the rows if both are)
+    pub(crate) fn masonry_axis(&self) -> Option<AbsoluteAxis> {
+        [AbsoluteAxis::Vertical, AbsoluteAxis::Horizontal]
+            .into_iter()
+            .find(|&axis| self.grid_template_tracks(axis).first().is_some_and(TrackSizingFunction::is_masonry))
+    }
+
     /// Get a grid item's row or column placement depending on the axis passed
     pub(crate) fn grid_placement(&self, axis: AbsoluteAxis) -> Line<GridPlacement> {
         match axis {
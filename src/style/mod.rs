@@ -4,17 +4,20 @@ mod dimension;
 mod flex;
 
 pub use self::alignment::{AlignContent, AlignItems, AlignSelf, JustifyContent, JustifyItems, JustifySelf};
-pub use self::dimension::{AvailableSpace, Dimension, LengthPercentage, LengthPercentageAuto};
+pub use self::dimension::{
+    AvailableSpace, CalcLength, Dimension, LengthPercentage, LengthPercentageAuto, ResolutionContext,
+};
 pub use self::flex::{FlexDirection, FlexWrap};
 
 #[cfg(feature = "grid")]
 mod grid;
 #[cfg(feature = "grid")]
 pub use self::grid::{
-    GridAutoFlow, GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
-    NonRepeatedTrackSizingFunction, TrackSizingFunction,
+    GridAutoFlow, GridLineName, GridPlacement, GridTemplateArea, GridTrackRepetition, MaxTrackSizingFunction,
+    MinTrackSizingFunction, NonRepeatedTrackSizingFunction, TrackSizingFunction,
 };
-use crate::geometry::{Rect, Size};
+use crate::geometry::{Point, Rect, Size};
+use crate::resolve::ResolveUnits;
 
 #[cfg(feature = "grid")]
 use crate::geometry::Line;
@@ -27,6 +30,9 @@ use crate::sys::GridTrackVec;
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Display {
+    /// The children will follow the block layout algorithm
+    #[cfg(feature = "block_layout")]
+    Block,
     /// The children will follow the flexbox layout algorithm
     Flex,
     /// The children will follow the CSS Grid layout algorithm
@@ -34,6 +40,8 @@ pub enum Display {
     Grid,
     /// The children will not be laid out, and will follow absolute positioning
     None,
+    /// The children will be laid out by the tree itself, using [`LayoutTree::compute_custom_layout`](crate::tree::LayoutTree::compute_custom_layout)
+    Custom,
 }
 
 impl Default for Display {
@@ -63,6 +71,18 @@ pub enum Position {
     ///
     /// WARNING: to opt-out of layouting entirely, you must use [`Display::None`] instead on your [`Style`] object.
     Absolute,
+    /// The item is laid out in flow, and the `inset` has no effect.
+    ///
+    /// Unlike every other positioning strategy, static items are not "positioned": they do not act as the containing block
+    /// of their absolutely positioned descendants. Those descendants are instead positioned relative to the closest
+    /// positioned ancestor of the static item.
+    Static,
+    /// Like [`Position::Absolute`], except that the offset is always computed relative to the root node (which acts as the viewport),
+    /// regardless of any positioned ancestors.
+    Fixed,
+    /// The item is laid out in flow, like a static item. The `inset` defines how far the item may be shifted when its nearest
+    /// scrolling ancestor is scrolled, which depends on the scroll position and is not applied by Taffy.
+    Sticky,
 }
 
 impl Default for Position {
@@ -71,6 +91,137 @@ impl Default for Position {
     }
 }
 
+impl Position {
+    /// Returns true for positioning strategies that take the item out of flow (`Position::Absolute`, `Position::Fixed`)
+    #[inline(always)]
+    pub(crate) fn is_out_of_flow(self) -> bool {
+        matches!(self, Self::Absolute | Self::Fixed)
+    }
+
+    /// Returns true for positioning strategies that make the item the containing block of its absolutely positioned
+    /// descendants (everything except `Position::Static`)
+    #[inline(always)]
+    pub(crate) fn is_positioned(self) -> bool {
+        self != Self::Static
+    }
+}
+
+/// How children overflowing their container should affect layout
+///
+/// In CSS the primary effect of this property is to control whether contents of a parent container that overflow that container should
+/// be displayed anyway, be clipped, or trigger the container to become a scroll container. However it also has secondary effects on layout,
+/// the main ones being:
+///
+///   - The automatic minimum size Flexbox/CSS Grid items with non-`Visible` overflow is `0` rather than being content based
+///   - `Overflow::Scroll` nodes have space in the layout reserved for a scrollbar (width controlled by the `scrollbar_width` property)
+///
+/// In Taffy, we only implement the layout related secondary effects as we are not concerned with drawing/painting. The amount of space reserved for
+/// a scrollbar is controlled by the `scrollbar_width` property. If this is `0` then `Scroll` behaves identically to `Hidden`.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/overflow>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Overflow {
+    /// The automatic minimum size of this node as a flexbox/grid item should be based on the size of its content.
+    Visible,
+    /// The automatic minimum size of this node as a flexbox/grid item should be based on the size of its content.
+    /// Content that overflows this node should be clipped, but this does not make the node a scroll container.
+    Clip,
+    /// The automatic minimum size of this node as a flexbox/grid item should be `0`.
+    Hidden,
+    /// The automatic minimum size of this node as a flexbox/grid item should be `0`. Additionally, space should be reserved
+    /// for a scrollbar. The amount of space reserved is controlled by the `scrollbar_width` property.
+    Scroll,
+}
+
+impl Default for Overflow {
+    fn default() -> Self {
+        Self::Visible
+    }
+}
+
+impl Overflow {
+    /// Returns true for overflow modes that contain their contents (`Overflow::Hidden`, `Overflow::Scroll`)
+    /// and false for overflow modes that allow their contents to spill (`Overflow::Visible`, `Overflow::Clip`).
+    #[inline(always)]
+    pub(crate) fn is_scroll_container(self) -> bool {
+        match self {
+            Self::Visible | Self::Clip => false,
+            Self::Hidden | Self::Scroll => true,
+        }
+    }
+
+    /// Returns `Some(0.0)` if the overflow mode would cause the automatic minimum size of a Flexbox or CSS Grid item
+    /// to be `0`. Else returns None.
+    #[inline(always)]
+    pub(crate) fn maybe_into_automatic_min_size(self) -> Option<f32> {
+        match self.is_scroll_container() {
+            true => Some(0.0),
+            false => None,
+        }
+    }
+}
+
+/// The direction in which inline content (and therefore the children of a node) flows horizontally
+///
+/// This controls which horizontal edge is the "start" edge when laying out children:
+///
+///   - In Flexbox it determines whether a row's main-start (or a column's cross-start) is the left or the right edge
+///   - In CSS Grid it determines whether columns are placed from the left or from the right
+///   - It determines whether `JustifyContent::Start` and `JustifyContent::End` pack content toward the left or the right
+///   - It determines which of the `left` and `right` insets wins when an absolutely positioned node is over-constrained
+///
+/// Physical properties such as `margin.left` and `inset.left` always refer to the left edge regardless of direction.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/direction>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Direction {
+    /// Content flows from left to right
+    Ltr,
+    /// Content flows from right to left
+    Rtl,
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Self::Ltr
+    }
+}
+
+impl Direction {
+    /// Returns true if the direction is [`Direction::Rtl`]
+    #[inline(always)]
+    pub(crate) fn is_rtl(self) -> bool {
+        self == Self::Rtl
+    }
+}
+
+/// Whether a node is displayed
+///
+/// Unlike [`Display::None`], nodes that are not visible still take part in layout. Taffy doesn't paint nodes, so the
+/// resolved visibility of each node is reported through [`Layout::visibility`](crate::layout::Layout::visibility) for
+/// renderers to skip painting the nodes that are not [`Visibility::Visible`].
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/visibility>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Visibility {
+    /// The node is displayed
+    #[default]
+    Visible,
+    /// The node is not displayed, but it is laid out as if it were (so it still takes up space)
+    Hidden,
+    /// Like [`Visibility::Hidden`], except for flex and grid items, which are collapsed and given a zero-sized layout:
+    ///
+    ///   - A collapsed flex item takes up no space in the main axis, but its flex line keeps the cross size that it
+    ///     would have had with the item displayed (the item acts as a "strut")
+    ///   - The rows and columns of a grid that are only occupied by collapsed items are collapsed to zero size (along
+    ///     with one of their adjacent gutters). This doesn't apply to masonry containers, or to the tracks that subgrids
+    ///     inherit from their parent grid.
+    Collapse,
+}
+
 /// The flexbox layout information for a single [`Node`](crate::node::Node).
 ///
 /// The most important idea in flexbox is the notion of a "main" and "cross" axis, which are always perpendicular to each other.
@@ -91,6 +242,15 @@ impl Default for Position {
 pub struct Style {
     /// What layout strategy should be used?
     pub display: Display,
+    /// Which direction does inline content (and the children of this node) flow?
+    /// Inherits the resolved direction of the parent node if not set
+    pub direction: Option<Direction>,
+    /// The font size (in points) that `em` lengths of this node are relative to
+    /// Inherits the font size of the parent node if not set
+    pub font_size: Option<f32>,
+    /// Is this node displayed?
+    /// Inherits the resolved visibility of the parent node if not set
+    pub visibility: Option<Visibility>,
 
     // Position properties
     /// What should the `position` value of this struct use as a base offset?
@@ -110,6 +270,12 @@ pub struct Style {
     /// The ratio is calculated as width divided by height.
     pub aspect_ratio: Option<f32>,
 
+    // Overflow properties
+    /// How children overflowing their container should affect layout
+    pub overflow: Point<Overflow>,
+    /// How much space (in points) should be reserved for the scrollbars of `Overflow::Scroll` nodes
+    pub scrollbar_width: f32,
+
     // Spacing Properties
     /// How large should the margin be on each side?
     pub margin: Rect<LengthPercentageAuto>,
@@ -137,6 +303,12 @@ pub struct Style {
     /// How large should the gaps between items in a grid or flex container be?
     pub gap: Size<LengthPercentage>,
 
+    // Flexbox and grid item properties
+    /// The order in which this node is laid out and painted relative to its siblings, if its parent is a flex or grid container
+    ///
+    /// Items with a lower `order` come first. Items with the same `order` are laid out in the order they were added to their parent.
+    pub order: i32,
+
     // Flexbox properies
     /// Which direction does the main axis flow in?
     pub flex_direction: FlexDirection,
@@ -160,6 +332,9 @@ pub struct Style {
     /// Defines the track sizing functions (heights) of the grid columns
     #[cfg(feature = "grid")]
     pub grid_template_columns: GridTrackVec<TrackSizingFunction>,
+    /// Defines named areas of the grid
+    #[cfg(feature = "grid")]
+    pub grid_template_areas: GridTrackVec<GridTemplateArea>,
     /// Defines the size of implicitly created rows
     #[cfg(feature = "grid")]
     pub grid_auto_rows: GridTrackVec<NonRepeatedTrackSizingFunction>,
@@ -183,6 +358,9 @@ impl Style {
     /// The [`Default`] layout, in a form that can be used in const functions
     pub const DEFAULT: Style = Style {
         display: Display::Flex,
+        direction: None,
+        font_size: None,
+        visibility: None,
         position: Position::Relative,
         flex_direction: FlexDirection::Row,
         flex_wrap: FlexWrap::NoWrap,
@@ -198,6 +376,7 @@ impl Style {
         padding: Rect::zero(),
         border: Rect::zero(),
         gap: Size::zero(),
+        order: 0,
         flex_grow: 0.0,
         flex_shrink: 1.0,
         flex_basis: Dimension::Auto,
@@ -205,11 +384,15 @@ impl Style {
         min_size: Size::auto(),
         max_size: Size::auto(),
         aspect_ratio: None,
+        overflow: Point { x: Overflow::Visible, y: Overflow::Visible },
+        scrollbar_width: 0.0,
         #[cfg(feature = "grid")]
         grid_template_rows: GridTrackVec::new(),
         #[cfg(feature = "grid")]
         grid_template_columns: GridTrackVec::new(),
         #[cfg(feature = "grid")]
+        grid_template_areas: GridTrackVec::new(),
+        #[cfg(feature = "grid")]
         grid_auto_rows: GridTrackVec::new(),
         #[cfg(feature = "grid")]
         grid_auto_columns: GridTrackVec::new(),
@@ -220,6 +403,67 @@ impl Style {
         #[cfg(feature = "grid")]
         grid_column: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
     };
+
+    /// Whether any of the lengths in this style are viewport-relative (`vw`, `vh`) or font-relative (`em`, `rem`)
+    pub fn uses_relative_units(&self) -> bool {
+        let uses_relative_units = self.inset.uses_relative_units()
+            || self.size.uses_relative_units()
+            || self.min_size.uses_relative_units()
+            || self.max_size.uses_relative_units()
+            || self.margin.uses_relative_units()
+            || self.padding.uses_relative_units()
+            || self.border.uses_relative_units()
+            || self.gap.uses_relative_units()
+            || self.flex_basis.uses_relative_units();
+        #[cfg(feature = "grid")]
+        let uses_relative_units = uses_relative_units
+            || self.grid_template_rows.uses_relative_units()
+            || self.grid_template_columns.uses_relative_units()
+            || self.grid_auto_rows.uses_relative_units()
+            || self.grid_auto_columns.uses_relative_units();
+        uses_relative_units
+    }
+
+    /// Returns a copy of this style with all viewport-relative and font-relative lengths converted to points
+    ///
+    /// `font_size` is the resolved font size of the node this style belongs to, which `em` lengths are relative to.
+    /// This is done automatically by [`Taffy::compute_layout_with_context`](crate::node::Taffy::compute_layout_with_context),
+    /// but custom [`LayoutTree`](crate::tree::LayoutTree) implementations must return styles resolved in this way.
+    #[must_use]
+    pub fn resolve_units(&self, context: &ResolutionContext, font_size: f32) -> Style {
+        Style {
+            inset: self.inset.resolve_units(context, font_size),
+            size: self.size.resolve_units(context, font_size),
+            min_size: self.min_size.resolve_units(context, font_size),
+            max_size: self.max_size.resolve_units(context, font_size),
+            margin: self.margin.resolve_units(context, font_size),
+            padding: self.padding.resolve_units(context, font_size),
+            border: self.border.resolve_units(context, font_size),
+            gap: self.gap.resolve_units(context, font_size),
+            flex_basis: self.flex_basis.resolve_units(context, font_size),
+            #[cfg(feature = "grid")]
+            grid_template_rows: self.grid_template_rows.resolve_units(context, font_size),
+            #[cfg(feature = "grid")]
+            grid_template_columns: self.grid_template_columns.resolve_units(context, font_size),
+            #[cfg(feature = "grid")]
+            grid_auto_rows: self.grid_auto_rows.resolve_units(context, font_size),
+            #[cfg(feature = "grid")]
+            grid_auto_columns: self.grid_auto_columns.resolve_units(context, font_size),
+            ..self.clone()
+        }
+    }
+
+    /// The amount of space reserved for scrollbars in each axis
+    ///
+    /// A node that scrolls horizontally reserves space at its bottom edge for a horizontal scrollbar, and a node that
+    /// scrolls vertically reserves space at its inline-end edge (the right edge, or the left edge for right-to-left
+    /// nodes) for a vertical scrollbar.
+    pub(crate) fn scrollbar_gutter(&self) -> Size<f32> {
+        Size {
+            width: if self.overflow.y == Overflow::Scroll { self.scrollbar_width } else { 0.0 },
+            height: if self.overflow.x == Overflow::Scroll { self.scrollbar_width } else { 0.0 },
+        }
+    }
 }
 
 impl Default for Style {
@@ -240,6 +484,9 @@ mod tests {
 
         let old_defaults = Style {
             display: Default::default(),
+            direction: Default::default(),
+            font_size: Default::default(),
+            visibility: Default::default(),
             position: Default::default(),
             flex_direction: Default::default(),
             flex_wrap: Default::default(),
@@ -255,6 +502,7 @@ mod tests {
             padding: Rect::zero(),
             border: Rect::zero(),
             gap: Size::zero(),
+            order: Default::default(),
             flex_grow: 0.0,
             flex_shrink: 1.0,
             flex_basis: super::Dimension::Auto,
@@ -262,11 +510,15 @@ mod tests {
             min_size: Size::auto(),
             max_size: Size::auto(),
             aspect_ratio: Default::default(),
+            overflow: Point { x: Default::default(), y: Default::default() },
+            scrollbar_width: 0.0,
             #[cfg(feature = "grid")]
             grid_template_rows: Default::default(),
             #[cfg(feature = "grid")]
             grid_template_columns: Default::default(),
             #[cfg(feature = "grid")]
+            grid_template_areas: Default::default(),
+            #[cfg(feature = "grid")]
             grid_auto_rows: Default::default(),
             #[cfg(feature = "grid")]
             grid_auto_columns: Default::default(),
@@ -308,19 +560,21 @@ mod tests {
 
         // Display and Position
         assert_type_size::<Display>(1);
+        assert_type_size::<Direction>(1);
         assert_type_size::<Position>(1);
+        assert_type_size::<Overflow>(1);
 
         // Dimensions and aggregations of Dimensions
         assert_type_size::<f32>(4);
-        assert_type_size::<LengthPercentage>(8);
-        assert_type_size::<LengthPercentageAuto>(8);
-        assert_type_size::<Dimension>(8);
-        assert_type_size::<Size<LengthPercentage>>(16);
-        assert_type_size::<Size<LengthPercentageAuto>>(16);
-        assert_type_size::<Size<Dimension>>(16);
-        assert_type_size::<Rect<LengthPercentage>>(32);
-        assert_type_size::<Rect<LengthPercentageAuto>>(32);
-        assert_type_size::<Rect<Dimension>>(32);
+        assert_type_size::<LengthPercentage>(20);
+        assert_type_size::<LengthPercentageAuto>(20);
+        assert_type_size::<Dimension>(20);
+        assert_type_size::<Size<LengthPercentage>>(40);
+        assert_type_size::<Size<LengthPercentageAuto>>(40);
+        assert_type_size::<Size<Dimension>>(40);
+        assert_type_size::<Rect<LengthPercentage>>(80);
+        assert_type_size::<Rect<LengthPercentageAuto>>(80);
+        assert_type_size::<Rect<Dimension>>(80);
 
         // Alignment
         assert_type_size::<AlignContent>(1);
@@ -333,18 +587,20 @@ mod tests {
 
         // CSS Grid Container
         assert_type_size::<GridAutoFlow>(1);
-        assert_type_size::<MinTrackSizingFunction>(8);
-        assert_type_size::<MaxTrackSizingFunction>(12);
-        assert_type_size::<NonRepeatedTrackSizingFunction>(20);
-        assert_type_size::<TrackSizingFunction>(32);
+        assert_type_size::<MinTrackSizingFunction>(20);
+        assert_type_size::<MaxTrackSizingFunction>(24);
+        assert_type_size::<NonRepeatedTrackSizingFunction>(44);
+        assert_type_size::<TrackSizingFunction>(48);
         assert_type_size::<Vec<NonRepeatedTrackSizingFunction>>(24);
         assert_type_size::<Vec<TrackSizingFunction>>(24);
+        assert_type_size::<GridTemplateArea>(10);
+        assert_type_size::<Vec<GridTemplateArea>>(24);
 
         // CSS Grid Item
-        assert_type_size::<GridPlacement>(4);
-        assert_type_size::<Line<GridPlacement>>(8);
+        assert_type_size::<GridPlacement>(6);
+        assert_type_size::<Line<GridPlacement>>(12);
 
         // Overall
-        assert_type_size::<Style>(344);
+        assert_type_size::<Style>(696);
     }
 }
@@ -1,19 +1,38 @@
 //! Helper functions which it make it easier to create instances of types in the `style` and `geometry` modules.
 use crate::{
     geometry::{MinMax, Point, Rect, Size},
-    style::LengthPercentage,
+    style::{CalcLength, LengthPercentage},
 };
 
 #[cfg(feature = "grid")]
-use crate::style::{GridTrackRepetition, NonRepeatedTrackSizingFunction, TrackSizingFunction};
+use crate::style::{GridLineName, GridTrackRepetition, NonRepeatedTrackSizingFunction, TrackSizingFunction};
 
-/// Returns an auto-repeated track definition
+/// Returns a repeated track definition. The tracks are either repeated automatically (`GridTrackRepetition::AutoFill` or
+/// `GridTrackRepetition::AutoFit`), or a fixed number of times if an integer count is passed (e.g. `repeat(12, ...)`).
 #[cfg(feature = "grid")]
-pub fn repeat(
-    repetition_kind: GridTrackRepetition,
+pub fn repeat<Input: Into<GridTrackRepetition>>(
+    repetition_kind: Input,
     track_list: Vec<NonRepeatedTrackSizingFunction>,
 ) -> TrackSizingFunction {
-    TrackSizingFunction::AutoRepeat(repetition_kind, track_list)
+    TrackSizingFunction::AutoRepeat(repetition_kind.into(), track_list)
+}
+
+/// Returns a list of names for a grid line. Does not generate a track.
+#[cfg(feature = "grid")]
+pub fn line_names(names: Vec<GridLineName>) -> TrackSizingFunction {
+    TrackSizingFunction::LineNames(names)
+}
+
+/// Returns the `subgrid` track definition, which makes a grid use the tracks that it spans in its parent grid
+#[cfg(feature = "grid")]
+pub fn subgrid() -> TrackSizingFunction {
+    TrackSizingFunction::Subgrid
+}
+
+/// Returns the `masonry` track definition, which makes an axis of a grid the masonry axis
+#[cfg(feature = "grid")]
+pub fn masonry() -> TrackSizingFunction {
+    TrackSizingFunction::Masonry
 }
 
 /// Returns a GridPlacement::Line
@@ -36,6 +55,42 @@ pub trait TaffyGridSpan {
     fn from_span(span: u16) -> Self;
 }
 
+/// Returns a GridPlacement::NamedLine
+#[cfg(feature = "grid")]
+pub fn named_line<T: TaffyGridNamedLine>(name: GridLineName, index: i16) -> T {
+    T::from_named_line(name, index)
+}
+/// Trait to abstract over named grid line values
+#[cfg(feature = "grid")]
+pub trait TaffyGridNamedLine {
+    /// Converts a name and an i16 index into Self
+    fn from_named_line(name: GridLineName, index: i16) -> Self;
+}
+
+/// Returns a GridPlacement::NamedSpan
+#[cfg(feature = "grid")]
+pub fn named_span<T: TaffyGridNamedSpan>(name: GridLineName, span: u16) -> T {
+    T::from_named_span(name, span)
+}
+/// Trait to abstract over named grid span values
+#[cfg(feature = "grid")]
+pub trait TaffyGridNamedSpan {
+    /// Converts a name and a u16 span into Self
+    fn from_named_span(name: GridLineName, span: u16) -> Self;
+}
+
+/// Returns a GridPlacement::NamedArea
+#[cfg(feature = "grid")]
+pub fn named_area<T: TaffyGridNamedArea>(name: GridLineName) -> T {
+    T::from_named_area(name)
+}
+/// Trait to abstract over named grid area values
+#[cfg(feature = "grid")]
+pub trait TaffyGridNamedArea {
+    /// Converts a name into Self
+    fn from_named_area(name: GridLineName) -> Self;
+}
+
 /// Returns a MinMax with min value of min and max value of max
 pub fn minmax<Min, Max, Output: From<MinMax<Min, Max>>>(min: Min, max: Max) -> Output {
     MinMax { min, max }.into()
@@ -398,6 +453,14 @@ impl<T: FromPercent> Rect<T> {
     }
 }
 
+/// Returns a value of the inferred type which represents `calc(percent + points)`
+///
+/// The percentage is a fraction (0.5 is 50%). Use [`CalcLength::at_least`], [`CalcLength::at_most`] and
+/// [`CalcLength::clamp`] before converting to bound the value.
+pub fn calc<T: From<CalcLength>>(points: f32, percent: f32) -> T {
+    CalcLength::new(points, percent).into()
+}
+
 /// Returns a value of the inferred type which represents a flex fraction
 pub fn flex<Input: Into<f32> + Copy, T: FromFlex>(flex: Input) -> T {
     T::from_flex(flex)
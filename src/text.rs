@@ -0,0 +1,334 @@
+//! Measurement of text nodes, backed by the [`cosmic_text`] shaping and line breaking engine
+//!
+//! A [`TextNode`] holds a string and its [`TextStyle`], and provides a [`MeasureFunc`] (see [`TextNode::measure_func`])
+//! that sizes the text for the constraints that the layout algorithms pass to it:
+//!
+//! - [`AvailableSpace::MinContent`] breaks the text at every break opportunity, so the width is that of the longest word
+//! - [`AvailableSpace::MaxContent`] only breaks the text at hard line breaks
+//! - [`AvailableSpace::Definite`] (and known widths) wrap the text to the given width
+//!
+//! Once laid out, [`TextNode::layout`] returns the lines of the text (with their glyphs and baselines) for rendering.
+//!
+//! ```
+//! use taffy::prelude::*;
+//! use taffy::text::{TextContext, TextNode, TextStyle};
+//!
+//! let context = TextContext::new();
+//! let text = TextNode::new(&context, "Hello world", TextStyle::default());
+//!
+//! let mut taffy = Taffy::new();
+//! let node = taffy.new_leaf_with_measure(Style::default(), text.measure_func()).unwrap();
+//! taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+//!
+//! let lines = text.layout(Some(taffy.layout(node).unwrap().size.width));
+//! ```
+use std::ops::Range;
+use std::string::String;
+use std::sync::{Arc, Mutex, PoisonError};
+
+pub use cosmic_text;
+use cosmic_text::{Attrs, Buffer, Family, FontSystem, Metrics, Shaping};
+
+use crate::geometry::Size;
+use crate::layout::SizeAndBaseline;
+use crate::node::MeasureFunc;
+use crate::style::AvailableSpace;
+use crate::sys::{Box, Vec};
+
+/// The number of wrapped layouts that each [`TextNode`] caches
+///
+/// A node is typically measured with a min-content width, a max-content width and its final width.
+const TEXT_LAYOUT_CACHE_SIZE: usize = 4;
+
+/// The fonts available to [`TextNode`]s
+///
+/// Cloning a [`TextContext`] is cheap, and the clones share their fonts. Text nodes lock the context while their text
+/// is shaped and broken into lines.
+#[derive(Clone)]
+pub struct TextContext {
+    /// The fonts, along with the caches used by the shaping engine
+    font_system: Arc<Mutex<FontSystem>>,
+}
+
+impl TextContext {
+    /// Creates a [`TextContext`] with the fonts installed on the system
+    #[must_use]
+    pub fn new() -> Self {
+        Self { font_system: Arc::new(Mutex::new(FontSystem::new())) }
+    }
+
+    /// Creates a [`TextContext`] with only the given fonts (for example fonts bundled with an application)
+    ///
+    /// Each font is the contents of a TrueType or OpenType font file (or collection).
+    #[must_use]
+    pub fn with_fonts(fonts: impl IntoIterator<Item = Vec<u8>>) -> Self {
+        let mut database = cosmic_text::fontdb::Database::new();
+        for font in fonts {
+            database.load_font_data(font);
+        }
+        let font_system = FontSystem::new_with_locale_and_db(String::from("en-US"), database);
+        Self { font_system: Arc::new(Mutex::new(font_system)) }
+    }
+
+    /// Adds a font to the context, from the contents of a TrueType or OpenType font file (or collection)
+    ///
+    /// Text nodes that have already been measured keep their layouts, and don't use the new font.
+    pub fn load_font_data(&self, font: Vec<u8>) {
+        self.font_system.lock().unwrap_or_else(PoisonError::into_inner).db_mut().load_font_data(font);
+    }
+}
+
+impl Default for TextContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The style of the text of a [`TextNode`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextStyle {
+    /// The name of the font family, or `None` for the default sans-serif font
+    pub font_family: Option<String>,
+    /// The font size in points
+    pub font_size: f32,
+    /// The height of each line of text in points
+    pub line_height: f32,
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self { font_family: None, font_size: 16.0, line_height: 19.2 }
+    }
+}
+
+/// Text that has been broken into lines
+#[derive(Clone, Debug, Default)]
+pub struct TextLayout {
+    /// The width of the longest line
+    pub width: f32,
+    /// The total height of the lines
+    pub height: f32,
+    /// The lines, from top to bottom
+    pub lines: Vec<LineBox>,
+}
+
+impl TextLayout {
+    /// The baseline of the first line, if there is one
+    #[must_use]
+    pub fn first_baseline(&self) -> Option<f32> {
+        self.lines.first().map(|line| line.baseline)
+    }
+}
+
+/// A single line of text within a [`TextLayout`]
+#[derive(Clone, Debug)]
+pub struct LineBox {
+    /// The range of bytes of the text displayed on this line
+    pub text_range: Range<usize>,
+    /// The offset of the top of the line from the top of the text
+    pub top: f32,
+    /// The offset of the baseline of the line from the top of the text
+    pub baseline: f32,
+    /// The width of the line
+    pub width: f32,
+    /// The height of the line
+    pub height: f32,
+    /// The shaped glyphs of the line, in visual order
+    pub glyphs: Vec<PositionedGlyph>,
+}
+
+/// A shaped glyph within a [`LineBox`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionedGlyph {
+    /// The range of bytes of the text displayed by the glyph (a glyph can display several characters)
+    pub text_range: Range<usize>,
+    /// The font of the glyph, within the fonts of the [`TextContext`]
+    pub font_id: cosmic_text::fontdb::ID,
+    /// The id of the glyph within its font
+    pub glyph_id: u16,
+    /// The font size of the glyph in points
+    pub font_size: f32,
+    /// The offset of the glyph's origin from the start of the line
+    pub x: f32,
+    /// The offset of the glyph's origin from the baseline of the line
+    pub y: f32,
+    /// The advance width of the glyph
+    pub width: f32,
+}
+
+/// A string that can be measured by Taffy, and laid out into lines for rendering
+///
+/// Cloning a [`TextNode`] is cheap, and the clones share their text and cached layouts. This allows a clone to be moved
+/// into the node's [`MeasureFunc`] while the original is kept for rendering.
+#[derive(Clone)]
+pub struct TextNode {
+    /// The shared state of the node
+    inner: Arc<TextNodeInner>,
+}
+
+/// The shared state of a [`TextNode`]
+struct TextNodeInner {
+    /// The fonts used to shape the text
+    context: TextContext,
+    /// The text
+    text: String,
+    /// The style of the text
+    style: TextStyle,
+    /// The shaped text and the cached layouts
+    state: Mutex<TextNodeState>,
+}
+
+/// The mutable state of a [`TextNode`]
+#[derive(Default)]
+struct TextNodeState {
+    /// The shaped text, created when the text is first laid out
+    buffer: Option<Buffer>,
+    /// The most recently used layouts (most recent first), keyed by the bits of the width the text was wrapped to
+    layouts: Vec<(u32, Arc<TextLayout>)>,
+}
+
+impl TextNode {
+    /// Creates a [`TextNode`] that displays the `text` in the given `style`, using the fonts of the `context`
+    #[must_use]
+    pub fn new(context: &TextContext, text: impl Into<String>, style: TextStyle) -> Self {
+        let inner = TextNodeInner {
+            context: context.clone(),
+            text: text.into(),
+            style,
+            state: Mutex::new(TextNodeState::default()),
+        };
+        Self { inner: Arc::new(inner) }
+    }
+
+    /// The text of the node
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.inner.text
+    }
+
+    /// The style of the text of the node
+    #[must_use]
+    pub fn style(&self) -> &TextStyle {
+        &self.inner.style
+    }
+
+    /// Creates a [`MeasureFunc`] that measures this text, for use with [`Taffy::new_leaf_with_measure`](crate::node::Taffy::new_leaf_with_measure)
+    #[must_use]
+    pub fn measure_func(&self) -> MeasureFunc {
+        let node = self.clone();
+        MeasureFunc::BoxedWithBaseline(Box::new(move |known_dimensions, available_space| {
+            node.measure(known_dimensions, available_space)
+        }))
+    }
+
+    /// Measures the text, wrapping it to the known width or else the available width
+    ///
+    /// The measured width is rounded up to a whole point, so that wrapping the text to the (rounded) width of the laid
+    /// out node breaks it into the same lines. The first baseline is that of the first line of text.
+    #[must_use]
+    pub fn measure(
+        &self,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+    ) -> SizeAndBaseline {
+        let max_width = known_dimensions.width.or(match available_space.width {
+            AvailableSpace::MinContent => Some(0.0),
+            AvailableSpace::MaxContent => None,
+            AvailableSpace::Definite(width) => Some(width),
+        });
+        let layout = self.layout(max_width);
+
+        let content_size = Size { width: layout.width.ceil(), height: layout.height };
+        let size = Size {
+            width: known_dimensions.width.unwrap_or(content_size.width),
+            height: known_dimensions.height.unwrap_or(content_size.height),
+        };
+        SizeAndBaseline { size, first_baseline: layout.first_baseline(), content_size }
+    }
+
+    /// Breaks the text into lines that are at most `max_width` wide (if possible), or only at hard line breaks if
+    /// `max_width` is `None`
+    ///
+    /// To render the text of a laid out node, pass the width of its [`Layout`](crate::layout::Layout). The most recently
+    /// used layouts are cached, so this is cheap if the node has been measured with the same width.
+    #[must_use]
+    pub fn layout(&self, max_width: Option<f32>) -> Arc<TextLayout> {
+        let max_width = max_width.unwrap_or(f32::INFINITY).max(0.0);
+        let key = max_width.to_bits();
+
+        let mut state = self.inner.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(index) = state.layouts.iter().position(|(cached_key, _)| *cached_key == key) {
+            let entry = state.layouts.remove(index);
+            let layout = Arc::clone(&entry.1);
+            state.layouts.insert(0, entry);
+            return layout;
+        }
+
+        let layout = Arc::new(self.compute_layout(&mut state, max_width));
+        state.layouts.truncate(TEXT_LAYOUT_CACHE_SIZE - 1);
+        state.layouts.insert(0, (key, Arc::clone(&layout)));
+        layout
+    }
+
+    /// Wraps the text to `max_width`, shaping it first if it hasn't been shaped yet
+    fn compute_layout(&self, state: &mut TextNodeState, max_width: f32) -> TextLayout {
+        let TextNodeInner { context, text, style, .. } = &*self.inner;
+        if text.is_empty() {
+            return TextLayout::default();
+        }
+
+        let mut font_system = context.font_system.lock().unwrap_or_else(PoisonError::into_inner);
+        let buffer = state.buffer.get_or_insert_with(|| {
+            let mut buffer = Buffer::new(&mut font_system, Metrics::new(style.font_size, style.line_height));
+            let family = match &style.font_family {
+                Some(name) => Family::Name(name),
+                None => Family::SansSerif,
+            };
+            buffer.set_text(&mut font_system, text, Attrs::new().family(family), Shaping::Advanced);
+            buffer
+        });
+        buffer.set_size(&mut font_system, max_width, f32::INFINITY);
+
+        // The glyphs of each line are positioned within its paragraph (the text between hard line breaks)
+        let paragraph_starts: Vec<usize> = cosmic_text::BidiParagraphs::new(text)
+            .map(|paragraph| paragraph.as_ptr() as usize - text.as_ptr() as usize)
+            .collect();
+
+        let mut layout = TextLayout::default();
+        for run in buffer.layout_runs() {
+            let paragraph_start = paragraph_starts.get(run.line_i).copied().unwrap_or(text.len());
+            let glyphs: Vec<PositionedGlyph> = run
+                .glyphs
+                .iter()
+                .map(|glyph| PositionedGlyph {
+                    text_range: paragraph_start + glyph.start..paragraph_start + glyph.end,
+                    font_id: glyph.font_id,
+                    glyph_id: glyph.glyph_id,
+                    font_size: glyph.font_size,
+                    x: glyph.x + glyph.font_size * glyph.x_offset,
+                    y: glyph.y - glyph.font_size * glyph.y_offset,
+                    width: glyph.w,
+                })
+                .collect();
+            // Whitespace that overflows a line is wrapped onto a line of its own, which a renderer wouldn't show
+            if glyphs.is_empty() && !run.text.is_empty() {
+                continue;
+            }
+            let start = glyphs.iter().map(|glyph| glyph.text_range.start).min().unwrap_or(paragraph_start);
+            let end = glyphs.iter().map(|glyph| glyph.text_range.end).max().unwrap_or(paragraph_start);
+
+            let top = layout.height;
+            layout.width = layout.width.max(run.line_w);
+            layout.height += style.line_height;
+            layout.lines.push(LineBox {
+                text_range: start..end,
+                top,
+                baseline: top + (run.line_y - run.line_top),
+                width: run.line_w,
+                height: style.line_height,
+                glyphs,
+            });
+        }
+        layout
+    }
+}
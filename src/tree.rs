@@ -1,66 +1,180 @@
 //! The baseline requirements of any UI Tree so Taffy can efficiently calculate the layout
 
-use slotmap::DefaultKey;
+use core::fmt::Debug;
 
 use crate::{
+    compute::{collect_fixed_descendants, perform_hidden_layout},
     error::TaffyResult,
-    layout::{Cache, Layout},
+    layout::{Cache, Layout, LayoutInput, SizeAndBaseline},
     prelude::*,
+    sys::Vec,
 };
 
 /// Any item that implements the LayoutTree can be layed out using Taffy's algorithms.
 ///
 /// Generally, Taffy expects your Node tree to be indexable by stable indices. A "stable" index means that the Node's ID
 /// remains the same between re-layouts.
+///
+/// The node ids are chosen by the implementor, so a tree can be laid out directly from its own node storage (such as
+/// the entities of an ECS, or indexes into a `Vec`) rather than being mirrored into a [`Taffy`] instance.
 pub trait LayoutTree {
+    /// Type representing the id of a node in the tree
+    type NodeId: Copy + Eq + Debug;
+
     /// Type representing an iterator of the children of a node
-    type ChildIter<'a>: Iterator<Item = &'a DefaultKey>
+    type ChildIter<'a>: Iterator<Item = Self::NodeId>
     where
         Self: 'a;
 
     /// Get the list of children IDs for the given node
-    fn children(&self, node: Node) -> Self::ChildIter<'_>;
+    fn children(&self, node: Self::NodeId) -> Self::ChildIter<'_>;
 
     /// Get the number of children for the given node
-    fn child_count(&self, node: Node) -> usize;
+    fn child_count(&self, node: Self::NodeId) -> usize;
 
     /// Returns true if the node has no children
-    fn is_childless(&self, node: Node) -> bool;
+    fn is_childless(&self, node: Self::NodeId) -> bool;
 
     /// Get a specific child of a node, where the index represents the nth child
-    fn child(&self, node: Node, index: usize) -> Node;
+    fn child(&self, node: Self::NodeId, index: usize) -> Self::NodeId;
 
     /// Get any available parent for this node
-    fn parent(&self, node: Node) -> Option<Node>;
+    fn parent(&self, node: Self::NodeId) -> Option<Self::NodeId>;
 
     // todo: allow abstractions over this so we don't prescribe how layout works
     // for reference, CSS cascades require context, and storing a full flexbox layout for each node could be inefficient
     //
     /// Get the [`Style`] for this Node.
-    fn style(&self, node: Node) -> &Style;
+    ///
+    /// The layout algorithms don't resolve viewport-relative (`vw`, `vh`) or font-relative (`em`, `rem`) lengths: any
+    /// that remain in the returned style are treated as `auto` (or as zero where `auto` isn't allowed). Implementations
+    /// that support them should return styles converted with [`Style::resolve_units`].
+    fn style(&self, node: Self::NodeId) -> &Style;
+
+    /// Get the resolved [`Direction`] of this node
+    ///
+    /// Nodes that don't set a `direction` inherit the resolved direction of their parent. The root node defaults to
+    /// [`Direction::Ltr`].
+    fn direction(&self, node: Self::NodeId) -> Direction {
+        match self.style(node).direction {
+            Some(direction) => direction,
+            None => self.parent(node).map(|parent| self.direction(parent)).unwrap_or_default(),
+        }
+    }
+
+    /// Get the resolved [`Visibility`] of this node
+    ///
+    /// Nodes that don't set a `visibility` inherit the resolved visibility of their parent. The root node defaults to
+    /// [`Visibility::Visible`].
+    fn visibility(&self, node: Self::NodeId) -> Visibility {
+        match self.style(node).visibility {
+            Some(visibility) => visibility,
+            None => self.parent(node).map(|parent| self.visibility(parent)).unwrap_or_default(),
+        }
+    }
+
+    /// Get the font size (in points) that `em` lengths of this node are relative to
+    ///
+    /// Nodes that don't set a `font_size` inherit the font size of their parent. Returns `None` if neither the node
+    /// nor any of its ancestors set a font size, in which case the root font size of the
+    /// [`ResolutionContext`](crate::style::ResolutionContext) is used.
+    fn font_size(&self, node: Self::NodeId) -> Option<f32> {
+        match self.style(node).font_size {
+            Some(font_size) => Some(font_size),
+            None => self.parent(node).and_then(|parent| self.font_size(parent)),
+        }
+    }
+
+    /// Collect the fixed position descendants of `node` that are displayed (that have no `Display::None` ancestor),
+    /// with ancestors before their descendants
+    ///
+    /// These are laid out relative to the root node. The default implementation walks the whole subtree of `node`, so
+    /// trees that keep track of their fixed position nodes may want to override it.
+    fn fixed_descendants(&self, node: Self::NodeId, fixed: &mut Vec<Self::NodeId>)
+    where
+        Self: Sized,
+    {
+        collect_fixed_descendants(self, node, fixed)
+    }
+
+    /// Get the node's unrounded layout, as computed by the layout algorithms
+    fn layout(&self, node: Self::NodeId) -> &Layout;
+
+    /// Modify the node's unrounded layout
+    fn layout_mut(&mut self, node: Self::NodeId) -> &mut Layout;
 
-    /// Get the node's output "Final Layout"
-    fn layout(&self, node: Node) -> &Layout;
+    /// Modify the node's output "Final Layout": its layout rounded to whole points
+    ///
+    /// This is updated at the end of each layout for the nodes whose layout has been recomputed.
+    fn final_layout_mut(&mut self, node: Self::NodeId) -> &mut Layout;
 
-    /// Modify the node's output layout
-    fn layout_mut(&mut self, node: Node) -> &mut Layout;
+    /// Called at the end of each layout for each node whose final location, size, order or visibility differs from its
+    /// previous final layout
+    ///
+    /// This can be used to only repaint the nodes that have moved, been resized, or been shown or hidden. The default implementation does nothing.
+    fn notify_layout_changed(&mut self, _node: Self::NodeId) {}
 
     /// Mark a node as dirty to tell Taffy that something has changed and it needs to be recomputed.
     ///
     /// Commonly done if the style of the node has changed.
-    fn mark_dirty(&mut self, node: Node) -> TaffyResult<()>;
+    fn mark_dirty(&mut self, node: Self::NodeId) -> TaffyResult<()>;
 
     /// Measure a node. Taffy uses this to force reflows of things like text and overflowing content.
+    ///
+    /// The returned first baseline (if any) is used when the node is baseline-aligned, and is exposed as [`Layout::first_baseline`].
     fn measure_node(
-        &self,
-        node: Node,
+        &mut self,
+        node: Self::NodeId,
         known_dimensions: Size<Option<f32>>,
         available_space: Size<AvailableSpace>,
-    ) -> Size<f32>;
+    ) -> SizeAndBaseline;
 
     /// Node needs to be measured
-    fn needs_measure(&self, node: Node) -> bool;
+    fn needs_measure(&self, node: Self::NodeId) -> bool;
+
+    /// Whether the measure function of the node can report the node's first baseline
+    ///
+    /// Nodes whose width and height are both known are only measured when their layout is performed (which is when
+    /// baselines are needed) and this returns true. The default implementation returns true.
+    fn can_measure_baseline(&self, _node: Self::NodeId) -> bool {
+        true
+    }
+
+    /// Compute the layout of a node with [`Display::Custom`] that has children, returning its size
+    ///
+    /// This lets the tree supply its own layout algorithm for some nodes. Implementations should size and position the
+    /// children of the node (typically using [`compute_node_layout`] and [`LayoutTree::layout_mut`]), but not set the
+    /// layout of the node itself as that is the responsibility of its parent. Childless nodes are always sized as leaves.
+    ///
+    /// The default implementation sizes the node as if it were a leaf node, and gives its children (and their
+    /// descendants) the same hidden layout as nodes with [`Display::None`].
+    fn compute_custom_layout(
+        &mut self,
+        node: Self::NodeId,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        run_mode: RunMode,
+        sizing_mode: SizingMode,
+    ) -> SizeAndBaseline
+    where
+        Self: Sized,
+    {
+        if run_mode == RunMode::PeformLayout {
+            perform_hidden_layout(self, node);
+        }
+        layout_leaf(self, node, known_dimensions, available_space, run_mode, sizing_mode)
+    }
+
+    /// Called by the flexbox and grid algorithms before the final layout pass over their in-flow children, with the
+    /// inputs of the [`compute_node_layout`] call (in [`RunMode::PeformLayout`]) that each child is about to receive
+    ///
+    /// The subtrees of these children are independent of each other, so a tree may use this hook to lay them out ahead
+    /// of time (for example concurrently). The results must be stored in the cache of each child so that the
+    /// subsequent calls are served from it. The default implementation does nothing.
+    fn prepare_child_layouts(&mut self, _inputs: &[LayoutInput<Self::NodeId>]) {}
 
     /// Get a cache entry for this Node by index
-    fn cache_mut(&mut self, node: Node, index: usize) -> &mut Option<Cache>;
+    ///
+    /// The index is always less than [`CACHE_SIZE`](crate::layout::CACHE_SIZE)
+    fn cache_mut(&mut self, node: Self::NodeId, index: usize) -> &mut Option<Cache>;
 }
@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod baseline {
+    use taffy::layout::SizeAndBaseline;
+    use taffy::node::MeasureFunc;
+    use taffy::prelude::*;
+
+    /// A single line of "text" that is 50px wide and 20px tall with its baseline 15px from the top
+    fn text_measure() -> MeasureFunc {
+        MeasureFunc::RawWithBaseline(|known_dimensions, _available_space| SizeAndBaseline {
+            size: Size {
+                width: known_dimensions.width.unwrap_or(50.0),
+                height: known_dimensions.height.unwrap_or(20.0),
+            },
+            first_baseline: Some(15.0),
+            content_size: Size { width: 50.0, height: 20.0 },
+        })
+    }
+
+    #[test]
+    fn measured_leaf_reports_baseline() {
+        let mut taffy = Taffy::new();
+        let node = taffy.new_leaf_with_measure(Style::default(), text_measure()).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(node).unwrap();
+        assert_eq!(layout.size, Size { width: 50.0, height: 20.0 });
+        assert_eq!(layout.first_baseline, Some(15.0));
+    }
+
+    #[test]
+    fn measured_leaf_with_definite_size_reports_baseline() {
+        let mut taffy = Taffy::new();
+        let node = taffy
+            .new_leaf_with_measure(Style { size: Size::from_points(80.0, 40.0), ..Default::default() }, text_measure())
+            .unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(node).unwrap();
+        assert_eq!(layout.size, Size { width: 80.0, height: 40.0 });
+        assert_eq!(layout.first_baseline, Some(15.0));
+    }
+
+    #[test]
+    fn leaf_without_measure_func_has_no_baseline() {
+        let mut taffy = Taffy::new();
+        let node = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(node).unwrap().first_baseline, None);
+    }
+
+    #[test]
+    fn flexbox_aligns_measured_baseline() {
+        let mut taffy = Taffy::new();
+        let text = taffy.new_leaf_with_measure(Style::default(), text_measure()).unwrap();
+        let boxed = taffy.new_leaf(Style { size: Size::from_points(10.0, 30.0), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(Style { align_items: Some(AlignItems::Baseline), ..Default::default() }, &[text, boxed])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The box has no baseline, so its baseline is synthesized from its bottom edge (30px)
+        assert_eq!(taffy.layout(text).unwrap().location.y, 15.0);
+        assert_eq!(taffy.layout(boxed).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 60.0, height: 35.0 });
+        assert_eq!(taffy.layout(root).unwrap().first_baseline, Some(30.0));
+    }
+
+    #[test]
+    fn nested_flexbox_container_baseline() {
+        let mut taffy = Taffy::new();
+        let text = taffy.new_leaf_with_measure(Style::default(), text_measure()).unwrap();
+        let inner = taffy
+            .new_with_children(
+                Style {
+                    flex_direction: FlexDirection::Column,
+                    padding: Rect { left: zero(), right: zero(), top: points(10.0), bottom: zero() },
+                    ..Default::default()
+                },
+                &[text],
+            )
+            .unwrap();
+        let boxed = taffy.new_leaf(Style { size: Size::from_points(10.0, 40.0), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(Style { align_items: Some(AlignItems::Baseline), ..Default::default() }, &[inner, boxed])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(inner).unwrap().first_baseline, Some(25.0));
+        assert_eq!(taffy.layout(inner).unwrap().location.y, 15.0);
+        assert_eq!(taffy.layout(boxed).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 45.0);
+    }
+
+    #[cfg(feature = "grid")]
+    #[test]
+    fn grid_aligns_measured_baseline() {
+        let mut taffy = Taffy::new();
+        let text = taffy.new_leaf_with_measure(Style::default(), text_measure()).unwrap();
+        let boxed = taffy.new_leaf(Style { size: Size::from_points(10.0, 30.0), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(50.0), points(50.0)],
+                    align_items: Some(AlignItems::Baseline),
+                    ..Default::default()
+                },
+                &[text, boxed],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(text).unwrap().location.y, 15.0);
+        assert_eq!(taffy.layout(boxed).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 35.0);
+        assert_eq!(taffy.layout(root).unwrap().first_baseline, Some(30.0));
+    }
+
+    #[cfg(all(feature = "grid", feature = "block_layout"))]
+    #[test]
+    fn grid_aligns_baselines_of_nested_containers() {
+        let mut taffy = Taffy::new();
+        let padding_top = |top| Rect { left: zero(), right: zero(), top: points(top), bottom: zero() };
+        let block_text = taffy.new_leaf_with_measure(Style::default(), text_measure()).unwrap();
+        let block = taffy
+            .new_with_children(
+                Style { display: Display::Block, padding: padding_top(10.0), ..Default::default() },
+                &[block_text],
+            )
+            .unwrap();
+        let grid_text = taffy.new_leaf_with_measure(Style::default(), text_measure()).unwrap();
+        let grid = taffy
+            .new_with_children(
+                Style { display: Display::Grid, padding: padding_top(5.0), ..Default::default() },
+                &[grid_text],
+            )
+            .unwrap();
+        let reversed_text = taffy.new_leaf_with_measure(Style::default(), text_measure()).unwrap();
+        let reversed_box = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let reversed = taffy
+            .new_with_children(
+                Style { flex_direction: FlexDirection::ColumnReverse, ..Default::default() },
+                &[reversed_text, reversed_box],
+            )
+            .unwrap();
+        let boxed = taffy.new_leaf(Style { size: Size::from_points(10.0, 40.0), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(50.0), points(50.0), points(50.0), points(50.0)],
+                    align_items: Some(AlignItems::Baseline),
+                    ..Default::default()
+                },
+                &[block, grid, reversed, boxed],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The baselines of the containers are 25px, 20px and 25px from their top edges, and the box's is its bottom edge
+        assert_eq!(taffy.layout(block).unwrap().location.y, 15.0);
+        assert_eq!(taffy.layout(grid).unwrap().location.y, 20.0);
+        assert_eq!(taffy.layout(reversed).unwrap().location.y, 15.0);
+        assert_eq!(taffy.layout(boxed).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 45.0);
+        assert_eq!(taffy.layout(root).unwrap().first_baseline, Some(40.0));
+    }
+
+    #[cfg(feature = "block_layout")]
+    #[test]
+    fn block_container_baseline_skips_children_without_baseline() {
+        let mut taffy = Taffy::new();
+        let spacer = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let text = taffy.new_leaf_with_measure(Style::default(), text_measure()).unwrap();
+        let root =
+            taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[spacer, text]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().first_baseline, Some(25.0));
+    }
+}
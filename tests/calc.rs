@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod calc {
+    use taffy::prelude::*;
+
+    #[test]
+    fn calc_without_percentage_resolves_in_indefinite_container() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size { width: calc(30.0, 0.0), height: CalcLength::new(10.0, 0.5).into() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The height has a percentage component which can't be resolved against the indefinite container height
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 30.0, height: 0.0 });
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn calc_grid_tracks() {
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style::default()).unwrap();
+        let second = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size::from_points(200.0, 50.0),
+                    grid_template_columns: vec![
+                        calc(-10.0, 0.5),
+                        minmax(MinTrackSizingFunction::from(CalcLength::new(0.0, 0.1).at_least(50.0)), flex(1.0)),
+                    ],
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size { width: points(200.0), height: points(50.0) }).unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().size.width, 90.0);
+        assert_eq!(taffy.layout(second).unwrap().location.x, 90.0);
+        assert_eq!(taffy.layout(second).unwrap().size.width, 110.0);
+    }
+
+    #[test]
+    fn many_distinct_calc_lengths_can_be_created() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 100.0), ..Default::default() }, &[child])
+            .unwrap();
+
+        // Each frame of an animation of calc(100% - Npx) creates a new length
+        for frame in 0..5000 {
+            let width = calc(-(frame as f32) / 100.0, 1.0);
+            taffy.set_style(child, Style { size: Size { width, height: auto() }, ..Default::default() }).unwrap();
+            taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        }
+
+        assert_eq!(taffy.layout(child).unwrap().size.width, 50.0);
+    }
+}
@@ -0,0 +1,9 @@
+//! Helpers shared by the hand-written integration tests
+
+use taffy::prelude::*;
+
+/// The size and location of `node`, as `(width, height, x, y)`
+pub fn layout_of(taffy: &Taffy, node: Node) -> (f32, f32, f32, f32) {
+    let layout = taffy.layout(node).unwrap();
+    (layout.size.width, layout.size.height, layout.location.x, layout.location.y)
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod containing_block {
+    use taffy::prelude::*;
+
+    fn absolute_leaf(taffy: &mut Taffy, position: Position, inset: Rect<LengthPercentageAuto>) -> Node {
+        taffy.new_leaf(Style { position, inset, size: Size::from_points(20.0, 20.0), ..Default::default() }).unwrap()
+    }
+
+    #[test]
+    fn hoisted_descendants_contribute_to_content_size_of_containing_block() {
+        let mut taffy = Taffy::new();
+        let absolute = absolute_leaf(
+            &mut taffy,
+            Position::Absolute,
+            Rect { left: points(150.0), right: auto(), top: points(10.0), bottom: auto() },
+        );
+        let static_child = taffy
+            .new_with_children(
+                Style { position: Position::Static, size: Size::from_points(50.0, 50.0), ..Default::default() },
+                &[absolute],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 100.0), ..Default::default() }, &[static_child])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(static_child).unwrap().content_size, Size::ZERO);
+        assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 170.0, height: 50.0 });
+    }
+
+    #[test]
+    fn changing_parent_position_changes_containing_block() {
+        let mut taffy = Taffy::new();
+        let absolute = absolute_leaf(
+            &mut taffy,
+            Position::Absolute,
+            Rect { left: points(5.0), right: auto(), top: points(5.0), bottom: auto() },
+        );
+        let child_style = |position| Style {
+            position,
+            size: Size::from_points(50.0, 50.0),
+            margin: Rect { left: points(10.0), right: zero(), top: points(10.0), bottom: zero() },
+            ..Default::default()
+        };
+        let child = taffy.new_with_children(child_style(Position::Static), &[absolute]).unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 100.0), ..Default::default() }, &[child])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(absolute).unwrap().location, Point { x: -5.0, y: -5.0 });
+
+        taffy.set_style(child, child_style(Position::Relative)).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(absolute).unwrap().location, Point { x: 5.0, y: 5.0 });
+    }
+
+    #[test]
+    fn fixed_descendants_are_relaid_out_when_ancestors_move() {
+        let mut taffy = Taffy::new();
+        let fixed = absolute_leaf(
+            &mut taffy,
+            Position::Fixed,
+            Rect { left: zero(), right: auto(), top: zero(), bottom: auto() },
+        );
+        let sibling = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let child = taffy
+            .new_with_children(Style { size: Size::from_points(50.0, 50.0), ..Default::default() }, &[fixed])
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 100.0), ..Default::default() }, &[sibling, child])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(fixed).unwrap().location, Point { x: -10.0, y: 0.0 });
+
+        taffy.set_style(sibling, Style { size: Size::from_points(30.0, 10.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(fixed).unwrap().location, Point { x: -30.0, y: 0.0 });
+    }
+
+    #[test]
+    fn fixed_descendants_follow_style_changes_and_reparenting() {
+        let mut taffy = Taffy::new();
+        let node = absolute_leaf(
+            &mut taffy,
+            Position::Relative,
+            Rect { left: points(5.0), right: auto(), top: points(5.0), bottom: auto() },
+        );
+        let child = taffy
+            .new_with_children(
+                Style { size: Size::from_points(50.0, 50.0), margin: points(10.0), ..Default::default() },
+                &[node],
+            )
+            .unwrap();
+        let hidden = taffy.new_with_children(Style { display: Display::None, ..Default::default() }, &[]).unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 100.0), ..Default::default() }, &[child, hidden])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().location, Point { x: 5.0, y: 5.0 });
+
+        let fixed_style = Style { position: Position::Fixed, ..taffy.style(node).unwrap().clone() };
+        taffy.set_style(node, fixed_style).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().location, Point { x: -5.0, y: -5.0 });
+
+        // Fixed position descendants of hidden nodes are hidden too
+        taffy.remove_child(child, node).unwrap();
+        taffy.add_child(hidden, node).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().location, Point::ZERO);
+        assert_eq!(taffy.layout(node).unwrap().size, Size::ZERO);
+    }
+
+    #[test]
+    fn static_position_uses_the_direction_of_the_containing_block() {
+        let mut taffy = Taffy::new();
+        let absolute = taffy
+            .new_leaf(Style {
+                position: Position::Absolute,
+                direction: Some(Direction::Ltr),
+                size: Size::from_points(20.0, 20.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let static_child = taffy
+            .new_with_children(
+                Style { position: Position::Static, size: Size::from_points(50.0, 50.0), ..Default::default() },
+                &[absolute],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { direction: Some(Direction::Rtl), size: Size::from_points(100.0, 100.0), ..Default::default() },
+                &[static_child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(static_child).unwrap().location.x, 50.0);
+        assert_eq!(taffy.layout(absolute).unwrap().location, Point { x: 30.0, y: 0.0 });
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod content_size {
+    use taffy::layout::SizeAndBaseline;
+    use taffy::node::MeasureFunc;
+    use taffy::prelude::*;
+
+    fn overflow(x: Overflow, y: Overflow) -> Point<Overflow> {
+        Point { x, y }
+    }
+
+    #[test]
+    fn leaf_without_measure_func_has_no_content() {
+        let mut taffy = Taffy::new();
+        let node = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(node).unwrap().content_size, Size::ZERO);
+    }
+
+    #[test]
+    fn leaf_content_size_is_reported_by_measure_func() {
+        let mut taffy = Taffy::new();
+        let node = taffy
+            .new_leaf_with_measure(
+                Style { size: Size::from_points(50.0, 20.0), ..Default::default() },
+                MeasureFunc::RawWithBaseline(|_known_dimensions, _available_space| SizeAndBaseline {
+                    size: Size { width: 50.0, height: 20.0 },
+                    first_baseline: None,
+                    content_size: Size { width: 50.0, height: 80.0 },
+                }),
+            )
+            .unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(node).unwrap();
+        assert_eq!(layout.size, Size { width: 50.0, height: 20.0 });
+        assert_eq!(layout.content_size, Size { width: 50.0, height: 80.0 });
+    }
+
+    #[test]
+    fn measure_func_without_content_size_has_no_content() {
+        let mut taffy = Taffy::new();
+        let node = taffy
+            .new_leaf_with_measure(
+                Style::default(),
+                MeasureFunc::Raw(|_known_dimensions, _available_space| Size { width: 50.0, height: 20.0 }),
+            )
+            .unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(node).unwrap();
+        assert_eq!(layout.size, Size { width: 50.0, height: 20.0 });
+        assert_eq!(layout.content_size, Size::ZERO);
+    }
+
+    #[test]
+    fn flexbox_content_size_is_union_of_child_margin_boxes() {
+        let mut taffy = Taffy::new();
+        let first = taffy
+            .new_leaf(Style {
+                size: Size::from_points(60.0, 20.0),
+                flex_shrink: 0.0,
+                margin: Rect { left: zero(), right: points(5.0), top: zero(), bottom: points(10.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let second = taffy
+            .new_leaf(Style { size: Size::from_points(60.0, 40.0), flex_shrink: 0.0, ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    size: Size::from_points(100.0, 30.0),
+                    padding: Rect { left: points(10.0), right: zero(), top: points(10.0), bottom: zero() },
+                    overflow: overflow(Overflow::Scroll, Overflow::Scroll),
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(root).unwrap();
+        assert_eq!(layout.size, Size { width: 100.0, height: 30.0 });
+        assert_eq!(layout.content_size, Size { width: 135.0, height: 50.0 });
+    }
+
+    #[test]
+    fn visible_overflow_of_children_is_propagated() {
+        let mut taffy = Taffy::new();
+        let grandchild = taffy
+            .new_leaf(Style { size: Size::from_points(200.0, 10.0), flex_shrink: 0.0, ..Default::default() })
+            .unwrap();
+        let visible_child = taffy
+            .new_with_children(
+                Style {
+                    size: Size::from_points(50.0, 10.0),
+                    margin: Rect { left: points(10.0), right: zero(), top: zero(), bottom: zero() },
+                    ..Default::default()
+                },
+                &[grandchild],
+            )
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[visible_child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(visible_child).unwrap().content_size, Size { width: 200.0, height: 10.0 });
+        assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 210.0, height: 10.0 });
+    }
+
+    #[test]
+    fn clipped_overflow_of_children_is_not_propagated() {
+        let mut taffy = Taffy::new();
+        let grandchild = taffy
+            .new_leaf(Style { size: Size::from_points(200.0, 100.0), flex_shrink: 0.0, ..Default::default() })
+            .unwrap();
+        let clipping_child = taffy
+            .new_with_children(
+                Style {
+                    size: Size::from_points(50.0, 10.0),
+                    overflow: overflow(Overflow::Hidden, Overflow::Clip),
+                    ..Default::default()
+                },
+                &[grandchild],
+            )
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[clipping_child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(clipping_child).unwrap().content_size, Size { width: 200.0, height: 100.0 });
+        assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 50.0, height: 10.0 });
+    }
+
+    #[test]
+    fn absolutely_positioned_children_contribute_to_content_size() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                position: Position::Absolute,
+                size: Size::from_points(20.0, 20.0),
+                inset: Rect { left: points(90.0), right: auto(), top: points(70.0), bottom: auto() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_points(50.0, 50.0), ..Default::default() }, &[child])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 110.0, height: 90.0 });
+    }
+
+    #[cfg(feature = "grid")]
+    #[test]
+    fn grid_content_size_includes_overflowing_tracks() {
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style::default()).unwrap();
+        let second = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size::from_points(100.0, 50.0),
+                    grid_template_columns: vec![points(80.0), points(80.0)],
+                    grid_template_rows: vec![points(60.0)],
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(root).unwrap();
+        assert_eq!(layout.size, Size { width: 100.0, height: 50.0 });
+        assert_eq!(layout.content_size, Size { width: 160.0, height: 60.0 });
+    }
+
+    #[cfg(feature = "block_layout")]
+    #[test]
+    fn block_content_size_includes_last_margin() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size::from_points(50.0, 80.0),
+                margin: Rect { left: zero(), right: zero(), top: points(10.0), bottom: points(20.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Block, size: Size::from_points(100.0, 50.0), ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 50.0, height: 110.0 });
+    }
+}
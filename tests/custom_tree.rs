@@ -0,0 +1,232 @@
+#[cfg(test)]
+mod custom_tree {
+    use taffy::error::TaffyResult;
+    use taffy::layout::{Cache, Layout, SizeAndBaseline, CACHE_SIZE};
+    use taffy::prelude::*;
+    use taffy::tree::LayoutTree;
+
+    /// A tree whose nodes are stored in a `Vec` and identified by their index into it
+    struct VecTree {
+        nodes: Vec<VecNode>,
+    }
+
+    struct VecNode {
+        style: Style,
+        parent: Option<usize>,
+        children: Vec<usize>,
+        layout: Layout,
+        final_layout: Layout,
+        cache: [Option<Cache>; CACHE_SIZE],
+    }
+
+    impl VecTree {
+        fn new() -> Self {
+            Self { nodes: Vec::new() }
+        }
+
+        fn add(&mut self, style: Style, children: &[usize]) -> usize {
+            let id = self.nodes.len();
+            for &child in children {
+                self.nodes[child].parent = Some(id);
+            }
+            self.nodes.push(VecNode {
+                style,
+                parent: None,
+                children: children.to_vec(),
+                layout: Layout::new(),
+                final_layout: Layout::new(),
+                cache: [None; CACHE_SIZE],
+            });
+            id
+        }
+    }
+
+    impl LayoutTree for VecTree {
+        type NodeId = usize;
+
+        type ChildIter<'a> = core::iter::Copied<core::slice::Iter<'a, usize>>;
+
+        fn children(&self, node: usize) -> Self::ChildIter<'_> {
+            self.nodes[node].children.iter().copied()
+        }
+
+        fn child_count(&self, node: usize) -> usize {
+            self.nodes[node].children.len()
+        }
+
+        fn is_childless(&self, node: usize) -> bool {
+            self.nodes[node].children.is_empty()
+        }
+
+        fn child(&self, node: usize, index: usize) -> usize {
+            self.nodes[node].children[index]
+        }
+
+        fn parent(&self, node: usize) -> Option<usize> {
+            self.nodes[node].parent
+        }
+
+        fn style(&self, node: usize) -> &Style {
+            &self.nodes[node].style
+        }
+
+        fn layout(&self, node: usize) -> &Layout {
+            &self.nodes[node].layout
+        }
+
+        fn layout_mut(&mut self, node: usize) -> &mut Layout {
+            &mut self.nodes[node].layout
+        }
+
+        fn final_layout_mut(&mut self, node: usize) -> &mut Layout {
+            &mut self.nodes[node].final_layout
+        }
+
+        fn mark_dirty(&mut self, node: usize) -> TaffyResult<()> {
+            self.nodes[node].cache = [None; CACHE_SIZE];
+            if let Some(parent) = self.nodes[node].parent {
+                self.mark_dirty(parent)?;
+            }
+            Ok(())
+        }
+
+        fn measure_node(
+            &mut self,
+            _node: usize,
+            _known_dimensions: Size<Option<f32>>,
+            _available_space: Size<AvailableSpace>,
+        ) -> SizeAndBaseline {
+            SizeAndBaseline::ZERO
+        }
+
+        fn needs_measure(&self, _node: usize) -> bool {
+            false
+        }
+
+        fn cache_mut(&mut self, node: usize, index: usize) -> &mut Option<Cache> {
+            &mut self.nodes[node].cache[index]
+        }
+
+        /// Stacks the children vertically at their max-content size, with a gap of 5 between each child
+        fn compute_custom_layout(
+            &mut self,
+            node: usize,
+            known_dimensions: Size<Option<f32>>,
+            _available_space: Size<AvailableSpace>,
+            run_mode: RunMode,
+            _sizing_mode: SizingMode,
+        ) -> SizeAndBaseline {
+            let mut size = Size::ZERO;
+            for index in 0..self.child_count(node) {
+                let child = self.child(node, index);
+                let child_size =
+                    compute_node_layout(self, child, Size::NONE, Size::MAX_CONTENT, run_mode, SizingMode::InherentSize)
+                        .size;
+                if run_mode == RunMode::PeformLayout {
+                    let layout = self.layout_mut(child);
+                    layout.order = index as u32;
+                    layout.size = child_size;
+                    layout.location = Point { x: 0.0, y: size.height };
+                }
+                size.width = size.width.max(child_size.width);
+                size.height += child_size.height + 5.0;
+            }
+            let size = Size {
+                width: known_dimensions.width.unwrap_or(size.width),
+                height: known_dimensions.height.unwrap_or(size.height),
+            };
+            SizeAndBaseline::from_size(size)
+        }
+    }
+
+    #[test]
+    fn flexbox_layout_of_vec_tree() {
+        let mut tree = VecTree::new();
+        let first = tree.add(Style { flex_grow: 1.0, ..Default::default() }, &[]);
+        let second = tree.add(Style { size: Size::from_points(30.0, 20.0), ..Default::default() }, &[]);
+        let root = tree.add(Style { size: Size::from_points(100.0, 50.0), ..Default::default() }, &[first, second]);
+        taffy::compute_layout(&mut tree, root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(tree.layout(root).size, Size { width: 100.0, height: 50.0 });
+        assert_eq!(tree.layout(first).size, Size { width: 70.0, height: 50.0 });
+        assert_eq!(tree.layout(second).location, Point { x: 70.0, y: 0.0 });
+        assert_eq!(tree.layout(second).size, Size { width: 30.0, height: 20.0 });
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn grid_layout_of_vec_tree() {
+        let mut tree = VecTree::new();
+        let first = tree.add(Style::default(), &[]);
+        let second = tree.add(Style::default(), &[]);
+        let root = tree.add(
+            Style {
+                display: Display::Grid,
+                size: Size::from_points(100.0, 50.0),
+                grid_template_columns: vec![points(40.0), flex(1.0)],
+                ..Default::default()
+            },
+            &[first, second],
+        );
+        taffy::compute_layout(&mut tree, root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(tree.layout(first).size, Size { width: 40.0, height: 50.0 });
+        assert_eq!(tree.layout(second).location, Point { x: 40.0, y: 0.0 });
+        assert_eq!(tree.layout(second).size, Size { width: 60.0, height: 50.0 });
+    }
+
+    #[test]
+    fn custom_layout_of_vec_tree() {
+        let mut tree = VecTree::new();
+        let first = tree.add(Style { size: Size::from_points(30.0, 10.0), ..Default::default() }, &[]);
+        let leaf = tree.add(Style { size: Size::from_points(50.0, 20.0), ..Default::default() }, &[]);
+        let second = tree.add(Style::default(), &[leaf]);
+        let custom = tree.add(Style { display: Display::Custom, ..Default::default() }, &[first, second]);
+        let root = tree.add(Style { size: Size::from_points(100.0, 100.0), ..Default::default() }, &[custom]);
+        taffy::compute_layout(&mut tree, root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(tree.layout(custom).size, Size { width: 50.0, height: 100.0 });
+        assert_eq!(tree.layout(first).location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(tree.layout(first).size, Size { width: 30.0, height: 10.0 });
+        assert_eq!(tree.layout(second).location, Point { x: 0.0, y: 15.0 });
+        assert_eq!(tree.layout(second).size, Size { width: 50.0, height: 20.0 });
+        assert_eq!(tree.layout(leaf).size, Size { width: 50.0, height: 20.0 });
+    }
+
+    #[test]
+    fn custom_display_defaults_to_leaf_layout() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(Style { size: Size::from_points(50.0, 50.0), ..Default::default() }).unwrap();
+        let custom = taffy
+            .new_with_children(
+                Style { display: Display::Custom, size: Size::from_points(20.0, 10.0), ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[custom]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(custom).unwrap().size, Size { width: 20.0, height: 10.0 });
+        assert_eq!(taffy.layout(child).unwrap().size, Size::ZERO);
+    }
+
+    #[test]
+    fn custom_display_hides_previously_laid_out_children_by_default() {
+        let mut taffy = Taffy::new();
+        let grandchild = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let child = taffy
+            .new_with_children(Style { size: Size::from_points(50.0, 50.0), ..Default::default() }, &[grandchild])
+            .unwrap();
+        let container = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[container]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 50.0, height: 50.0 });
+
+        taffy.set_style(container, Style { display: Display::Custom, ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(container).unwrap().size, Size::ZERO);
+        assert_eq!(taffy.layout(child).unwrap().size, Size::ZERO);
+        assert_eq!(taffy.layout(grandchild).unwrap().size, Size::ZERO);
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod direction {
+    use taffy::prelude::*;
+
+    #[test]
+    fn direction_is_inherited_from_ancestors() {
+        let mut taffy = Taffy::new();
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        let child = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+        let root =
+            taffy.new_with_children(Style { direction: Some(Direction::Rtl), ..Default::default() }, &[child]).unwrap();
+        let ltr_leaf = taffy.new_leaf(Style { direction: Some(Direction::Ltr), ..Default::default() }).unwrap();
+        taffy.add_child(child, ltr_leaf).unwrap();
+
+        assert_eq!(taffy.direction(root), Direction::Rtl);
+        assert_eq!(taffy.direction(leaf), Direction::Rtl);
+        assert_eq!(taffy.direction(ltr_leaf), Direction::Ltr);
+    }
+
+    #[test]
+    fn root_direction_defaults_to_ltr() {
+        let mut taffy = Taffy::new();
+        let root = taffy.new_leaf(Style::default()).unwrap();
+
+        assert_eq!(taffy.direction(root), Direction::Ltr);
+    }
+
+    #[test]
+    fn margins_are_reported_as_physical_edges() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size::from_points(50.0, 50.0),
+                margin: Rect { left: points(5.0), right: points(15.0), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { direction: Some(Direction::Rtl), size: Size::from_points(200.0, 50.0), ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(child).unwrap();
+        assert_eq!(layout.location.x, 135.0);
+        assert_eq!((layout.margin.left, layout.margin.right), (5.0, 15.0));
+    }
+
+    #[test]
+    fn changing_direction_relayouts_descendants() {
+        let mut taffy = Taffy::new();
+        let leaf = taffy.new_leaf(Style { size: Size::from_points(20.0, 10.0), ..Default::default() }).unwrap();
+        let child = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 10.0), ..Default::default() }, &[leaf])
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 10.0), ..Default::default() }, &[child])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(leaf).unwrap().location.x, 0.0);
+
+        taffy
+            .set_style(
+                root,
+                Style { direction: Some(Direction::Rtl), size: Size::from_points(100.0, 10.0), ..Default::default() },
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(leaf).unwrap().location.x, 80.0);
+    }
+
+    #[test]
+    fn reparenting_into_a_container_with_a_different_direction_relayouts_descendants() {
+        let mut taffy = Taffy::new();
+        let leaf = taffy.new_leaf(Style { size: Size::from_points(20.0, 10.0), ..Default::default() }).unwrap();
+        let child = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 10.0), ..Default::default() }, &[leaf])
+            .unwrap();
+        let ltr_parent = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 10.0), ..Default::default() }, &[child])
+            .unwrap();
+        let rtl_parent = taffy
+            .new_leaf(Style {
+                direction: Some(Direction::Rtl),
+                size: Size::from_points(100.0, 10.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { flex_direction: FlexDirection::Column, ..Default::default() },
+                &[ltr_parent, rtl_parent],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(leaf).unwrap().location.x, 0.0);
+
+        taffy.remove_child(ltr_parent, child).unwrap();
+        taffy.add_child(rtl_parent, child).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(leaf).unwrap().location.x, 80.0);
+    }
+}
@@ -0,0 +1,93 @@
+#[test]
+fn calc_margin_and_padding() {
+    use slotmap::Key;
+    #[allow(unused_imports)]
+    use taffy::{layout::Layout, prelude::*};
+    let mut taffy = taffy::Taffy::new();
+    let node00 = taffy
+        .new_leaf(taffy::style::Style {
+            flex_grow: 1f32,
+            margin: taffy::geometry::Rect {
+                left: taffy::style::LengthPercentageAuto::from(taffy::style::CalcLength {
+                    points: -10f32,
+                    percent: 0.1f32,
+                    min: f32::NEG_INFINITY,
+                    max: f32::INFINITY,
+                }),
+                right: zero(),
+                top: zero(),
+                bottom: zero(),
+            },
+            ..Default::default()
+        })
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(200f32),
+                    height: taffy::style::Dimension::Points(100f32),
+                },
+                padding: taffy::geometry::Rect {
+                    left: taffy::style::LengthPercentage::from(taffy::style::CalcLength {
+                        points: 5f32,
+                        percent: 0.1f32,
+                        min: f32::NEG_INFINITY,
+                        max: f32::INFINITY,
+                    }),
+                    right: taffy::style::LengthPercentage::from(taffy::style::CalcLength {
+                        points: 5f32,
+                        percent: 0.1f32,
+                        min: f32::NEG_INFINITY,
+                        max: f32::INFINITY,
+                    }),
+                    top: taffy::style::LengthPercentage::from(taffy::style::CalcLength {
+                        points: 5f32,
+                        percent: 0.1f32,
+                        min: f32::NEG_INFINITY,
+                        max: f32::INFINITY,
+                    }),
+                    bottom: taffy::style::LengthPercentage::from(taffy::style::CalcLength {
+                        points: 5f32,
+                        percent: 0.1f32,
+                        min: f32::NEG_INFINITY,
+                        max: f32::INFINITY,
+                    }),
+                },
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(200f32),
+                    height: taffy::style::Dimension::Points(100f32),
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::MAX_CONTENT).unwrap();
+    println!("\nComputed tree:");
+    taffy::debug::print_tree(&taffy, node);
+    println!();
+    let Layout { size, location, .. } = taffy.layout(node).unwrap();
+    assert_eq!(size.width, 200f32, "width of node {:?}. Expected {}. Actual {}", node.data(), 200f32, size.width);
+    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node.data(), 100f32, size.height);
+    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node.data(), 0f32, location.x);
+    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node.data(), 0f32, location.y);
+    let Layout { size, location, .. } = taffy.layout(node0).unwrap();
+    assert_eq!(size.width, 200f32, "width of node {:?}. Expected {}. Actual {}", node0.data(), 200f32, size.width);
+    assert_eq!(size.height, 100f32, "height of node {:?}. Expected {}. Actual {}", node0.data(), 100f32, size.height);
+    assert_eq!(location.x, 0f32, "x of node {:?}. Expected {}. Actual {}", node0.data(), 0f32, location.x);
+    assert_eq!(location.y, 0f32, "y of node {:?}. Expected {}. Actual {}", node0.data(), 0f32, location.y);
+    let Layout { size, location, .. } = taffy.layout(node00).unwrap();
+    assert_eq!(size.width, 145f32, "width of node {:?}. Expected {}. Actual {}", node00.data(), 145f32, size.width);
+    assert_eq!(size.height, 50f32, "height of node {:?}. Expected {}. Actual {}", node00.data(), 50f32, size.height);
+    assert_eq!(location.x, 30f32, "x of node {:?}. Expected {}. Actual {}", node00.data(), 30f32, location.x);
+    assert_eq!(location.y, 25f32, "y of node {:?}. Expected {}. Actual {}", node00.data(), 25f32, location.y);
+}
@@ -59,6 +59,9 @@ fn measure_standard_text(
         WritingMode::Vertical => Size { width: block_size, height: inline_size },
     }
 }
+mod absolute_containing_block_inset_right_bottom;
+mod absolute_containing_block_is_nearest_positioned_ancestor;
+mod absolute_containing_block_is_static_root;
 mod absolute_layout_align_items_and_justify_content_center;
 mod absolute_layout_align_items_and_justify_content_center_and_bottom_position;
 mod absolute_layout_align_items_and_justify_content_center_and_left_position;
@@ -80,6 +83,7 @@ mod absolute_layout_width_height_end_bottom;
 mod absolute_layout_width_height_start_top;
 mod absolute_layout_width_height_start_top_end_bottom;
 mod absolute_layout_within_border;
+mod absolute_static_position_with_static_parent;
 mod align_baseline;
 mod align_baseline_child_multiline;
 mod align_baseline_nested_child;
@@ -110,17 +114,76 @@ mod align_self_flex_end;
 mod align_self_flex_end_override_flex_start;
 mod align_self_flex_start;
 mod align_strech_should_size_based_on_parent;
+#[cfg(feature = "block_layout")]
+mod block_absolute_child;
+#[cfg(feature = "block_layout")]
+mod block_auto_width_fits_content;
+#[cfg(feature = "block_layout")]
+mod block_basic;
+#[cfg(feature = "block_layout")]
+mod block_direction_rtl;
+#[cfg(feature = "block_layout")]
+mod block_direction_rtl_absolute;
+#[cfg(feature = "block_layout")]
+mod block_display_none_child;
+#[cfg(feature = "block_layout")]
+mod block_margin_auto;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_negative_margins;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_siblings;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_through_empty_child;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_with_children_blocked_by_overflow;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_with_children_blocked_by_padding;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_with_first_child;
+#[cfg(feature = "block_layout")]
+mod block_margin_collapse_with_last_child;
+#[cfg(feature = "block_layout")]
+mod block_min_max_size;
+#[cfg(feature = "block_layout")]
+mod block_nested;
+#[cfg(feature = "block_layout")]
+mod block_padding_border;
+#[cfg(feature = "block_layout")]
+mod block_percentage_sizes;
+#[cfg(feature = "block_layout")]
+mod block_relative_inset;
+#[cfg(feature = "block_layout")]
+mod block_relative_position_conflicting;
+#[cfg(feature = "block_layout")]
+mod block_text_wraps_to_container_width;
 mod border_center_child;
 mod border_flex_child;
 mod border_no_child;
 mod border_stretch_child;
+mod calc_clamp;
+mod calc_gap;
+mod calc_inset;
+mod calc_margin_and_padding;
+mod calc_max;
+mod calc_min;
+mod calc_width_percent_minus_points;
 mod child_min_max_width_flexing;
 mod container_with_unsized_child;
+mod direction_rtl_absolute;
+mod direction_rtl_auto_margin;
+mod direction_rtl_column;
+mod direction_rtl_inherited;
+mod direction_rtl_justify_content_end;
+mod direction_rtl_relative_inset;
+mod direction_rtl_row;
+mod direction_rtl_row_reverse;
 mod display_none;
 mod display_none_fixed_size;
 mod display_none_with_child;
 mod display_none_with_margin;
 mod display_none_with_position;
+mod fixed_is_positioned_relative_to_root;
+mod fixed_size_from_insets;
 mod flex_basis_and_main_dimen_set_when_flexing;
 mod flex_basis_flex_grow_column;
 mod flex_basis_flex_grow_row;
@@ -161,6 +224,9 @@ mod flex_grow_within_constrained_min_column;
 mod flex_grow_within_constrained_min_max_column;
 mod flex_grow_within_constrained_min_row;
 mod flex_grow_within_max_width;
+mod flex_order;
+mod flex_order_equal_values_keep_source_order;
+mod flex_order_row_reverse;
 mod flex_root_ignored;
 mod flex_shrink_by_outer_margin_with_max_size;
 mod flex_shrink_flex_grow_child_flex_shrink_other_child;
@@ -204,6 +270,8 @@ mod gap_row_gap_row_wrap_child_margins;
 #[cfg(feature = "grid")]
 mod grid_absolute_align_self_sized_all;
 #[cfg(feature = "grid")]
+mod grid_absolute_child_of_static_grid;
+#[cfg(feature = "grid")]
 mod grid_absolute_column_end;
 #[cfg(feature = "grid")]
 mod grid_absolute_column_start;
@@ -264,6 +332,14 @@ mod grid_align_content_start;
 #[cfg(feature = "grid")]
 mod grid_align_content_start_with_padding_border;
 #[cfg(feature = "grid")]
+mod grid_align_items_baseline;
+#[cfg(feature = "grid")]
+mod grid_align_items_baseline_child_margin;
+#[cfg(feature = "grid")]
+mod grid_align_items_baseline_multiple_rows;
+#[cfg(feature = "grid")]
+mod grid_align_items_baseline_nested_child;
+#[cfg(feature = "grid")]
 mod grid_align_items_sized_center;
 #[cfg(feature = "grid")]
 mod grid_align_items_sized_end;
@@ -272,6 +348,8 @@ mod grid_align_items_sized_start;
 #[cfg(feature = "grid")]
 mod grid_align_items_sized_stretch;
 #[cfg(feature = "grid")]
+mod grid_align_self_baseline;
+#[cfg(feature = "grid")]
 mod grid_align_self_sized_all;
 #[cfg(feature = "grid")]
 mod grid_auto_columns_fixed_width;
@@ -296,6 +374,10 @@ mod grid_basic_with_overflow;
 #[cfg(feature = "grid")]
 mod grid_basic_with_padding;
 #[cfg(feature = "grid")]
+mod grid_direction_rtl;
+#[cfg(feature = "grid")]
+mod grid_direction_rtl_justify_self_start;
+#[cfg(feature = "grid")]
 mod grid_fit_content_points_argument;
 #[cfg(feature = "grid")]
 mod grid_fit_content_points_max_content;
@@ -402,8 +484,18 @@ mod grid_min_max_column_fixed_width_below_range;
 #[cfg(feature = "grid")]
 mod grid_min_max_column_fixed_width_within_range;
 #[cfg(feature = "grid")]
+mod grid_order_auto_placement;
+#[cfg(feature = "grid")]
+mod grid_order_definite_placement_first;
+#[cfg(feature = "grid")]
 mod grid_out_of_order_items;
 #[cfg(feature = "grid")]
+mod grid_overflow_hidden_item_min_size_zero;
+#[cfg(feature = "grid")]
+mod grid_overflow_scroll_container_reserves_scrollbar;
+#[cfg(feature = "grid")]
+mod grid_relative_position_conflicting;
+#[cfg(feature = "grid")]
 mod grid_relayout_vertical_text;
 #[cfg(feature = "grid")]
 mod grid_size_child_fixed_tracks;
@@ -490,7 +582,11 @@ mod min_width_overrides_width_on_root;
 mod nested_overflowing_child;
 mod nested_overflowing_child_in_constraint_parent;
 mod overflow_cross_axis;
+mod overflow_hidden_flex_item_min_size_zero;
 mod overflow_main_axis;
+mod overflow_scroll_flex_container_reserves_scrollbar;
+mod overflow_scroll_flex_item_auto_size_includes_scrollbar;
+mod overflow_visible_flex_item_min_size_content;
 mod padding_align_end_child;
 mod padding_center_child;
 mod padding_flex_child;
@@ -519,6 +615,12 @@ mod percentage_size_based_on_parent_inner_size;
 mod percentage_size_of_flex_basis;
 mod percentage_width_height;
 mod percentage_width_height_undefined_parent_size;
+mod position_static_ignores_inset;
+mod relative_position_conflicting_left_right;
+mod relative_position_conflicting_left_right_rtl;
+mod relative_position_conflicting_top_bottom;
+mod relative_position_percentage;
+mod relative_position_right_bottom;
 mod relative_position_should_not_nudge_siblings;
 mod rounding_flex_basis_flex_grow_row_prime_number_width;
 mod rounding_flex_basis_flex_grow_row_width_of_100;
@@ -534,6 +636,9 @@ mod size_defined_by_child;
 mod size_defined_by_child_with_border;
 mod size_defined_by_child_with_padding;
 mod size_defined_by_grand_child;
+mod units_em;
+mod units_em_padding_and_gap;
+mod units_rem;
 mod width_smaller_then_content_with_flex_grow_large_size;
 mod width_smaller_then_content_with_flex_grow_small_size;
 mod width_smaller_then_content_with_flex_grow_unconstraint_size;
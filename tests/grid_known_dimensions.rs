@@ -0,0 +1,67 @@
+#[cfg(feature = "grid")]
+mod common;
+
+#[cfg(feature = "grid")]
+mod grid_known_dimensions {
+    use crate::common::layout_of;
+    use taffy::prelude::*;
+
+    #[test]
+    fn grid_uses_the_size_determined_by_its_flex_container() {
+        let mut taffy = Taffy::new();
+        let a = taffy.new_leaf(Style::default()).unwrap();
+        let b = taffy.new_leaf(Style::default()).unwrap();
+        let grid = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    flex_grow: 1.0,
+                    flex_basis: zero(),
+                    grid_template_columns: vec![flex(1.0), flex(1.0)],
+                    ..Default::default()
+                },
+                &[a, b],
+            )
+            .unwrap();
+        let sibling = taffy.new_leaf(Style { size: Size::from_points(100.0, 10.0), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_points(300.0, 100.0), ..Default::default() }, &[grid, sibling])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The grid is stretched to the height of the flex line, and its columns share the width it was grown to
+        assert_eq!(layout_of(&taffy, grid), (200.0, 100.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, a), (100.0, 100.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, b), (100.0, 100.0, 100.0, 0.0));
+        assert_eq!(layout_of(&taffy, sibling), (100.0, 10.0, 200.0, 0.0));
+    }
+
+    #[test]
+    fn grid_known_dimensions_override_its_size_styles() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(Style::default()).unwrap();
+        let grid = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size { width: points(50.0), height: auto() },
+                    flex_grow: 1.0,
+                    flex_shrink: 0.0,
+                    grid_template_rows: vec![points(30.0)],
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size { width: points(120.0), height: auto() }, ..Default::default() },
+                &[grid],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, grid), (120.0, 30.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, child), (120.0, 30.0, 0.0, 0.0));
+    }
+}
@@ -0,0 +1,230 @@
+#[cfg(feature = "grid")]
+mod common;
+
+#[cfg(feature = "grid")]
+mod grid_masonry {
+    use crate::common::layout_of;
+    use taffy::node::MeasureFunc;
+    use taffy::prelude::*;
+
+    fn leaf_with_height(taffy: &mut Taffy, height: f32) -> Node {
+        taffy.new_leaf(Style { size: Size { width: auto(), height: points(height) }, ..Default::default() }).unwrap()
+    }
+
+    #[test]
+    fn items_are_placed_in_the_shortest_column() {
+        let mut taffy = Taffy::new();
+        let heights = [30.0, 50.0, 20.0, 10.0, 40.0];
+        let items: Vec<_> = heights.iter().map(|&height| leaf_with_height(&mut taffy, height)).collect();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(50.0), points(50.0), points(50.0)],
+                    grid_template_rows: vec![masonry()],
+                    gap: Size { width: points(10.0), height: points(10.0) },
+                    ..Default::default()
+                },
+                &items,
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, root), (170.0, 80.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, items[0]), (50.0, 30.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, items[1]), (50.0, 50.0, 60.0, 0.0));
+        assert_eq!(layout_of(&taffy, items[2]), (50.0, 20.0, 120.0, 0.0));
+        assert_eq!(layout_of(&taffy, items[3]), (50.0, 10.0, 120.0, 30.0));
+        assert_eq!(layout_of(&taffy, items[4]), (50.0, 40.0, 0.0, 40.0));
+    }
+
+    #[test]
+    fn definitely_placed_items_are_placed_first() {
+        let mut taffy = Taffy::new();
+        let first = leaf_with_height(&mut taffy, 20.0);
+        let second_column = taffy
+            .new_leaf(Style {
+                size: Size { width: auto(), height: points(10.0) },
+                grid_column: line(2),
+                ..Default::default()
+            })
+            .unwrap();
+        let spanning = taffy
+            .new_leaf(Style {
+                size: Size { width: auto(), height: points(5.0) },
+                grid_column: span(2),
+                ..Default::default()
+            })
+            .unwrap();
+        let last = leaf_with_height(&mut taffy, 30.0);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(40.0), points(40.0)],
+                    grid_template_rows: vec![masonry()],
+                    ..Default::default()
+                },
+                &[first, second_column, spanning, last],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, root), (80.0, 55.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, second_column), (40.0, 10.0, 40.0, 0.0));
+        assert_eq!(layout_of(&taffy, first), (40.0, 20.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, spanning), (80.0, 5.0, 0.0, 20.0));
+        // Both columns are equally short, so the item goes into the first of them
+        assert_eq!(layout_of(&taffy, last), (40.0, 30.0, 0.0, 25.0));
+    }
+
+    #[test]
+    fn auto_placed_items_contribute_to_every_column() {
+        let mut taffy = Taffy::new();
+        let narrow = taffy
+            .new_leaf(Style { size: Size { width: points(30.0), height: points(10.0) }, ..Default::default() })
+            .unwrap();
+        let wide = taffy
+            .new_leaf(Style { size: Size { width: points(60.0), height: points(10.0) }, ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![auto(), auto()],
+                    grid_template_rows: vec![masonry()],
+                    ..Default::default()
+                },
+                &[narrow, wide],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, root), (120.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, narrow), (30.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, wide), (60.0, 10.0, 60.0, 0.0));
+    }
+
+    #[test]
+    fn item_heights_depend_on_the_width_of_their_column() {
+        let mut taffy = Taffy::new();
+        // Text-like content whose height depends on the width that it wraps to
+        let text = taffy
+            .new_leaf_with_measure(
+                Style::default(),
+                MeasureFunc::Raw(|known_dimensions, available_space| {
+                    let width = known_dimensions.width.unwrap_or(match available_space.width {
+                        AvailableSpace::Definite(width) => width.min(200.0),
+                        AvailableSpace::MinContent => 20.0,
+                        AvailableSpace::MaxContent => 200.0,
+                    });
+                    Size { width, height: (200.0f32 / width).ceil() * 10.0 }
+                }),
+            )
+            .unwrap();
+        let other = leaf_with_height(&mut taffy, 15.0);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(50.0), points(100.0)],
+                    grid_template_rows: vec![masonry()],
+                    ..Default::default()
+                },
+                &[text, other],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, text), (50.0, 40.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, other), (100.0, 15.0, 50.0, 0.0));
+        assert_eq!(layout_of(&taffy, root), (150.0, 40.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn masonry_columns() {
+        let mut taffy = Taffy::new();
+        let widths = [30.0, 10.0, 15.0];
+        let items: Vec<_> = widths
+            .iter()
+            .map(|&width| {
+                taffy.new_leaf(Style { size: Size { width: points(width), height: auto() }, ..Default::default() })
+            })
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![masonry()],
+                    grid_template_rows: vec![points(20.0), points(20.0)],
+                    ..Default::default()
+                },
+                &items,
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, root), (30.0, 40.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, items[0]), (30.0, 20.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, items[1]), (10.0, 20.0, 0.0, 20.0));
+        assert_eq!(layout_of(&taffy, items[2]), (15.0, 20.0, 10.0, 20.0));
+    }
+
+    #[test]
+    fn masonry_content_is_aligned() {
+        let mut taffy = Taffy::new();
+        let a = leaf_with_height(&mut taffy, 30.0);
+        let b = leaf_with_height(&mut taffy, 50.0);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size { width: points(200.0), height: points(100.0) },
+                    grid_template_columns: vec![points(50.0), points(50.0)],
+                    grid_template_rows: vec![masonry()],
+                    gap: Size { width: points(10.0), height: zero() },
+                    justify_content: Some(JustifyContent::Center),
+                    align_content: Some(AlignContent::End),
+                    ..Default::default()
+                },
+                &[a, b],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, a), (50.0, 30.0, 45.0, 50.0));
+        assert_eq!(layout_of(&taffy, b), (50.0, 50.0, 105.0, 50.0));
+    }
+
+    #[test]
+    fn absolutely_positioned_children_span_the_masonry_axis() {
+        let mut taffy = Taffy::new();
+        let a = leaf_with_height(&mut taffy, 30.0);
+        let b = leaf_with_height(&mut taffy, 50.0);
+        let overlay = taffy
+            .new_leaf(Style {
+                position: Position::Absolute,
+                grid_column: line(2),
+                grid_row: line(3),
+                inset: Rect { left: zero(), right: zero(), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(50.0), points(50.0)],
+                    grid_template_rows: vec![masonry()],
+                    ..Default::default()
+                },
+                &[a, b, overlay],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, root), (100.0, 50.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, overlay), (50.0, 50.0, 50.0, 0.0));
+    }
+}
@@ -0,0 +1,303 @@
+#[cfg(feature = "grid")]
+mod common;
+
+#[cfg(feature = "grid")]
+mod grid_named_lines {
+    use crate::common::layout_of;
+    use taffy::prelude::*;
+
+    const HEADER: GridLineName = GridLineName::new(1).unwrap();
+    const SIDEBAR: GridLineName = GridLineName::new(2).unwrap();
+    const MAIN: GridLineName = GridLineName::new(3).unwrap();
+
+    #[test]
+    fn grid_template_areas_place_items() {
+        let mut taffy = Taffy::new();
+        let header = taffy
+            .new_leaf(Style { grid_row: named_area(HEADER), grid_column: named_area(HEADER), ..Default::default() })
+            .unwrap();
+        let sidebar = taffy
+            .new_leaf(Style { grid_row: named_area(SIDEBAR), grid_column: named_area(SIDEBAR), ..Default::default() })
+            .unwrap();
+        let main = taffy
+            .new_leaf(Style { grid_row: named_area(MAIN), grid_column: named_area(MAIN), ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(100.0), points(100.0), points(100.0)],
+                    grid_template_rows: vec![points(40.0), points(100.0)],
+                    grid_template_areas: vec![
+                        GridTemplateArea { name: HEADER, row_start: 1, row_end: 2, column_start: 1, column_end: 4 },
+                        GridTemplateArea { name: SIDEBAR, row_start: 2, row_end: 3, column_start: 1, column_end: 2 },
+                        GridTemplateArea { name: MAIN, row_start: 2, row_end: 3, column_start: 2, column_end: 4 },
+                    ],
+                    ..Default::default()
+                },
+                // Insert the items in reverse order to check that they are not auto-placed
+                &[main, sidebar, header],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, root), (300.0, 140.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, header), (300.0, 40.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, sidebar), (100.0, 100.0, 0.0, 40.0));
+        assert_eq!(layout_of(&taffy, main), (200.0, 100.0, 100.0, 40.0));
+    }
+
+    #[test]
+    fn grid_named_lines_place_items() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                grid_column: Line { start: named_line(SIDEBAR, 1), end: named_line(MAIN, -1) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![
+                        points(50.0),
+                        line_names(vec![SIDEBAR]),
+                        points(60.0),
+                        line_names(vec![MAIN]),
+                        points(70.0),
+                        line_names(vec![MAIN]),
+                        points(80.0),
+                    ],
+                    grid_template_rows: vec![points(10.0)],
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, root), (260.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, child), (130.0, 10.0, 50.0, 0.0));
+    }
+
+    #[test]
+    fn grid_named_lines_after_count_repeat() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style { grid_column: Line { start: named_line(MAIN, 1), end: line(-1) }, ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![
+                        repeat(3, vec![points(10.0), points(20.0)]),
+                        line_names(vec![MAIN]),
+                        points(40.0),
+                        points(50.0),
+                    ],
+                    grid_template_rows: vec![points(10.0)],
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, root), (180.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, child), (90.0, 10.0, 90.0, 0.0));
+    }
+
+    #[test]
+    fn grid_named_span_counts_named_lines() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style { grid_column: Line { start: line(1), end: named_span(MAIN, 2) }, ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![
+                        points(10.0),
+                        line_names(vec![MAIN]),
+                        points(20.0),
+                        points(30.0),
+                        line_names(vec![MAIN]),
+                        points(40.0),
+                    ],
+                    grid_template_rows: vec![points(10.0)],
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, child), (60.0, 10.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn grid_template_areas_name_their_edge_lines() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                grid_column: Line {
+                    start: named_line(GridTemplateArea::start_line_name(MAIN), 1),
+                    end: named_line(GridTemplateArea::end_line_name(MAIN), 1),
+                },
+                grid_row: named_line(GridTemplateArea::end_line_name(HEADER), 1),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(100.0), points(100.0), points(100.0)],
+                    grid_template_rows: vec![points(40.0), points(100.0)],
+                    grid_template_areas: vec![
+                        GridTemplateArea { name: HEADER, row_start: 1, row_end: 2, column_start: 1, column_end: 4 },
+                        GridTemplateArea { name: MAIN, row_start: 2, row_end: 3, column_start: 2, column_end: 4 },
+                    ],
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The "MAIN-start" and "MAIN-end" column lines and the "HEADER-end" row line are named by the areas
+        assert_eq!(layout_of(&taffy, child), (200.0, 100.0, 100.0, 40.0));
+    }
+
+    #[test]
+    fn grid_edge_line_names_define_implicit_areas() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style { grid_row: named_area(MAIN), grid_column: named_area(MAIN), ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![
+                        points(100.0),
+                        line_names(vec![GridTemplateArea::start_line_name(MAIN)]),
+                        points(100.0),
+                        points(100.0),
+                        line_names(vec![GridTemplateArea::end_line_name(MAIN)]),
+                    ],
+                    grid_template_rows: vec![
+                        points(40.0),
+                        line_names(vec![GridTemplateArea::start_line_name(MAIN)]),
+                        points(100.0),
+                        line_names(vec![GridTemplateArea::end_line_name(MAIN)]),
+                    ],
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The explicitly named "MAIN-start" and "MAIN-end" lines define an implicit "MAIN" area
+        assert_eq!(layout_of(&taffy, child), (200.0, 100.0, 100.0, 40.0));
+    }
+
+    #[test]
+    fn grid_missing_named_line_creates_implicit_tracks() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(Style { grid_column: named_line(MAIN, 2), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(100.0)],
+                    grid_template_rows: vec![points(10.0)],
+                    grid_auto_columns: vec![points(20.0)],
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The missing name is assumed to name every implicit line, so the 2nd "MAIN" line is the 2nd line after
+        // the end of the explicit grid
+        assert_eq!(layout_of(&taffy, root), (160.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, child), (20.0, 10.0, 140.0, 0.0));
+    }
+
+    #[test]
+    fn grid_template_areas_extend_explicit_grid() {
+        let mut taffy = Taffy::new();
+        let main = taffy
+            .new_leaf(Style { grid_row: named_area(MAIN), grid_column: named_area(MAIN), ..Default::default() })
+            .unwrap();
+        let last = taffy
+            .new_leaf(Style { grid_column: Line { start: line(-2), end: line(-1) }, ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(100.0)],
+                    grid_template_rows: vec![points(10.0), points(10.0)],
+                    grid_auto_columns: vec![points(40.0)],
+                    grid_template_areas: vec![GridTemplateArea {
+                        name: MAIN,
+                        row_start: 1,
+                        row_end: 2,
+                        column_start: 1,
+                        column_end: 3,
+                    }],
+                    ..Default::default()
+                },
+                &[main, last],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The second column is part of the explicit grid (so line -1 is its end line) but is sized by grid-auto-columns
+        assert_eq!(layout_of(&taffy, root), (140.0, 20.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, main), (140.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, last), (40.0, 10.0, 100.0, 10.0));
+    }
+
+    #[test]
+    fn grid_absolute_child_in_named_area() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                position: Position::Absolute,
+                grid_row: named_area(MAIN),
+                grid_column: named_area(MAIN),
+                inset: Rect::zero(),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(100.0), points(50.0)],
+                    grid_template_rows: vec![points(20.0), points(30.0)],
+                    grid_template_areas: vec![GridTemplateArea {
+                        name: MAIN,
+                        row_start: 2,
+                        row_end: 3,
+                        column_start: 2,
+                        column_end: 3,
+                    }],
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, child), (50.0, 30.0, 100.0, 20.0));
+    }
+}
@@ -0,0 +1,261 @@
+#[cfg(feature = "grid")]
+mod common;
+
+#[cfg(feature = "grid")]
+mod grid_subgrid {
+    use crate::common::layout_of;
+    use taffy::node::MeasureFunc;
+    use taffy::prelude::*;
+
+    fn fixed_leaf(taffy: &mut Taffy, width: f32, height: f32) -> Node {
+        taffy
+            .new_leaf(Style { size: Size { width: points(width), height: points(height) }, ..Default::default() })
+            .unwrap()
+    }
+
+    fn column_subgrid() -> Style {
+        Style {
+            display: Display::Grid,
+            grid_column: Line { start: line(1), end: line(-1) },
+            grid_template_columns: vec![subgrid()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sibling_subgrids_share_the_columns_of_their_parent() {
+        let mut taffy = Taffy::new();
+        let a1 = fixed_leaf(&mut taffy, 30.0, 10.0);
+        let a2 = fixed_leaf(&mut taffy, 10.0, 10.0);
+        let b1 = fixed_leaf(&mut taffy, 10.0, 20.0);
+        let b2 = fixed_leaf(&mut taffy, 50.0, 10.0);
+        let row_a = taffy.new_with_children(column_subgrid(), &[a1, a2]).unwrap();
+        let row_b = taffy.new_with_children(column_subgrid(), &[b1, b2]).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![auto(), auto()],
+                    gap: Size { width: points(5.0), height: zero() },
+                    ..Default::default()
+                },
+                &[row_a, row_b],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, root), (85.0, 30.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, row_a), (85.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, row_b), (85.0, 20.0, 0.0, 10.0));
+        assert_eq!(layout_of(&taffy, a1), (30.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, a2), (10.0, 10.0, 35.0, 0.0));
+        assert_eq!(layout_of(&taffy, b1), (10.0, 20.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, b2), (50.0, 10.0, 35.0, 0.0));
+    }
+
+    #[test]
+    fn subgrid_items_contribute_to_the_tracks_of_the_parent() {
+        let mut taffy = Taffy::new();
+        let first = fixed_leaf(&mut taffy, 15.0, 10.0);
+        let a = fixed_leaf(&mut taffy, 30.0, 10.0);
+        let b = fixed_leaf(&mut taffy, 10.0, 10.0);
+        let subgrid = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_column: Line { start: line(2), end: span(2) },
+                    grid_template_columns: vec![subgrid()],
+                    margin: Rect { left: points(2.0), right: zero(), top: zero(), bottom: zero() },
+                    padding: Rect { left: points(7.0), right: points(3.0), top: points(1.0), bottom: points(1.0) },
+                    ..Default::default()
+                },
+                &[a, b],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![auto(), auto(), auto()],
+                    gap: Size { width: points(5.0), height: zero() },
+                    ..Default::default()
+                },
+                &[first, subgrid],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The columns make room for the margin and padding of the subgrid at its edges: 15 | 2 + 7 + 30 | 10 + 3
+        assert_eq!(layout_of(&taffy, root), (15.0 + 5.0 + 39.0 + 5.0 + 13.0, 12.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, subgrid), (39.0 + 5.0 + 13.0 - 2.0, 12.0, 22.0, 0.0));
+        assert_eq!(layout_of(&taffy, a), (30.0, 10.0, 7.0, 1.0));
+        assert_eq!(layout_of(&taffy, b), (10.0, 10.0, 42.0, 1.0));
+    }
+
+    #[test]
+    fn subgrid_items_are_placed_within_the_inherited_lines() {
+        let mut taffy = Taffy::new();
+        let placed =
+            taffy.new_leaf(Style { grid_column: Line { start: line(2), end: line(3) }, ..Default::default() }).unwrap();
+        // Items placed outside of the inherited lines are clamped into them after placement, as a subgrid has no
+        // implicit tracks. This may make them overlap other items.
+        let clamped =
+            taffy.new_leaf(Style { grid_column: Line { start: line(5), end: span(2) }, ..Default::default() }).unwrap();
+        let auto_placed = taffy.new_leaf(Style::default()).unwrap();
+        let subgrid = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_column: Line { start: line(2), end: line(4) },
+                    grid_template_columns: vec![subgrid()],
+                    grid_auto_rows: vec![points(10.0)],
+                    ..Default::default()
+                },
+                &[placed, clamped, auto_placed],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(100.0), points(20.0), points(30.0), points(40.0)],
+                    ..Default::default()
+                },
+                &[subgrid],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, subgrid), (50.0, 20.0, 100.0, 0.0));
+        assert_eq!(layout_of(&taffy, placed), (30.0, 10.0, 20.0, 0.0));
+        assert_eq!(layout_of(&taffy, clamped), (30.0, 10.0, 20.0, 0.0));
+        // The auto-placement cursor was advanced past the hypothetical lines that the clamped item was placed at
+        assert_eq!(layout_of(&taffy, auto_placed), (20.0, 10.0, 0.0, 10.0));
+    }
+
+    #[test]
+    fn subgrid_is_sized_in_the_tracks_it_inherits() {
+        let mut taffy = Taffy::new();
+        // Text-like content whose height depends on the width that it wraps to
+        let text = taffy
+            .new_leaf_with_measure(
+                Style::default(),
+                MeasureFunc::Raw(|known_dimensions, available_space| {
+                    let width = known_dimensions.width.unwrap_or(match available_space.width {
+                        AvailableSpace::Definite(width) => width.min(200.0),
+                        AvailableSpace::MinContent => 20.0,
+                        AvailableSpace::MaxContent => 200.0,
+                    });
+                    Size { width, height: (200.0f32 / width).ceil() * 10.0 }
+                }),
+            )
+            .unwrap();
+        let other = fixed_leaf(&mut taffy, 10.0, 5.0);
+        let row = taffy.new_with_children(column_subgrid(), &[text, other]).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(50.0), auto()],
+                    ..Default::default()
+                },
+                &[row],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, row), (60.0, 40.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, text), (50.0, 40.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn nested_subgrids_inherit_the_tracks_of_the_outermost_grid() {
+        let mut taffy = Taffy::new();
+        let a = fixed_leaf(&mut taffy, 40.0, 25.0);
+        let b = fixed_leaf(&mut taffy, 10.0, 10.0);
+        let inner = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![subgrid()],
+                    grid_template_rows: vec![subgrid()],
+                    grid_column: Line { start: line(1), end: span(2) },
+                    grid_row: Line { start: line(1), end: span(2) },
+                    border: Rect { left: points(1.0), right: points(1.0), top: points(1.0), bottom: points(1.0) },
+                    ..Default::default()
+                },
+                &[a, b],
+            )
+            .unwrap();
+        let outer = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![subgrid()],
+                    grid_template_rows: vec![subgrid()],
+                    grid_column: Line { start: line(1), end: line(-1) },
+                    grid_row: Line { start: line(1), end: line(-1) },
+                    ..Default::default()
+                },
+                &[inner],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![auto(), auto()],
+                    grid_template_rows: vec![auto(), auto()],
+                    ..Default::default()
+                },
+                &[outer],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The bottom border of the inner subgrid is accounted for by the items in its last occupied row
+        assert_eq!(layout_of(&taffy, root), (52.0, 27.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, inner), (52.0, 27.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, a), (40.0, 25.0, 1.0, 1.0));
+        assert_eq!(layout_of(&taffy, b), (10.0, 10.0, 41.0, 1.0));
+    }
+
+    #[test]
+    fn subgrid_of_a_non_grid_has_no_explicit_tracks() {
+        let mut taffy = Taffy::new();
+        let a = fixed_leaf(&mut taffy, 30.0, 10.0);
+        let b = fixed_leaf(&mut taffy, 10.0, 10.0);
+        let grid = taffy.new_with_children(column_subgrid(), &[a, b]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[grid]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(layout_of(&taffy, grid), (30.0, 20.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, b), (10.0, 10.0, 0.0, 10.0));
+    }
+
+    #[test]
+    fn relayout_after_subgrid_item_changes() {
+        let mut taffy = Taffy::new();
+        let a = fixed_leaf(&mut taffy, 30.0, 10.0);
+        let b = fixed_leaf(&mut taffy, 10.0, 10.0);
+        let c = fixed_leaf(&mut taffy, 20.0, 10.0);
+        let d = fixed_leaf(&mut taffy, 20.0, 10.0);
+        let row_a = taffy.new_with_children(column_subgrid(), &[a, b]).unwrap();
+        let row_b = taffy.new_with_children(column_subgrid(), &[c, d]).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Grid, grid_template_columns: vec![auto(), auto()], ..Default::default() },
+                &[row_a, row_b],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(layout_of(&taffy, d), (20.0, 10.0, 30.0, 0.0));
+
+        taffy
+            .set_style(a, Style { size: Size { width: points(60.0), height: points(10.0) }, ..Default::default() })
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(layout_of(&taffy, root), (80.0, 20.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, d), (20.0, 10.0, 60.0, 0.0));
+    }
+}
@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod layout_box_model {
+    use taffy::prelude::*;
+
+    fn uniform<T: Copy>(value: T) -> Rect<T> {
+        Rect { left: value, right: value, top: value, bottom: value }
+    }
+
+    #[test]
+    fn root_padding_border_and_margin_are_resolved() {
+        let mut taffy = Taffy::new();
+        let root = taffy
+            .new_leaf(Style {
+                padding: uniform(LengthPercentage::Percent(0.1)),
+                border: uniform(LengthPercentage::Points(2.0)),
+                margin: uniform(LengthPercentageAuto::Points(3.0)),
+                ..Default::default()
+            })
+            .unwrap();
+        taffy
+            .compute_layout(root, Size { width: AvailableSpace::Definite(100.0), height: AvailableSpace::MaxContent })
+            .unwrap();
+
+        let layout = taffy.layout(root).unwrap();
+        assert_eq!(layout.padding, uniform(10.0));
+        assert_eq!(layout.border, uniform(2.0));
+        assert_eq!(layout.margin, uniform(3.0));
+    }
+
+    #[test]
+    fn flexbox_item_box_model_is_resolved() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size { width: points(50.0), height: points(50.0) },
+                padding: uniform(LengthPercentage::Percent(0.1)),
+                border: uniform(LengthPercentage::Points(5.0)),
+                margin: Rect { left: LengthPercentageAuto::Auto, ..uniform(LengthPercentageAuto::Points(4.0)) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size { width: points(200.0), height: points(100.0) }, ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(child).unwrap();
+        assert_eq!(layout.padding, uniform(20.0));
+        assert_eq!(layout.border, uniform(5.0));
+        // The auto margin takes up all of the free space in the main axis
+        assert_eq!(layout.margin, Rect { left: 146.0, right: 4.0, top: 4.0, bottom: 4.0 });
+        assert_eq!(layout.location.x, 146.0);
+    }
+
+    #[test]
+    fn flexbox_absolute_child_box_model_is_resolved() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                position: Position::Absolute,
+                size: Size { width: points(50.0), height: points(50.0) },
+                padding: uniform(LengthPercentage::Percent(0.1)),
+                margin: uniform(LengthPercentageAuto::Points(4.0)),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size { width: points(200.0), height: points(100.0) }, ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(child).unwrap();
+        assert_eq!(layout.padding, uniform(20.0));
+        assert_eq!(layout.margin, uniform(4.0));
+    }
+
+    #[cfg(feature = "grid")]
+    #[test]
+    fn grid_item_box_model_is_resolved() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size { width: points(40.0), height: points(40.0) },
+                padding: uniform(LengthPercentage::Percent(0.1)),
+                border: uniform(LengthPercentage::Points(1.0)),
+                margin: uniform(LengthPercentageAuto::Auto),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(100.0)],
+                    grid_template_rows: vec![points(100.0)],
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(child).unwrap();
+        assert_eq!(layout.padding, uniform(10.0));
+        assert_eq!(layout.border, uniform(1.0));
+        assert_eq!(layout.margin, uniform(30.0));
+        assert_eq!((layout.location.x, layout.location.y), (30.0, 30.0));
+    }
+
+    #[cfg(feature = "block_layout")]
+    #[test]
+    fn block_item_box_model_is_resolved() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size { width: points(50.0), height: points(10.0) },
+                padding: uniform(LengthPercentage::Percent(0.1)),
+                margin: Rect {
+                    left: LengthPercentageAuto::Auto,
+                    right: LengthPercentageAuto::Auto,
+                    top: LengthPercentageAuto::Points(5.0),
+                    bottom: LengthPercentageAuto::Points(5.0),
+                },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: points(200.0), height: auto() },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(child).unwrap();
+        assert_eq!(layout.padding, uniform(20.0));
+        assert_eq!(layout.margin, Rect { left: 75.0, right: 75.0, top: 5.0, bottom: 5.0 });
+    }
+
+    #[test]
+    fn box_model_is_rounded() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                padding: uniform(LengthPercentage::Points(10.4)),
+                border: uniform(LengthPercentage::Points(0.6)),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(child).unwrap();
+        assert_eq!(layout.padding, uniform(10.0));
+        assert_eq!(layout.border, uniform(1.0));
+    }
+
+    #[test]
+    fn box_model_is_rounded_cumulatively() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                padding: uniform(LengthPercentage::Points(0.4)),
+                border: uniform(LengthPercentage::Points(0.4)),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { padding: uniform(LengthPercentage::Points(0.3)), ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The child starts at 0.3, its border box edge at 0.7 and its content box at 1.1, which round to 0, 1 and 1
+        let layout = taffy.layout(child).unwrap();
+        assert_eq!(layout.border.left, 1.0);
+        assert_eq!(layout.padding.left, 0.0);
+        assert_eq!(layout.border.top, 1.0);
+        assert_eq!(layout.padding.top, 0.0);
+    }
+}
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod measure {
+    use taffy::layout::SizeAndBaseline;
     use taffy::node::MeasureFunc;
     use taffy::prelude::*;
 
@@ -474,4 +475,189 @@ mod measure {
 
         assert_eq!(NUM_MEASURES.load(Ordering::SeqCst), 2);
     }
+
+    #[test]
+    fn fully_sized_leaves_are_not_measured_unless_their_baseline_is_needed() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let mut taffy = Taffy::new();
+        static NUM_MEASURES: AtomicU32 = AtomicU32::new(0);
+        static NUM_BASELINE_MEASURES: AtomicU32 = AtomicU32::new(0);
+
+        let style = Style { size: Size::from_points(50.0, 50.0), ..Default::default() };
+        let leaf = taffy
+            .new_leaf_with_measure(
+                style.clone(),
+                MeasureFunc::Raw(|_known_dimensions, _available_space| {
+                    NUM_MEASURES.fetch_add(1, Ordering::SeqCst);
+                    Size::ZERO
+                }),
+            )
+            .unwrap();
+        let baseline_leaf = taffy
+            .new_leaf_with_measure(
+                style,
+                MeasureFunc::RawWithBaseline(|_known_dimensions, _available_space| {
+                    NUM_BASELINE_MEASURES.fetch_add(1, Ordering::SeqCst);
+                    SizeAndBaseline { size: Size::ZERO, first_baseline: Some(10.0), content_size: Size::ZERO }
+                }),
+            )
+            .unwrap();
+
+        taffy.compute_layout(leaf, Size::MAX_CONTENT).unwrap();
+        taffy.compute_layout(baseline_leaf, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(NUM_MEASURES.load(Ordering::SeqCst), 0);
+        assert_eq!(NUM_BASELINE_MEASURES.load(Ordering::SeqCst), 1);
+        assert_eq!(taffy.layout(baseline_leaf).unwrap().first_baseline, Some(10.0));
+    }
+
+    #[test]
+    fn grid_with_definite_size_is_only_laid_out_once() {
+        let mut taffy: Taffy<()> = Taffy::default();
+        let child = taffy.new_leaf_with_context(Style::default(), ()).unwrap();
+        let grid = taffy
+            .new_with_children(
+                Style { display: Display::Grid, size: Size::from_points(50.0, 20.0), ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+        let measure = |num_measures: &mut u32,
+                       _node: Node,
+                       _context: Option<&mut ()>,
+                       known_dimensions: Size<Option<f32>>,
+                       _available_space: Size<AvailableSpace>| {
+            *num_measures += 1;
+            Size { width: known_dimensions.width.unwrap_or(30.0), height: known_dimensions.height.unwrap_or(10.0) }
+        };
+
+        let mut num_measures_as_root = 0;
+        taffy.compute_layout_with_measure(grid, Size::MAX_CONTENT, &mut num_measures_as_root, measure).unwrap();
+
+        // The size of the grid is known from its styles, so its parent doesn't need to measure its children to size it
+        taffy.mark_dirty(child).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[grid]).unwrap();
+        let mut num_measures_as_child = 0;
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, &mut num_measures_as_child, measure).unwrap();
+
+        assert_eq!(taffy.layout(grid).unwrap().size, Size { width: 50.0, height: 20.0 });
+        assert_eq!(num_measures_as_child, num_measures_as_root);
+    }
+
+    #[test]
+    fn measure_with_context() {
+        /// A stand-in for a font system that measures text as 10 points per character on a single line
+        struct FontSystem {
+            num_measures: u32,
+        }
+
+        let mut taffy: Taffy<&str> = Taffy::default();
+        let hello = taffy.new_leaf_with_context(Style::default(), "hello").unwrap();
+        let world = taffy.new_leaf_with_context(Style::default(), "world!").unwrap();
+        let spacer = taffy.new_leaf(Style { size: Size::from_points(5.0, 5.0), ..Default::default() }).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[hello, spacer, world]).unwrap();
+
+        let mut font_system = FontSystem { num_measures: 0 };
+        let measure = |font_system: &mut FontSystem,
+                       _node: Node,
+                       text: Option<&mut &str>,
+                       known_dimensions: Size<Option<f32>>,
+                       _available_space: Size<AvailableSpace>| {
+            font_system.num_measures += 1;
+            let text = text.expect("only nodes with a context are measured");
+            Size {
+                width: known_dimensions.width.unwrap_or(text.len() as f32 * 10.0),
+                height: known_dimensions.height.unwrap_or(10.0),
+            }
+        };
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, &mut font_system, measure).unwrap();
+
+        assert_eq!(taffy.layout(hello).unwrap().size, Size { width: 50.0, height: 10.0 });
+        assert_eq!(taffy.layout(spacer).unwrap().location.x, 50.0);
+        assert_eq!(taffy.layout(world).unwrap().size, Size { width: 60.0, height: 10.0 });
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 115.0, height: 10.0 });
+        assert!(font_system.num_measures > 0);
+
+        // Changing a node's context and marking it as dirty causes it to be measured again
+        *taffy.get_node_context_mut(world).unwrap() = "world";
+        taffy.mark_dirty(world).unwrap();
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, &mut font_system, measure).unwrap();
+        assert_eq!(taffy.layout(world).unwrap().size.width, 50.0);
+    }
+
+    #[test]
+    fn measure_with_context_receives_the_measured_node() {
+        let mut taffy: Taffy<f32> = Taffy::default();
+        let child = taffy.new_leaf_with_context(Style::default(), 30.0).unwrap();
+        let measured_child = taffy
+            .new_leaf_with_measure(Style::default(), MeasureFunc::Raw(|_, _| Size { width: 7.0, height: 7.0 }))
+            .unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child, measured_child]).unwrap();
+
+        let mut measured_nodes = Vec::new();
+        taffy
+            .compute_layout_with_measure(
+                node,
+                Size::MAX_CONTENT,
+                &mut measured_nodes,
+                |measured_nodes, node, width, _, _| {
+                    measured_nodes.push(node);
+                    let size = Size { width: *width.unwrap(), height: 20.0 };
+                    SizeAndBaseline { size, first_baseline: Some(3.0), content_size: size }
+                },
+            )
+            .unwrap();
+
+        // Nodes with a measure function are measured with it instead
+        assert!(!measured_nodes.is_empty());
+        assert!(measured_nodes.iter().all(|&measured_node| measured_node == child));
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 30.0, height: 20.0 });
+        assert_eq!(taffy.layout(child).unwrap().first_baseline, Some(3.0));
+        assert_eq!(taffy.layout(measured_child).unwrap().size, Size { width: 7.0, height: 20.0 });
+    }
+
+    #[test]
+    fn measure_with_context_and_resolution_context() {
+        let mut taffy: Taffy<f32> = Taffy::default();
+        let child = taffy
+            .new_leaf_with_context(
+                Style {
+                    size: Size { width: Dimension::Vw(0.5), height: auto() },
+                    margin: Rect { left: zero(), right: zero(), top: LengthPercentageAuto::Rem(2.0), bottom: zero() },
+                    ..Default::default()
+                },
+                4.0,
+            )
+            .unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child]).unwrap();
+
+        // The measure function returns a height of 10 points per unit of the node's context
+        let context = ResolutionContext { viewport_size: Size { width: 400.0, height: 300.0 }, root_font_size: 10.0 };
+        let mut num_measures = 0;
+        taffy
+            .compute_layout_with_context_and_measure(
+                node,
+                Size::MAX_CONTENT,
+                context,
+                &mut num_measures,
+                |num_measures, _node, lines, known_dimensions, _available_space| {
+                    *num_measures += 1;
+                    Size { width: known_dimensions.width.unwrap_or(0.0), height: *lines.unwrap() * 10.0 }
+                },
+            )
+            .unwrap();
+
+        assert!(num_measures > 0);
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 200.0, height: 40.0 });
+        assert_eq!(taffy.layout(child).unwrap().location.y, 20.0);
+    }
+
+    #[test]
+    fn nodes_with_context_are_not_measured_by_compute_layout() {
+        let mut taffy: Taffy<f32> = Taffy::default();
+        let node = taffy.new_leaf_with_context(Style::default(), 30.0).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(node).unwrap().size, Size::ZERO);
+    }
 }
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod order {
+    use taffy::prelude::*;
+
+    fn painting_order(taffy: &Taffy, nodes: &[Node]) -> Vec<u32> {
+        nodes.iter().map(|node| taffy.layout(*node).unwrap().order).collect()
+    }
+
+    #[test]
+    fn flexbox_painting_order_follows_order_style() {
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style { order: 1, ..Default::default() }).unwrap();
+        let second = taffy.new_leaf(Style { order: -1, ..Default::default() }).unwrap();
+        let absolute = taffy.new_leaf(Style { position: Position::Absolute, ..Default::default() }).unwrap();
+        let third = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[first, second, absolute, third]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(painting_order(&taffy, &[first, second, absolute, third]), vec![3, 0, 1, 2]);
+    }
+
+    #[test]
+    fn flexbox_order_does_not_modify_child_list() {
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style { order: 1, ..Default::default() }).unwrap();
+        let second = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[first, second]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.children(root).unwrap(), vec![first, second]);
+    }
+
+    #[test]
+    fn changing_order_relayouts_parent() {
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let second = taffy.new_leaf(Style { size: Size::from_points(20.0, 10.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[first, second]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(first).unwrap().location.x, 0.0);
+
+        taffy.set_style(first, Style { order: 1, size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().location.x, 20.0);
+        assert_eq!(taffy.layout(second).unwrap().location.x, 0.0);
+        assert_eq!(painting_order(&taffy, &[first, second]), vec![1, 0]);
+    }
+
+    #[cfg(feature = "grid")]
+    #[test]
+    fn grid_painting_order_follows_order_style() {
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style { order: 2, ..Default::default() }).unwrap();
+        let absolute = taffy.new_leaf(Style { position: Position::Absolute, order: -1, ..Default::default() }).unwrap();
+        let second = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Grid, grid_template_columns: vec![points(10.0)], ..Default::default() },
+                &[first, absolute, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(painting_order(&taffy, &[first, absolute, second]), vec![2, 0, 1]);
+        // Auto-placement also follows the order style, so the second item is placed in the first row
+        assert_eq!(taffy.layout(second).unwrap().location.y, 0.0);
+    }
+}
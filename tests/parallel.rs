@@ -0,0 +1,207 @@
+#[cfg(all(test, feature = "parallel"))]
+mod parallel {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use taffy::error::TaffyResult;
+    use taffy::layout::{Cache, SizeAndBaseline};
+    use taffy::prelude::*;
+    use taffy::randomizable::Randomizeable;
+
+    /// Lays out a [`Taffy`] without laying out any subtrees concurrently
+    struct Sequential<'t>(&'t mut Taffy);
+
+    impl LayoutTree for Sequential<'_> {
+        type NodeId = Node;
+
+        type ChildIter<'a>
+            = <Taffy as LayoutTree>::ChildIter<'a>
+        where
+            Self: 'a;
+
+        fn children(&self, node: Node) -> Self::ChildIter<'_> {
+            LayoutTree::children(self.0, node)
+        }
+
+        fn child_count(&self, node: Node) -> usize {
+            LayoutTree::child_count(self.0, node)
+        }
+
+        fn is_childless(&self, node: Node) -> bool {
+            LayoutTree::is_childless(self.0, node)
+        }
+
+        fn child(&self, node: Node, index: usize) -> Node {
+            LayoutTree::child(self.0, node, index)
+        }
+
+        fn parent(&self, node: Node) -> Option<Node> {
+            LayoutTree::parent(self.0, node)
+        }
+
+        fn style(&self, node: Node) -> &Style {
+            LayoutTree::style(self.0, node)
+        }
+
+        fn layout(&self, node: Node) -> &Layout {
+            LayoutTree::layout(self.0, node)
+        }
+
+        fn layout_mut(&mut self, node: Node) -> &mut Layout {
+            LayoutTree::layout_mut(self.0, node)
+        }
+
+        fn final_layout_mut(&mut self, node: Node) -> &mut Layout {
+            LayoutTree::final_layout_mut(self.0, node)
+        }
+
+        fn mark_dirty(&mut self, node: Node) -> TaffyResult<()> {
+            LayoutTree::mark_dirty(self.0, node)
+        }
+
+        fn measure_node(
+            &mut self,
+            node: Node,
+            known_dimensions: Size<Option<f32>>,
+            available_space: Size<AvailableSpace>,
+        ) -> SizeAndBaseline {
+            LayoutTree::measure_node(self.0, node, known_dimensions, available_space)
+        }
+
+        fn needs_measure(&self, node: Node) -> bool {
+            LayoutTree::needs_measure(self.0, node)
+        }
+
+        fn cache_mut(&mut self, node: Node, index: usize) -> &mut Option<Cache> {
+            LayoutTree::cache_mut(self.0, node, index)
+        }
+    }
+
+    /// Computes the layout of `root` on a thread pool with several threads, as subtrees are never laid out
+    /// concurrently on a single thread (even if the machine running the tests has a single core)
+    fn compute_layout_concurrently(taffy: &mut Taffy, root: Node) {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+        pool.install(|| taffy.compute_layout(root, Size::MAX_CONTENT)).unwrap();
+    }
+
+    /// Builds the tree twice, and asserts that laying it out concurrently gives the same layout as laying it out
+    /// sequentially
+    fn assert_matches_sequential_layout(build: impl Fn(&mut Taffy) -> Node) {
+        let mut taffy = Taffy::new();
+        let root = build(&mut taffy);
+        compute_layout_concurrently(&mut taffy, root);
+
+        let mut sequential_taffy = Taffy::new();
+        let sequential_root = build(&mut sequential_taffy);
+        taffy::compute_layout(&mut Sequential(&mut sequential_taffy), sequential_root, Size::MAX_CONTENT).unwrap();
+
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            assert_eq!(taffy.layout(node).unwrap(), sequential_taffy.layout(node).unwrap(), "layout of {node:?}");
+            stack.extend(taffy.children(node).unwrap());
+        }
+    }
+
+    /// A container with four children that each stack 100 leaves of 10x10
+    fn build_columns(taffy: &mut Taffy, container_style: Style) -> (Node, Vec<Node>) {
+        let leaf_style = Style { size: Size::from_points(10.0, 10.0), ..Default::default() };
+        let columns: Vec<Node> = (0..4)
+            .map(|_| {
+                let leaves: Vec<Node> = (0..100).map(|_| taffy.new_leaf(leaf_style.clone()).unwrap()).collect();
+                let column_style = Style { flex_direction: FlexDirection::Column, ..Default::default() };
+                taffy.new_with_children(column_style, &leaves).unwrap()
+            })
+            .collect();
+        (taffy.new_with_children(container_style, &columns).unwrap(), columns)
+    }
+
+    #[test]
+    fn flex_items_are_laid_out_concurrently() {
+        let mut taffy = Taffy::new();
+        let (root, columns) = build_columns(&mut taffy, Style::default());
+        compute_layout_concurrently(&mut taffy, root);
+
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 40.0, height: 1000.0 });
+        for (index, &column) in columns.iter().enumerate() {
+            assert_eq!(taffy.layout(column).unwrap().location, Point { x: index as f32 * 10.0, y: 0.0 });
+            let last_leaf = taffy.child_at_index(column, 99).unwrap();
+            assert_eq!(taffy.layout(last_leaf).unwrap().location, Point { x: 0.0, y: 990.0 });
+        }
+
+        assert_matches_sequential_layout(|taffy| build_columns(taffy, Style::default()).0);
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn grid_items_are_laid_out_concurrently() {
+        let grid_style = Style {
+            display: Display::Grid,
+            grid_template_columns: vec![points(20.0), points(20.0)],
+            ..Default::default()
+        };
+        let mut taffy = Taffy::new();
+        let (root, columns) = build_columns(&mut taffy, grid_style.clone());
+        compute_layout_concurrently(&mut taffy, root);
+
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 40.0, height: 2000.0 });
+        assert_eq!(taffy.layout(columns[3]).unwrap().location, Point { x: 20.0, y: 1000.0 });
+        assert_eq!(taffy.layout(columns[3]).unwrap().size, Size { width: 20.0, height: 1000.0 });
+
+        assert_matches_sequential_layout(|taffy| build_columns(taffy, grid_style.clone()).0);
+    }
+
+    #[test]
+    fn relayout_of_concurrently_laid_out_subtree() {
+        let mut taffy = Taffy::new();
+        let (root, columns) = build_columns(&mut taffy, Style::default());
+        compute_layout_concurrently(&mut taffy, root);
+
+        let leaf = taffy.child_at_index(columns[1], 0).unwrap();
+        taffy.set_style(leaf, Style { size: Size::from_points(30.0, 20.0), ..Default::default() }).unwrap();
+        compute_layout_concurrently(&mut taffy, root);
+
+        assert_eq!(taffy.layout(columns[1]).unwrap().size, Size { width: 30.0, height: 1010.0 });
+        assert_eq!(taffy.layout(columns[2]).unwrap().location, Point { x: 40.0, y: 0.0 });
+        let last_leaf = taffy.child_at_index(columns[1], 99).unwrap();
+        assert_eq!(taffy.layout(last_leaf).unwrap().location, Point { x: 0.0, y: 1000.0 });
+    }
+
+    #[test]
+    fn changed_layouts_of_concurrently_laid_out_subtrees_are_reported() {
+        let mut taffy = Taffy::new();
+        let (root, columns) = build_columns(&mut taffy, Style::default());
+        taffy.set_layout_change_tracking(true);
+        compute_layout_concurrently(&mut taffy, root);
+
+        let leaf = taffy.child_at_index(columns[1], 99).unwrap();
+        taffy.set_style(leaf, Style { size: Size::from_points(30.0, 10.0), ..Default::default() }).unwrap();
+        compute_layout_concurrently(&mut taffy, root);
+
+        let changed: Vec<Node> = taffy.changed_layouts().collect();
+        assert!(changed.contains(&leaf));
+        assert!(changed.contains(&columns[1]));
+        assert!(changed.contains(&columns[2]));
+        assert!(!changed.contains(&columns[0]));
+    }
+
+    #[test]
+    fn random_tree_matches_sequential_layout() {
+        fn build_random_tree(taffy: &mut Taffy) -> Node {
+            let mut rng = ChaCha8Rng::seed_from_u64(12345);
+            let children: Vec<Node> = (0..10)
+                .map(|_| {
+                    let grandchildren: Vec<Node> = (0..10)
+                        .map(|_| {
+                            let leaves: Vec<Node> =
+                                (0..10).map(|_| taffy.new_leaf(Style::random(&mut rng)).unwrap()).collect();
+                            taffy.new_with_children(Style::random(&mut rng), &leaves).unwrap()
+                        })
+                        .collect();
+                    taffy.new_with_children(Style::random(&mut rng), &grandchildren).unwrap()
+                })
+                .collect();
+            taffy.new_with_children(Style::default(), &children).unwrap()
+        }
+
+        assert_matches_sequential_layout(build_random_tree);
+    }
+}
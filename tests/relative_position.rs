@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod relative_position {
+    use taffy::prelude::*;
+
+    #[test]
+    fn relatively_positioned_root_is_offset_within_available_space() {
+        let mut taffy = Taffy::new();
+        let root = taffy
+            .new_leaf(Style {
+                size: Size::from_points(50.0, 50.0),
+                inset: Rect {
+                    left: LengthPercentageAuto::Percent(0.1),
+                    right: auto(),
+                    top: auto(),
+                    bottom: points(5.0),
+                },
+                ..Default::default()
+            })
+            .unwrap();
+        taffy
+            .compute_layout(
+                root,
+                Size { width: AvailableSpace::Definite(200.0), height: AvailableSpace::Definite(100.0) },
+            )
+            .unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 20.0, y: -5.0 });
+    }
+
+    #[test]
+    fn relative_offset_contributes_to_content_size() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size::from_points(50.0, 50.0),
+                inset: Rect { left: points(80.0), right: auto(), top: points(10.0), bottom: auto() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 100.0), ..Default::default() }, &[child])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 130.0, height: 60.0 });
+    }
+}
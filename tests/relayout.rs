@@ -55,3 +55,150 @@ fn relayout() {
         assert_eq!(taffy.layout(node1).unwrap().location, initial1);
     }
 }
+
+#[test]
+fn relayout_only_changes_affected_nodes() {
+    use taffy::prelude::*;
+
+    let mut taffy = Taffy::new();
+    let grandchild = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+    let unchanged = taffy
+        .new_with_children(Style { size: Size::from_points(50.0, 50.0), ..Default::default() }, &[grandchild])
+        .unwrap();
+    let changed = taffy.new_leaf(Style { size: Size::from_points(20.0, 20.0), ..Default::default() }).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                flex_direction: FlexDirection::Column,
+                size: Size::from_points(100.0, 100.0),
+                ..Default::default()
+            },
+            &[changed, unchanged],
+        )
+        .unwrap();
+
+    taffy.set_layout_change_tracking(true);
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.changed_layouts().count(), 4);
+
+    // Nothing has changed, so nothing is laid out again
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.changed_layouts().count(), 0);
+
+    // Only the resized node and the sibling that it pushed down have moved or been resized (the content size of the
+    // root has changed, but not its size)
+    taffy.set_style(changed, Style { size: Size::from_points(20.0, 30.0), ..Default::default() }).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.changed_layouts().collect::<Vec<_>>(), vec![changed, unchanged]);
+    assert_eq!(taffy.layout(unchanged).unwrap().location.y, 30.0);
+    assert_eq!(taffy.layout(grandchild).unwrap().size, Size { width: 10.0, height: 10.0 });
+}
+
+#[test]
+fn relayout_restores_children_of_node_that_is_shown_again() {
+    use taffy::prelude::*;
+
+    let mut taffy = Taffy::new();
+    let grandchild = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+    let child = taffy.new_with_children(Style::default(), &[grandchild]).unwrap();
+    let container = taffy.new_with_children(Style::default(), &[child]).unwrap();
+    let root = taffy.new_with_children(Style::default(), &[container]).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    taffy.set_style(container, Style { display: Display::None, ..Default::default() }).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(grandchild).unwrap().size, Size::ZERO);
+
+    taffy.set_style(container, Style::default()).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.0, height: 10.0 });
+    assert_eq!(taffy.layout(grandchild).unwrap().size, Size { width: 10.0, height: 10.0 });
+}
+
+#[test]
+fn relayout_updates_absolute_descendants_of_skipped_nodes() {
+    use taffy::prelude::*;
+
+    let mut taffy = Taffy::new();
+    let absolute = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            size: Size::from_points(10.0, 10.0),
+            inset: Rect { left: auto(), right: points(0.0), top: points(0.0), bottom: auto() },
+            ..Default::default()
+        })
+        .unwrap();
+    let static_parent = taffy
+        .new_with_children(
+            Style { position: Position::Static, size: Size::from_points(50.0, 50.0), ..Default::default() },
+            &[absolute],
+        )
+        .unwrap();
+    let root = taffy
+        .new_with_children(Style { size: Size::from_points(200.0, 100.0), ..Default::default() }, &[static_parent])
+        .unwrap();
+    taffy.set_layout_change_tracking(true);
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(absolute).unwrap().location.x, 190.0);
+
+    // The static parent doesn't need to be laid out again, but the absolutely positioned node does
+    taffy.set_style(root, Style { size: Size::from_points(300.0, 100.0), ..Default::default() }).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.changed_layouts().collect::<Vec<_>>(), vec![root, absolute]);
+    assert_eq!(taffy.layout(absolute).unwrap().location.x, 290.0);
+}
+
+#[test]
+fn layout_changes_are_only_tracked_when_enabled() {
+    use taffy::prelude::*;
+
+    let mut taffy = Taffy::new();
+    let first = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+    let second = taffy.new_leaf(Style { size: Size::from_points(20.0, 10.0), ..Default::default() }).unwrap();
+    let root = taffy.new_with_children(Style::default(), &[first, second]).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.changed_layouts().count(), 0);
+
+    // Reordering the children changes their painting order and location, but not their size
+    taffy.set_layout_change_tracking(true);
+    taffy.set_style(first, Style { order: 1, size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.changed_layouts().collect::<Vec<_>>(), vec![first, second]);
+    assert_eq!(taffy.layout(first).unwrap().order, 1);
+    assert_eq!(taffy.layout(second).unwrap().location.x, 0.0);
+}
+
+#[test]
+fn relayout_collapses_updated_margins_of_nested_block_children() {
+    use taffy::prelude::*;
+
+    let mut taffy = Taffy::new();
+    let inner_style = |margin_top: f32| Style {
+        display: Display::Block,
+        size: Size { width: auto(), height: points(10.0) },
+        margin: Rect { left: zero(), right: zero(), top: points(margin_top), bottom: zero() },
+        ..Default::default()
+    };
+    let inner = taffy.new_leaf(inner_style(20.0)).unwrap();
+    let middle = taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[inner]).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                padding: Rect { left: zero(), right: zero(), top: points(1.0), bottom: zero() },
+                ..Default::default()
+            },
+            &[middle],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(middle).unwrap().location.y, 21.0);
+    assert_eq!(taffy.layout(inner).unwrap().location.y, 0.0);
+
+    // The margins cached for the middle node are recomputed once its child has changed
+    taffy.set_style(inner, inner_style(30.0)).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(middle).unwrap().location.y, 31.0);
+    assert_eq!(taffy.layout(inner).unwrap().location.y, 0.0);
+    assert_eq!(taffy.layout(root).unwrap().size.height, 41.0);
+}
@@ -0,0 +1,152 @@
+#[cfg(all(test, feature = "text"))]
+mod text {
+    use std::sync::Arc;
+
+    use taffy::prelude::*;
+    use taffy::text::{TextContext, TextNode, TextStyle};
+
+    /// A context with only the bundled monospace font, so that every character has the same advance
+    fn context() -> TextContext {
+        TextContext::with_fonts([include_bytes!("fonts/DejaVuSansMono.ttf").to_vec()])
+    }
+
+    fn text_style() -> TextStyle {
+        TextStyle { font_family: Some("DejaVu Sans Mono".into()), font_size: 10.0, line_height: 12.0 }
+    }
+
+    /// The advance of each character of the bundled font at the font size of `text_style`
+    fn advance(context: &TextContext) -> f32 {
+        TextNode::new(context, "x", text_style()).layout(None).width
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 0.01, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn min_content_width_is_the_longest_word() {
+        let context = context();
+        let text = TextNode::new(&context, "hello wonderful world", text_style());
+        let size = text.measure(Size::NONE, Size::MIN_CONTENT).size;
+
+        assert_eq!(size.width, (9.0 * advance(&context)).ceil());
+        assert_eq!(size.height, 36.0);
+    }
+
+    #[test]
+    fn max_content_width_is_the_unwrapped_line() {
+        let context = context();
+        let text = TextNode::new(&context, "hello wonderful world", text_style());
+        let size = text.measure(Size::NONE, Size::MAX_CONTENT).size;
+
+        assert_eq!(size.width, (21.0 * advance(&context)).ceil());
+        assert_eq!(size.height, 12.0);
+    }
+
+    #[test]
+    fn definite_width_wraps_the_text() {
+        let context = context();
+        let text = TextNode::new(&context, "hello wonderful world", text_style());
+        let available_width = AvailableSpace::Definite(16.0 * advance(&context));
+        let size = text.measure(Size::NONE, Size { width: available_width, height: AvailableSpace::MaxContent }).size;
+
+        assert_eq!(size.width, (15.0 * advance(&context)).ceil());
+        assert_eq!(size.height, 24.0);
+    }
+
+    #[test]
+    fn known_width_wraps_the_text() {
+        let context = context();
+        let text = TextNode::new(&context, "hello wonderful world", text_style());
+        let known_dimensions = Size { width: Some(10.0 * advance(&context)), height: None };
+        let measured = text.measure(known_dimensions, Size::MAX_CONTENT);
+
+        assert_eq!(measured.size.width, known_dimensions.width.unwrap());
+        assert_eq!(measured.size.height, 36.0);
+        assert_eq!(measured.first_baseline, text.layout(known_dimensions.width).first_baseline());
+    }
+
+    #[test]
+    fn lines_are_broken_at_hard_line_breaks() {
+        let context = context();
+        let text = TextNode::new(&context, "ab\ncde", text_style());
+        let layout = text.layout(None);
+
+        assert_eq!(layout.lines.len(), 2);
+        assert_eq!(layout.lines[0].text_range, 0..2);
+        assert_eq!(layout.lines[1].text_range, 3..6);
+        assert_close(layout.width, 3.0 * advance(&context));
+        assert_eq!(layout.height, 24.0);
+    }
+
+    #[test]
+    fn line_boxes_expose_glyphs_and_baselines() {
+        let context = context();
+        let text = TextNode::new(&context, "hello world", text_style());
+        let layout = text.layout(Some(0.0));
+
+        assert_eq!(layout.lines.len(), 2);
+        let second_line = &layout.lines[1];
+        assert_eq!(second_line.text_range, 6..11);
+        assert_eq!(second_line.top, 12.0);
+        assert_eq!(second_line.height, 12.0);
+        assert!(second_line.baseline > second_line.top && second_line.baseline < second_line.top + 12.0);
+        assert_close(second_line.baseline - second_line.top, layout.first_baseline().unwrap());
+
+        let glyph_ranges: Vec<_> = second_line.glyphs.iter().map(|glyph| glyph.text_range.clone()).collect();
+        assert_eq!(glyph_ranges, vec![6..7, 7..8, 8..9, 9..10, 10..11]);
+        assert_close(second_line.glyphs[2].x, 2.0 * advance(&context));
+    }
+
+    #[test]
+    fn layouts_are_cached_per_width() {
+        let context = context();
+        let text = TextNode::new(&context, "hello wonderful world", text_style());
+
+        let min_content = text.layout(Some(0.0));
+        let max_content = text.layout(None);
+        assert!(Arc::ptr_eq(&min_content, &text.layout(Some(0.0))));
+        assert!(Arc::ptr_eq(&max_content, &text.layout(None)));
+        assert!(!Arc::ptr_eq(&min_content, &max_content));
+    }
+
+    #[test]
+    fn empty_text_has_no_lines() {
+        let text = TextNode::new(&context(), "", text_style());
+
+        assert_eq!(text.measure(Size::NONE, Size::MAX_CONTENT).size, Size::ZERO);
+        assert!(text.layout(None).lines.is_empty());
+    }
+
+    #[test]
+    fn text_node_in_flexbox() {
+        let context = context();
+        let advance = advance(&context);
+        let text = TextNode::new(&context, "hello wonderful world", text_style());
+
+        let mut taffy = Taffy::new();
+        let text_leaf = taffy.new_leaf_with_measure(Style::default(), text.measure_func()).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    size: Size { width: Dimension::Points((16.0 * advance).ceil()), height: Dimension::Auto },
+                    align_items: Some(AlignItems::Baseline),
+                    ..Default::default()
+                },
+                &[text_leaf],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = *taffy.layout(text_leaf).unwrap();
+        assert_eq!(layout.size.width, (15.0 * advance).ceil());
+        assert_eq!(layout.size.height, 24.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 24.0);
+
+        // Wrapping the text to the laid out width gives the lines that were measured (the final layout is rounded)
+        let lines = text.layout(Some(layout.size.width));
+        assert_eq!(lines.lines.len(), 2);
+        assert_eq!(lines.lines[0].text_range, 0..15);
+        assert_eq!(layout.first_baseline, lines.first_baseline().map(f32::round));
+    }
+}
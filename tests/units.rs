@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod units {
+    use taffy::prelude::*;
+
+    fn context(width: f32, height: f32, root_font_size: f32) -> ResolutionContext {
+        ResolutionContext { viewport_size: Size { width, height }, root_font_size }
+    }
+
+    #[test]
+    fn viewport_units_resolve_against_the_context() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size { width: Dimension::Vw(0.5), height: Dimension::Vh(0.25) },
+                margin: Rect { left: LengthPercentageAuto::Vw(0.1), right: zero(), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        taffy.compute_layout_with_context(root, Size::MAX_CONTENT, context(400.0, 200.0, 16.0)).unwrap();
+
+        let layout = taffy.layout(child).unwrap();
+        assert_eq!(layout.size, Size { width: 200.0, height: 50.0 });
+        assert_eq!(layout.location.x, 40.0);
+    }
+
+    #[test]
+    fn viewport_units_default_to_the_available_space() {
+        let mut taffy = Taffy::new();
+        let root = taffy
+            .new_leaf(Style {
+                size: Size { width: Dimension::Vw(0.5), height: Dimension::Vh(1.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        taffy.compute_layout(root, Size { width: points(300.0), height: AvailableSpace::MaxContent }).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 150.0, height: 0.0 });
+    }
+
+    #[test]
+    fn font_relative_units_use_inherited_font_size() {
+        let mut taffy = Taffy::new();
+        let em_child = taffy
+            .new_leaf(Style {
+                size: Size { width: Dimension::Em(2.0), height: Dimension::Rem(1.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let container =
+            taffy.new_with_children(Style { font_size: Some(20.0), ..Default::default() }, &[em_child]).unwrap();
+        let root_em_child = taffy
+            .new_leaf(Style { size: Size { width: Dimension::Em(2.0), height: auto() }, ..Default::default() })
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[container, root_em_child]).unwrap();
+        taffy.compute_layout_with_context(root, Size::MAX_CONTENT, context(0.0, 0.0, 10.0)).unwrap();
+
+        assert_eq!(taffy.font_size(em_child), Some(20.0));
+        assert_eq!(taffy.font_size(root_em_child), None);
+        assert_eq!(taffy.layout(em_child).unwrap().size, Size { width: 40.0, height: 10.0 });
+        assert_eq!(taffy.layout(root_em_child).unwrap().size.width, 20.0);
+    }
+
+    #[test]
+    fn resizing_the_viewport_relayouts_without_restyling() {
+        let mut taffy = Taffy::new();
+        let fixed = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let relative = taffy
+            .new_leaf(Style { size: Size { width: Dimension::Vw(0.5), height: points(10.0) }, ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { flex_direction: FlexDirection::Column, ..Default::default() },
+                &[fixed, relative],
+            )
+            .unwrap();
+        taffy.compute_layout_with_context(root, Size::MAX_CONTENT, context(100.0, 100.0, 16.0)).unwrap();
+        assert_eq!(taffy.layout(relative).unwrap().size.width, 50.0);
+
+        taffy.compute_layout_with_context(root, Size::MAX_CONTENT, context(100.0, 100.0, 16.0)).unwrap();
+        assert!(!taffy.dirty(root).unwrap());
+
+        // Resizing the viewport doesn't require the styles to be updated
+        let resolved = taffy.style(relative).unwrap().clone();
+        taffy.compute_layout_with_context(root, Size::MAX_CONTENT, context(300.0, 100.0, 16.0)).unwrap();
+        assert_eq!(taffy.style(relative).unwrap(), &resolved);
+        assert_eq!(taffy.layout(relative).unwrap().size.width, 150.0);
+        assert_eq!(taffy.layout(root).unwrap().size.width, 150.0);
+    }
+
+    #[test]
+    fn changing_the_inherited_font_size_resolves_em_lengths_again() {
+        let mut taffy = Taffy::new();
+        let em_child = taffy
+            .new_leaf(Style { size: Size { width: Dimension::Em(2.0), height: auto() }, ..Default::default() })
+            .unwrap();
+        let container =
+            taffy.new_with_children(Style { font_size: Some(10.0), ..Default::default() }, &[em_child]).unwrap();
+        let other_container =
+            taffy.new_with_children(Style { font_size: Some(30.0), ..Default::default() }, &[]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[container, other_container]).unwrap();
+        taffy.compute_layout_with_context(root, Size::MAX_CONTENT, context(0.0, 0.0, 16.0)).unwrap();
+        assert_eq!(taffy.layout(em_child).unwrap().size.width, 20.0);
+
+        taffy.set_style(container, Style { font_size: Some(15.0), ..Default::default() }).unwrap();
+        taffy.compute_layout_with_context(root, Size::MAX_CONTENT, context(0.0, 0.0, 16.0)).unwrap();
+        assert_eq!(taffy.layout(em_child).unwrap().size.width, 30.0);
+
+        taffy.remove_child(container, em_child).unwrap();
+        taffy.add_child(other_container, em_child).unwrap();
+        taffy.compute_layout_with_context(root, Size::MAX_CONTENT, context(0.0, 0.0, 16.0)).unwrap();
+        assert_eq!(taffy.layout(em_child).unwrap().size.width, 60.0);
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn font_relative_grid_tracks() {
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style::default()).unwrap();
+        let second = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    font_size: Some(10.0),
+                    size: Size::from_points(200.0, 50.0),
+                    grid_template_columns: vec![
+                        minmax(
+                            MinTrackSizingFunction::Fixed(LengthPercentage::Em(5.0)),
+                            MaxTrackSizingFunction::Fixed(LengthPercentage::Em(5.0)),
+                        ),
+                        flex(1.0),
+                    ],
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().size.width, 50.0);
+        assert_eq!(taffy.layout(second).unwrap().location.x, 50.0);
+        assert_eq!(taffy.layout(second).unwrap().size.width, 150.0);
+    }
+}
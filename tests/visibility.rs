@@ -0,0 +1,262 @@
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod visibility {
+    use crate::common::layout_of;
+    use taffy::prelude::*;
+
+    #[test]
+    fn visibility_is_inherited_from_ancestors() {
+        let mut taffy = Taffy::new();
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        let child = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+        let root = taffy
+            .new_with_children(Style { visibility: Some(Visibility::Hidden), ..Default::default() }, &[child])
+            .unwrap();
+        let visible_leaf =
+            taffy.new_leaf(Style { visibility: Some(Visibility::Visible), ..Default::default() }).unwrap();
+        taffy.add_child(child, visible_leaf).unwrap();
+
+        assert_eq!(taffy.visibility(root), Visibility::Hidden);
+        assert_eq!(taffy.visibility(leaf), Visibility::Hidden);
+        assert_eq!(taffy.visibility(visible_leaf), Visibility::Visible);
+    }
+
+    #[test]
+    fn hidden_nodes_are_laid_out_and_report_their_visibility() {
+        let mut taffy = Taffy::new();
+        let leaf = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let hidden = taffy
+            .new_with_children(
+                Style {
+                    visibility: Some(Visibility::Hidden),
+                    size: Size::from_points(50.0, 20.0),
+                    ..Default::default()
+                },
+                &[leaf],
+            )
+            .unwrap();
+        let sibling = taffy.new_leaf(Style { size: Size::from_points(30.0, 20.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[hidden, sibling]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Hidden nodes still take up space
+        assert_eq!(layout_of(&taffy, hidden), (50.0, 20.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, leaf), (10.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, sibling), (30.0, 20.0, 50.0, 0.0));
+        assert_eq!(taffy.layout(root).unwrap().visibility, Visibility::Visible);
+        assert_eq!(taffy.layout(hidden).unwrap().visibility, Visibility::Hidden);
+        assert_eq!(taffy.layout(leaf).unwrap().visibility, Visibility::Hidden);
+    }
+
+    #[test]
+    fn collapsed_flex_item_takes_no_main_space_but_keeps_line_cross_size() {
+        let mut taffy = Taffy::new();
+        let collapsed = taffy
+            .new_leaf(Style {
+                visibility: Some(Visibility::Collapse),
+                size: Size::from_points(40.0, 60.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let first = taffy.new_leaf(Style { size: Size::from_points(20.0, 10.0), ..Default::default() }).unwrap();
+        let second = taffy.new_leaf(Style { size: Size::from_points(30.0, 10.0), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { align_items: Some(AlignItems::Start), ..Default::default() },
+                &[first, collapsed, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The line keeps the cross size of the collapsed item (its strut size), but not its main size
+        assert_eq!(layout_of(&taffy, root), (50.0, 60.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, first), (20.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, second), (30.0, 10.0, 20.0, 0.0));
+        assert_eq!(layout_of(&taffy, collapsed), (0.0, 0.0, 0.0, 0.0));
+        assert_eq!(taffy.layout(collapsed).unwrap().visibility, Visibility::Collapse);
+    }
+
+    #[test]
+    fn collapsed_flex_item_keeps_the_cross_size_of_its_line_when_wrapping() {
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style { size: Size::from_points(60.0, 10.0), ..Default::default() }).unwrap();
+        let collapsed = taffy
+            .new_leaf(Style {
+                visibility: Some(Visibility::Collapse),
+                size: Size::from_points(30.0, 40.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let second = taffy.new_leaf(Style { size: Size::from_points(60.0, 10.0), ..Default::default() }).unwrap();
+        let third = taffy.new_leaf(Style { size: Size::from_points(30.0, 10.0), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    flex_wrap: FlexWrap::Wrap,
+                    align_items: Some(AlignItems::Start),
+                    align_content: Some(AlignContent::Start),
+                    size: Size { width: points(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[first, collapsed, second, third],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The collapsed item was in the first line (with a cross size of 40) in the first layout round, and the items
+        // that follow it are collected into lines as though it had zero main size
+        assert_eq!(layout_of(&taffy, first), (60.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, second), (60.0, 10.0, 0.0, 40.0));
+        assert_eq!(layout_of(&taffy, third), (30.0, 10.0, 60.0, 40.0));
+        assert_eq!(layout_of(&taffy, root), (100.0, 50.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn collapsing_a_flex_item_hides_its_descendants() {
+        let mut taffy = Taffy::new();
+        let grandchild = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let child = taffy.new_with_children(Style::default(), &[grandchild]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(layout_of(&taffy, grandchild), (10.0, 10.0, 0.0, 0.0));
+
+        taffy.set_style(child, Style { visibility: Some(Visibility::Collapse), ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(layout_of(&taffy, root), (0.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, child), (0.0, 0.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, grandchild), (0.0, 0.0, 0.0, 0.0));
+        assert_eq!(taffy.layout(grandchild).unwrap().visibility, Visibility::Collapse);
+
+        // Showing the item again restores the layouts of its descendants
+        taffy.set_style(child, Style::default()).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(layout_of(&taffy, child), (10.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, grandchild), (10.0, 10.0, 0.0, 0.0));
+        assert_eq!(taffy.layout(grandchild).unwrap().visibility, Visibility::Visible);
+    }
+
+    #[test]
+    fn changing_visibility_is_reported_as_a_layout_change() {
+        let mut taffy = Taffy::new();
+        let leaf = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let child = taffy
+            .new_with_children(Style { size: Size::from_points(20.0, 20.0), ..Default::default() }, &[leaf])
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        taffy.set_layout_change_tracking(true);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Hiding a node doesn't change its size or location, but both it and its descendants must be repainted
+        taffy
+            .set_style(
+                child,
+                Style {
+                    visibility: Some(Visibility::Hidden),
+                    size: Size::from_points(20.0, 20.0),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.changed_layouts().collect::<Vec<_>>(), vec![child, leaf]);
+        assert_eq!(taffy.layout(leaf).unwrap().visibility, Visibility::Hidden);
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn grid_tracks_occupied_only_by_collapsed_items_are_collapsed() {
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style::default()).unwrap();
+        let collapsed = taffy.new_leaf(Style { visibility: Some(Visibility::Collapse), ..Default::default() }).unwrap();
+        let third = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(30.0), points(40.0), points(50.0)],
+                    grid_template_rows: vec![points(20.0)],
+                    gap: Size { width: points(10.0), height: zero() },
+                    ..Default::default()
+                },
+                &[first, collapsed, third],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The second column and one of the gaps next to it are collapsed
+        assert_eq!(layout_of(&taffy, root), (90.0, 20.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, first), (30.0, 20.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, third), (50.0, 20.0, 40.0, 0.0));
+        assert_eq!(layout_of(&taffy, collapsed), (0.0, 0.0, 0.0, 0.0));
+        assert_eq!(taffy.layout(collapsed).unwrap().visibility, Visibility::Collapse);
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn grid_tracks_also_occupied_by_visible_items_are_not_collapsed() {
+        let mut taffy = Taffy::new();
+        let collapsed = taffy.new_leaf(Style { visibility: Some(Visibility::Collapse), ..Default::default() }).unwrap();
+        let spanning = taffy
+            .new_leaf(Style {
+                grid_row: line(2),
+                grid_column: Line { start: line(1), end: span(2) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![points(30.0), points(40.0)],
+                    grid_template_rows: vec![points(20.0), points(10.0)],
+                    ..Default::default()
+                },
+                &[collapsed, spanning],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Only the first row is collapsed, as the columns are also occupied by the visible item
+        assert_eq!(layout_of(&taffy, root), (70.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, spanning), (70.0, 10.0, 0.0, 0.0));
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn collapsed_masonry_items_are_not_stacked() {
+        let mut taffy = Taffy::new();
+        let first =
+            taffy.new_leaf(Style { size: Size { width: auto(), height: points(10.0) }, ..Default::default() }).unwrap();
+        let collapsed = taffy
+            .new_leaf(Style {
+                size: Size { width: auto(), height: points(50.0) },
+                visibility: Some(Visibility::Collapse),
+                ..Default::default()
+            })
+            .unwrap();
+        let third =
+            taffy.new_leaf(Style { size: Size { width: auto(), height: points(20.0) }, ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size { width: points(40.0), height: auto() },
+                    grid_template_columns: vec![flex(1.0)],
+                    grid_template_rows: vec![masonry()],
+                    ..Default::default()
+                },
+                &[first, collapsed, third],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The collapsed item takes no space in the masonry axis
+        assert_eq!(layout_of(&taffy, root), (40.0, 30.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, first), (40.0, 10.0, 0.0, 0.0));
+        assert_eq!(layout_of(&taffy, third), (40.0, 20.0, 0.0, 10.0));
+        assert_eq!(layout_of(&taffy, collapsed), (0.0, 0.0, 0.0, 0.0));
+        assert_eq!(taffy.layout(collapsed).unwrap().visibility, Visibility::Collapse);
+    }
+}